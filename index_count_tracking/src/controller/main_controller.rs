@@ -42,7 +42,7 @@ where
         let alarm_index_name: Arc<str> =
             Arc::from(get_alarm_log_index_info().index_name().to_string());
 
-        /* Index document count save interval */
+        /* Default sampling interval - indices without `sample_interval_sec` follow this cadence */
         let save_tick: u64 = get_system_config_info().ticker_sec;
 
         /* 1. 모니터링 테스크 */
@@ -53,6 +53,16 @@ where
             Arc::clone(&target_index_info_list),
         );
 
+        /* 1-1. 스풀(모니터링 클러스터 장애 시 보관한 문서) 재전송 테스크 */
+        let spool_replay_handle =
+            Self::spawn_spool_replay_task(save_tick, Arc::clone(&self.tracking_monitor_service));
+
+        /* 1-2. 원본 보관 기간이 지난 모니터링 인덱스 롤업 압축 테스크 */
+        let compaction_handle = Self::spawn_compaction_task(
+            Arc::clone(&self.tracking_monitor_service),
+            Arc::clone(&mon_index_name),
+        );
+
         /* 2. 일일 리포트 테스크 */
         let daily_report_handle = Self::spawn_report_task(
             Arc::clone(&self.report_service),
@@ -86,6 +96,8 @@ where
         /* 모든 태스크를 병렬로 실행하고 종료를 대기 */
         let _ = tokio::join!(
             tracking_monitor_handle,
+            spool_replay_handle,
+            compaction_handle,
             daily_report_handle,
             weekly_report_handle,
             monthly_report_handle
@@ -115,6 +127,35 @@ where
         })
     }
 
+    #[doc = "스풀 재전송 태스크를 별도의 tokio task로 spawn"]
+    fn spawn_spool_replay_task(replay_tick: u64, service: Arc<T>) -> tokio::task::JoinHandle<()>
+    where
+        T: Send + Sync + 'static,
+    {
+        tokio::spawn(async move {
+            match service.spool_replay_loop(replay_tick).await {
+                Ok(_) => info!("[spool_replay_task] Completed successfully"),
+                Err(e) => error!("[spool_replay_task] Failed with error: {:?}", e),
+            }
+        })
+    }
+
+    #[doc = "모니터링 인덱스 압축 태스크를 별도의 tokio task로 spawn"]
+    fn spawn_compaction_task(
+        service: Arc<T>,
+        mon_index_name: Arc<str>,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        T: Send + Sync + 'static,
+    {
+        tokio::spawn(async move {
+            match service.compaction_loop(&mon_index_name).await {
+                Ok(_) => info!("[compaction_task] Completed successfully"),
+                Err(e) => error!("[compaction_task] Failed with error: {:?}", e),
+            }
+        })
+    }
+
     #[doc = "리포트 태스크를 별도의 tokio task로 spawn"]
     fn spawn_report_task(
         service: Arc<R>,
@@ -0,0 +1,475 @@
+use crate::common::*;
+
+use crate::model::index::{alert_index::*, index_config::*};
+
+use crate::dto::log_index_result::*;
+
+use crate::enums::{alert_rule::*, alert_severity::*, fluctuation_direction::*};
+
+use crate::utils_modules::time_utils::*;
+
+/* 알람 메시지에 포함할 최근 샘플 수 */
+pub const ALERT_HISTORY_SIZE: usize = 100;
+/* 통계 기반 탐지기의 기본 기준선 학습 구간(초) */
+pub const DEFAULT_BASELINE_SEC: i64 = 86400;
+/* 통계 기반 탐지기의 기본 이상 점수 허용치 */
+pub const DEFAULT_ANOMALY_SCORE_LIMIT: f64 = 3.0;
+/* 기준선 판단에 필요한 최소 tick 변동 수 */
+pub const MIN_BASELINE_TICKS: usize = 10;
+
+#[doc = r#"
+    두 값을 바탕으로 변동률(%)을 계산하는 헬퍼 함수.
+
+    변동률 = (|값1 - 값2| / 두 값의 평균) × 100
+
+    1. 두 값의 평균이 0보다 큰 경우: 정상적인 변동률 계산 수행
+    2. 평균이 0 이하인 경우: 0.0 반환 (0으로 나누기 방지)
+    3. 결과는 백분율로 표현됨 (예: 50.0 = 50%)
+"#]
+pub fn calculate_fluctuation(val1: f64, val2: f64) -> f64 {
+    let diff: f64 = (val1 - val2).abs();
+
+    let avg: f64 = (val1.abs() + val2.abs()) / 2.0;
+
+    if avg > 0.0 { (diff / avg) * 100.0 } else { 0.0 }
+}
+
+#[doc = "변동률을 소수점 둘째 자리까지 반올림"]
+pub fn round_fluctuation(flunct_val: f64) -> f64 {
+    (flunct_val * 100.0).round() / 100.0
+}
+
+#[doc = r#"
+    시간순 샘플을 기준으로 구간 내 문서 수 변동 방향을 판단하는 헬퍼 함수.
+
+    1. 최종값이 최초값보다 작으면 감소(Drop), 크면 증가(Growth)
+    2. 최초값과 최종값이 같더라도 구간 내 최소/최대값이 다르면(급감 후 복구 등)
+       최초값에서 더 멀리 벗어난 쪽을 변동 방향으로 판단
+    3. 최소값과 최대값이 같으면 변동 없음(Flat)
+"#]
+pub fn determine_direction(
+    first_val: f64,
+    last_val: f64,
+    min_val: f64,
+    max_val: f64,
+) -> FluctuationDirection {
+    if last_val < first_val {
+        FluctuationDirection::Drop
+    } else if last_val > first_val {
+        FluctuationDirection::Growth
+    } else if min_val == max_val {
+        FluctuationDirection::Flat
+    } else if (first_val - min_val) >= (max_val - first_val) {
+        FluctuationDirection::Drop
+    } else {
+        FluctuationDirection::Growth
+    }
+}
+
+#[doc = "현재 문서 수에 대한 하한(floor)/상한(ceiling) 규칙을 검사하여 위반한 규칙 목록을 반환"]
+pub fn evaluate_count_rules(index_config: &IndexConfig, cur_cnt: usize) -> Vec<AlertRule> {
+    let mut alert_rules: Vec<AlertRule> = Vec::new();
+
+    if index_config
+        .min_doc_count()
+        .is_some_and(|floor| cur_cnt < floor)
+    {
+        alert_rules.push(AlertRule::FloorCount);
+    }
+
+    if index_config
+        .max_doc_count()
+        .is_some_and(|ceiling| cur_cnt > ceiling)
+    {
+        alert_rules.push(AlertRule::CeilingCount);
+    }
+
+    alert_rules
+}
+
+#[doc = r#"
+    Critical 규칙 위반이 없을 때만 Warning 규칙 위반을 알람 규칙으로 사용하고, 그에 맞는 심각도를 반환하는 헬퍼 함수.
+
+    Critical 위반이 하나라도 있으면 Warning 위반은 버리고 Critical 로 판단한다.
+"#]
+pub fn resolve_severity(
+    alert_rules: &mut Vec<AlertRule>,
+    warning_rules: Vec<AlertRule>,
+) -> AlertSeverity {
+    if alert_rules.is_empty() && !warning_rules.is_empty() {
+        *alert_rules = warning_rules;
+        AlertSeverity::Warning
+    } else {
+        AlertSeverity::Critical
+    }
+}
+
+#[doc = "샘플 중 `window_start_utc` 이후(포함)에 저장된 샘플만 반환 - timestamp 파싱에 실패한 샘플은 제외"]
+pub fn window_samples(samples: &[AlertIndex], window_start_utc: DateTime<Utc>) -> Vec<&AlertIndex> {
+    samples
+        .iter()
+        .filter(|sample| {
+            convert_utc_from_str(sample.timestamp())
+                .map(|timestamp| timestamp >= window_start_utc)
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+#[doc = "알람 메시지에 사용할 구간 내 최근 샘플 목록 (timestamp 내림차순, 최대 ALERT_HISTORY_SIZE건)"]
+pub fn recent_alert_history(window: &[&AlertIndex]) -> Vec<AlertIndex> {
+    window
+        .iter()
+        .rev()
+        .take(ALERT_HISTORY_SIZE)
+        .map(|sample| (*sample).clone())
+        .collect()
+}
+
+#[doc = "정상(알람 아님) 상태의 LogIndexResult 생성"]
+pub fn normal_result(index_name: &str, fluctuation_val: f64, cur_cnt: usize) -> LogIndexResult {
+    LogIndexResult::new(
+        index_name.to_string(),
+        false,
+        None,
+        fluctuation_val,
+        cur_cnt,
+        FluctuationDirection::Flat,
+        Vec::new(),
+        None,
+        None,
+        None,
+    )
+}
+
+#[doc = r#"
+    과거 이력으로 학습한 기준선(중심값, 척도)과 비교하여 tick 단위 변동의 이상 여부를 판단하는 공통 함수.
+
+    1. 연속한 두 샘플의 cnt 차이를 tick 변동으로 정의
+    2. 현재 구간(기준시각 - agg_term_sec 이후) 이전의 tick 변동을 기준선 학습 데이터로 사용
+       - 학습 데이터가 `MIN_BASELINE_TICKS` 미만이면 판단하지 않음
+    3. `baseline_fn`으로 기준선의 (중심값, 척도)를 계산 - 척도는 최소 1건으로 보정
+    4. 현재 구간의 각 tick 변동에 대해 이상 점수 = |변동 - 중심값| / 척도 를 계산
+    5. 가장 높은 이상 점수가 `anomaly_score_limit` 이상이면 `AlertRule::AnomalyScore` 위반 (Critical)
+       - 미만이지만 `warning_anomaly_score_limit` 이상이면 Warning 심각도로 위반
+    6. 하한/상한 규칙은 탐지기와 무관하게 항상 검사 (Critical)
+
+    # Arguments
+    * `index_config` - 모니터링 대상 인덱스 설정
+    * `samples` - 기준선 학습 구간 + 현재 구간의 샘플 (timestamp 오름차순)
+    * `cur_timestamp_utc` - 기준 시각 (UTC)
+    * `baseline_fn` - tick 변동 목록으로부터 (중심값, 척도)를 계산하는 함수
+"#]
+pub fn detect_by_baseline<F>(
+    index_config: &IndexConfig,
+    samples: &[AlertIndex],
+    cur_timestamp_utc: DateTime<Utc>,
+    baseline_fn: F,
+) -> LogIndexResult
+where
+    F: Fn(&[f64]) -> Option<(f64, f64)>,
+{
+    let index_name: &str = index_config.index_name();
+    let window_start_utc: DateTime<Utc> =
+        calc_time_window(cur_timestamp_utc, *index_config.agg_term_sec());
+
+    let mut baseline_changes: Vec<f64> = Vec::new();
+    /* (변동량, 직전 cnt, 현재 cnt) */
+    let mut window_changes: Vec<(f64, f64, f64)> = Vec::new();
+
+    for pair in samples.windows(2) {
+        let prev_cnt: f64 = *pair[0].cnt() as f64;
+        let cur_cnt: f64 = *pair[1].cnt() as f64;
+        let change: f64 = cur_cnt - prev_cnt;
+
+        let in_window: bool = convert_utc_from_str(pair[1].timestamp())
+            .map(|timestamp| timestamp >= window_start_utc)
+            .unwrap_or(false);
+
+        if in_window {
+            window_changes.push((change, prev_cnt, cur_cnt));
+        } else {
+            baseline_changes.push(change);
+        }
+    }
+
+    let window: Vec<&AlertIndex> = window_samples(samples, window_start_utc);
+    let cur_cnt: usize = window.last().map(|sample| *sample.cnt()).unwrap_or(0);
+
+    let mut alert_rules: Vec<AlertRule> = Vec::new();
+    let mut warning_rules: Vec<AlertRule> = Vec::new();
+    let mut anomaly_score: Option<f64> = None;
+    let mut direction: FluctuationDirection = FluctuationDirection::Flat;
+    let mut flunct_val: f64 = 0.0;
+
+    if baseline_changes.len() >= MIN_BASELINE_TICKS
+        && let Some((center, scale)) = baseline_fn(&baseline_changes)
+    {
+        let scale: f64 = scale.max(1.0);
+        let score_limit: f64 = index_config
+            .anomaly_score_limit()
+            .unwrap_or(DEFAULT_ANOMALY_SCORE_LIMIT);
+
+        /* The tick with the highest anomaly score in the current window. */
+        let worst_tick: Option<(f64, f64, f64, f64)> = window_changes
+            .iter()
+            .map(|(change, prev_cnt, cur_cnt)| {
+                (
+                    (change - center).abs() / scale,
+                    *change,
+                    *prev_cnt,
+                    *cur_cnt,
+                )
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+
+        if let Some((score, change, prev_cnt, tick_cnt)) = worst_tick {
+            anomaly_score = Some(round_fluctuation(score));
+            flunct_val = calculate_fluctuation(prev_cnt, tick_cnt);
+            direction = if change < 0.0 {
+                FluctuationDirection::Drop
+            } else if change > 0.0 {
+                FluctuationDirection::Growth
+            } else {
+                FluctuationDirection::Flat
+            };
+
+            let ignored: bool =
+                direction == FluctuationDirection::Growth && *index_config.ignore_growth();
+
+            if ignored {
+                /* Growth is not an alert target. */
+            } else if score >= score_limit {
+                alert_rules.push(AlertRule::AnomalyScore);
+            } else if index_config
+                .warning_anomaly_score_limit()
+                .is_some_and(|warning_limit| score >= warning_limit)
+            {
+                warning_rules.push(AlertRule::AnomalyScore);
+            }
+        }
+    }
+
+    if !window.is_empty() {
+        alert_rules.extend(evaluate_count_rules(index_config, cur_cnt));
+    }
+
+    let severity: AlertSeverity = resolve_severity(&mut alert_rules, warning_rules);
+    let alert_yn: bool = !alert_rules.is_empty();
+
+    let mut log_index_res: LogIndexResult = LogIndexResult::new(
+        index_name.to_string(),
+        alert_yn,
+        alert_yn.then(|| recent_alert_history(&window)),
+        round_fluctuation(flunct_val),
+        cur_cnt,
+        direction,
+        alert_rules,
+        None,
+        anomaly_score,
+        None,
+    );
+    log_index_res.set_severity(severity);
+
+    log_index_res
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /* 테스트 기준 시각 / 샘플 수집 주기(초) / 집계 구간(초) */
+    pub(crate) const TEST_AGG_TERM_SEC: i64 = 600;
+    pub(crate) const TEST_SAMPLE_STEP_SEC: i64 = 60;
+    /* 현재 구간 시작 시각의 샘플도 구간에 포함되므로 현재 구간 tick 수는 agg_term / step + 1 */
+    const TEST_WINDOW_TICKS: usize = (TEST_AGG_TERM_SEC / TEST_SAMPLE_STEP_SEC) as usize + 1;
+
+    pub(crate) fn test_now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap()
+    }
+
+    #[doc = "agg_term_sec = 600 인 테스트용 인덱스 설정 - `extra` 의 필드로 덮어쓴다"]
+    pub(crate) fn test_index_config(extra: Value) -> IndexConfig {
+        let mut config: Value = json!({
+            "index_name": "test_index",
+            "allowable_fluctuation_range": 10.0,
+            "agg_term_sec": TEST_AGG_TERM_SEC,
+            "sample_interval_sec": TEST_SAMPLE_STEP_SEC
+        });
+
+        if let (Some(config), Some(extra)) = (config.as_object_mut(), extra.as_object()) {
+            config.extend(extra.clone());
+        }
+
+        serde_json::from_value(config).unwrap()
+    }
+
+    #[doc = "`end_time` 에서 끝나는 60초 간격 샘플 (timestamp 오름차순)"]
+    pub(crate) fn test_samples(end_time: DateTime<Utc>, cnts: &[usize]) -> Vec<AlertIndex> {
+        cnts.iter()
+            .enumerate()
+            .map(|(idx, cnt)| {
+                let steps_before_end: i64 = (cnts.len() - 1 - idx) as i64;
+                let timestamp: DateTime<Utc> =
+                    end_time - chrono::Duration::seconds(steps_before_end * TEST_SAMPLE_STEP_SEC);
+
+                AlertIndex::new(
+                    "test_index".to_string(),
+                    *cnt,
+                    convert_date_to_str(timestamp, Utc),
+                )
+            })
+            .collect()
+    }
+
+    #[doc = "`start_cnt` 에서 시작해 매 tick `step` 만큼 변하는 문서 수 목록 (`tick_cnt` 개 tick)"]
+    pub(crate) fn steady_cnts(start_cnt: usize, step: usize, tick_cnt: usize) -> Vec<usize> {
+        (0..=tick_cnt).map(|tick| start_cnt + tick * step).collect()
+    }
+
+    #[doc = "평균/표준편차 기준선 - 테스트용"]
+    fn mean_std_baseline(changes: &[f64]) -> Option<(f64, f64)> {
+        let center: f64 = changes.iter().sum::<f64>() / changes.len() as f64;
+        let var: f64 =
+            changes.iter().map(|c| (c - center).powi(2)).sum::<f64>() / changes.len() as f64;
+
+        Some((center, var.sqrt()))
+    }
+
+    #[test]
+    fn detect_by_baseline_without_samples_is_normal() {
+        let result: LogIndexResult = detect_by_baseline(
+            &test_index_config(json!({})),
+            &[],
+            test_now(),
+            mean_std_baseline,
+        );
+
+        assert!(!result.alert_yn);
+        assert_eq!(result.cur_cnt, 0);
+        assert_eq!(result.anomaly_score, None);
+    }
+
+    #[test]
+    fn detect_by_baseline_skips_scoring_below_min_baseline_ticks() {
+        /* 기준선 tick (MIN_BASELINE_TICKS - 1) 개 + 현재 구간 tick (마지막 tick 급감) */
+        let mut cnts: Vec<usize> =
+            steady_cnts(1000, 10, MIN_BASELINE_TICKS - 1 + TEST_WINDOW_TICKS);
+        *cnts.last_mut().unwrap() = 0;
+
+        let result: LogIndexResult = detect_by_baseline(
+            &test_index_config(json!({})),
+            &test_samples(test_now(), &cnts),
+            test_now(),
+            mean_std_baseline,
+        );
+
+        assert!(!result.alert_yn);
+        assert_eq!(result.anomaly_score, None);
+        assert!(result.alert_rules.is_empty());
+    }
+
+    #[test]
+    fn detect_by_baseline_scores_once_min_baseline_ticks_are_available() {
+        let mut cnts: Vec<usize> = steady_cnts(1000, 10, MIN_BASELINE_TICKS + TEST_WINDOW_TICKS);
+        *cnts.last_mut().unwrap() = 0;
+
+        let result: LogIndexResult = detect_by_baseline(
+            &test_index_config(json!({})),
+            &test_samples(test_now(), &cnts),
+            test_now(),
+            mean_std_baseline,
+        );
+
+        assert!(result.alert_yn);
+        assert_eq!(result.alert_rules, vec![AlertRule::AnomalyScore]);
+        assert_eq!(result.direction, FluctuationDirection::Drop);
+        assert_eq!(result.cur_cnt, 0);
+    }
+
+    #[test]
+    fn detect_by_baseline_floors_zero_scale_to_one() {
+        /* 기준선 tick 변동이 모두 +10 이면 척도 0 -> 1 로 보정되어 점수 = |변동 - 10| */
+        let mut cnts: Vec<usize> = steady_cnts(1000, 10, 30);
+        let last_idx: usize = cnts.len() - 1;
+        cnts[last_idx] = cnts[last_idx - 1] + 12;
+
+        let result: LogIndexResult = detect_by_baseline(
+            &test_index_config(json!({})),
+            &test_samples(test_now(), &cnts),
+            test_now(),
+            mean_std_baseline,
+        );
+
+        assert_eq!(result.anomaly_score, Some(2.0));
+        assert!(!result.alert_yn);
+    }
+
+    #[test]
+    fn detect_by_baseline_reports_warning_between_warning_and_critical_limits() {
+        /* 점수 2.0 - warning 허용치(1.5) 이상, critical 허용치(3.0) 미만 */
+        let mut cnts: Vec<usize> = steady_cnts(1000, 10, 30);
+        let last_idx: usize = cnts.len() - 1;
+        cnts[last_idx] = cnts[last_idx - 1] + 12;
+
+        let result: LogIndexResult = detect_by_baseline(
+            &test_index_config(json!({ "warning_anomaly_score_limit": 1.5 })),
+            &test_samples(test_now(), &cnts),
+            test_now(),
+            mean_std_baseline,
+        );
+
+        assert!(result.alert_yn);
+        assert_eq!(result.alert_rules, vec![AlertRule::AnomalyScore]);
+        assert_eq!(result.severity, AlertSeverity::Warning);
+    }
+
+    #[test]
+    fn resolve_severity_drops_warning_rules_when_critical_rule_exists() {
+        let mut alert_rules: Vec<AlertRule> = vec![AlertRule::FloorCount];
+
+        let severity: AlertSeverity =
+            resolve_severity(&mut alert_rules, vec![AlertRule::AnomalyScore]);
+
+        assert_eq!(severity, AlertSeverity::Critical);
+        assert_eq!(alert_rules, vec![AlertRule::FloorCount]);
+    }
+
+    #[test]
+    fn detect_by_baseline_ignores_growth_when_configured() {
+        let mut cnts: Vec<usize> = steady_cnts(1000, 10, 30);
+        *cnts.last_mut().unwrap() += 1000;
+
+        let samples: Vec<AlertIndex> = test_samples(test_now(), &cnts);
+
+        let alerted: LogIndexResult = detect_by_baseline(
+            &test_index_config(json!({})),
+            &samples,
+            test_now(),
+            mean_std_baseline,
+        );
+        let ignored: LogIndexResult = detect_by_baseline(
+            &test_index_config(json!({ "ignore_growth": true })),
+            &samples,
+            test_now(),
+            mean_std_baseline,
+        );
+
+        assert_eq!(alerted.alert_rules, vec![AlertRule::AnomalyScore]);
+        assert_eq!(ignored.direction, FluctuationDirection::Growth);
+        assert!(!ignored.alert_yn);
+    }
+
+    #[test]
+    fn detect_by_baseline_checks_count_rules_without_baseline() {
+        let result: LogIndexResult = detect_by_baseline(
+            &test_index_config(json!({ "min_doc_count": 5000 })),
+            &test_samples(test_now(), &[1000, 1010]),
+            test_now(),
+            mean_std_baseline,
+        );
+
+        assert_eq!(result.alert_rules, vec![AlertRule::FloorCount]);
+        assert_eq!(result.anomaly_score, None);
+    }
+}
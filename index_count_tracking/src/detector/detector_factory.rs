@@ -0,0 +1,18 @@
+use crate::detector::{
+    ewma_detector_impl::*, mad_detector_impl::*, seasonal_detector_impl::*,
+    threshold_detector_impl::*,
+};
+
+use crate::enums::detector_type::*;
+
+use crate::traits::detector_traits::detector::*;
+
+#[doc = "인덱스 설정의 탐지기 종류에 해당하는 Detector 구현체를 반환"]
+pub fn build_detector(detector_type: DetectorType) -> Box<dyn Detector> {
+    match detector_type {
+        DetectorType::Threshold => Box::new(ThresholdDetectorImpl::new()),
+        DetectorType::Ewma => Box::new(EwmaDetectorImpl::new()),
+        DetectorType::Mad => Box::new(MadDetectorImpl::new()),
+        DetectorType::Seasonal => Box::new(SeasonalDetectorImpl::new()),
+    }
+}
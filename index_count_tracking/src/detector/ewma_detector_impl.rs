@@ -0,0 +1,102 @@
+use crate::common::*;
+
+use crate::detector::detector_common::*;
+
+use crate::model::index::{alert_index::*, index_config::*};
+
+use crate::dto::{comparison_window::*, log_index_result::*};
+
+use crate::traits::detector_traits::detector::*;
+
+use crate::utils_modules::stats_utils::*;
+
+/* EWMA 기본 평활 계수 */
+const DEFAULT_EWMA_ALPHA: f64 = 0.3;
+
+#[derive(Debug, Clone, new)]
+pub struct EwmaDetectorImpl;
+
+impl Detector for EwmaDetectorImpl {
+    fn lookback_sec(&self, index_config: &IndexConfig) -> i64 {
+        index_config.baseline_sec().unwrap_or(DEFAULT_BASELINE_SEC) + *index_config.agg_term_sec()
+    }
+
+    #[doc = r#"
+        과거 tick 변동의 지수가중이동평균(EWMA)과 표준편차로 기준선을 학습하고,
+        현재 구간의 tick 변동이 기준선에서 몇 표준편차(z-score)만큼 벗어났는지로 이상 여부를 판단한다.
+    "#]
+    fn detect(
+        &self,
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+        _comparisons: &[ComparisonWindow],
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> LogIndexResult {
+        let alpha: f64 = index_config
+            .ewma_alpha()
+            .filter(|alpha| *alpha > 0.0 && *alpha <= 1.0)
+            .unwrap_or(DEFAULT_EWMA_ALPHA);
+
+        detect_by_baseline(index_config, samples, cur_timestamp_utc, |changes| {
+            ewma_mean_std(changes, alpha)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::detector::detector_common::tests::*;
+    use crate::enums::{alert_rule::*, fluctuation_direction::*};
+
+    fn detect(index_config: &IndexConfig, cnts: &[usize]) -> LogIndexResult {
+        EwmaDetectorImpl::new().detect(
+            index_config,
+            &test_samples(test_now(), cnts),
+            &[],
+            test_now(),
+        )
+    }
+
+    #[test]
+    fn empty_baseline_is_not_scored() {
+        let result: LogIndexResult = detect(&test_index_config(json!({})), &[]);
+
+        assert!(!result.alert_yn);
+        assert_eq!(result.anomaly_score, None);
+    }
+
+    #[test]
+    fn steady_ticks_are_normal() {
+        let result: LogIndexResult =
+            detect(&test_index_config(json!({})), &steady_cnts(1000, 10, 30));
+
+        assert!(!result.alert_yn);
+        assert_eq!(result.anomaly_score, Some(0.0));
+    }
+
+    #[test]
+    fn sudden_drop_against_zero_std_baseline_alerts() {
+        let mut cnts: Vec<usize> = steady_cnts(1000, 10, 30);
+        *cnts.last_mut().unwrap() = 500;
+
+        let result: LogIndexResult = detect(&test_index_config(json!({})), &cnts);
+
+        assert!(result.alert_yn);
+        assert_eq!(result.alert_rules, vec![AlertRule::AnomalyScore]);
+        assert_eq!(result.direction, FluctuationDirection::Drop);
+    }
+
+    #[test]
+    fn out_of_range_alpha_falls_back_to_default() {
+        let mut cnts: Vec<usize> = steady_cnts(1000, 10, 30);
+        *cnts.last_mut().unwrap() = 500;
+
+        let default_alpha: LogIndexResult = detect(&test_index_config(json!({})), &cnts);
+        let invalid_alpha: LogIndexResult =
+            detect(&test_index_config(json!({ "ewma_alpha": 1.5 })), &cnts);
+
+        assert_eq!(invalid_alpha.anomaly_score, default_alpha.anomaly_score);
+    }
+}
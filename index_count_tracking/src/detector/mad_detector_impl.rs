@@ -0,0 +1,91 @@
+use crate::common::*;
+
+use crate::detector::detector_common::*;
+
+use crate::model::index::{alert_index::*, index_config::*};
+
+use crate::dto::{comparison_window::*, log_index_result::*};
+
+use crate::traits::detector_traits::detector::*;
+
+use crate::utils_modules::stats_utils::*;
+
+/* 정규분포 가정 시 MAD 를 표준편차 척도로 변환하는 상수 */
+const MAD_SCALE_FACTOR: f64 = 1.4826;
+
+#[derive(Debug, Clone, new)]
+pub struct MadDetectorImpl;
+
+impl Detector for MadDetectorImpl {
+    fn lookback_sec(&self, index_config: &IndexConfig) -> i64 {
+        index_config.baseline_sec().unwrap_or(DEFAULT_BASELINE_SEC) + *index_config.agg_term_sec()
+    }
+
+    #[doc = r#"
+        과거 tick 변동의 중앙값과 중앙값 절대편차(MAD)로 기준선을 학습하고,
+        현재 구간의 tick 변동이 기준선에서 얼마나 벗어났는지로 이상 여부를 판단한다.
+        대량 색인 등 일시적인 급변에 평균/표준편차보다 덜 민감하다.
+    "#]
+    fn detect(
+        &self,
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+        _comparisons: &[ComparisonWindow],
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> LogIndexResult {
+        detect_by_baseline(index_config, samples, cur_timestamp_utc, |changes| {
+            median_absolute_deviation(changes).map(|(med, mad)| (med, mad * MAD_SCALE_FACTOR))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::detector::detector_common::tests::*;
+    use crate::enums::alert_rule::*;
+
+    fn detect(cnts: &[usize]) -> LogIndexResult {
+        MadDetectorImpl::new().detect(
+            &test_index_config(json!({})),
+            &test_samples(test_now(), cnts),
+            &[],
+            test_now(),
+        )
+    }
+
+    /* 기준선: 매 tick +10, 중간에 대량 색인 1회(+5000) / 현재 구간 마지막 tick 변동 `last_change` */
+    fn cnts_with_bulk_load(last_change: usize) -> Vec<usize> {
+        let mut cnts: Vec<usize> = steady_cnts(1000, 10, 30);
+
+        for cnt in cnts.iter_mut().skip(5) {
+            *cnt += 5000;
+        }
+
+        let last_idx: usize = cnts.len() - 1;
+        cnts[last_idx] = cnts[last_idx - 1] + last_change;
+
+        cnts
+    }
+
+    #[test]
+    fn empty_baseline_is_not_scored() {
+        let result: LogIndexResult = detect(&[]);
+
+        assert!(!result.alert_yn);
+        assert_eq!(result.anomaly_score, None);
+    }
+
+    #[test]
+    fn baseline_outlier_does_not_widen_zero_mad() {
+        /* 중앙값 10, MAD 0 -> 척도 1 로 보정 - 일시적인 대량 색인은 기준선에 영향을 주지 않는다 */
+        let normal: LogIndexResult = detect(&cnts_with_bulk_load(10));
+        let anomaly: LogIndexResult = detect(&cnts_with_bulk_load(20));
+
+        assert!(!normal.alert_yn);
+        assert_eq!(normal.anomaly_score, Some(0.0));
+        assert_eq!(anomaly.anomaly_score, Some(10.0));
+        assert_eq!(anomaly.alert_rules, vec![AlertRule::AnomalyScore]);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod detector_common;
+pub mod detector_factory;
+pub mod ewma_detector_impl;
+pub mod mad_detector_impl;
+pub mod seasonal_detector_impl;
+pub mod threshold_detector_impl;
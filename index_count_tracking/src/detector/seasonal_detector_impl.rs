@@ -0,0 +1,241 @@
+use crate::common::*;
+
+use crate::detector::detector_common::*;
+
+use crate::model::index::{alert_index::*, index_config::*};
+
+use crate::dto::{comparison_window::*, log_index_result::*};
+
+use crate::enums::{alert_rule::*, alert_severity::*, fluctuation_direction::*};
+
+use crate::traits::detector_traits::detector::*;
+
+use crate::utils_modules::{stats_utils::*, time_utils::*};
+
+/* 계절성 비교 구간 - 1일 전, 7일 전 */
+const SEASONAL_OFFSETS_SEC: [i64; 2] = [86400, 604800];
+
+#[derive(Debug, Clone, new)]
+pub struct SeasonalDetectorImpl;
+
+impl SeasonalDetectorImpl {
+    #[doc = "구간 내 최초 샘플 대비 최종 샘플의 순변동량 - 샘플이 없으면 None"]
+    fn net_change(samples: &[&AlertIndex]) -> Option<f64> {
+        match (samples.first(), samples.last()) {
+            (Some(first), Some(last)) => Some(*last.cnt() as f64 - *first.cnt() as f64),
+            _ => None,
+        }
+    }
+}
+
+impl Detector for SeasonalDetectorImpl {
+    fn lookback_sec(&self, index_config: &IndexConfig) -> i64 {
+        *index_config.agg_term_sec()
+    }
+
+    fn comparison_offsets_sec(&self, _index_config: &IndexConfig) -> Vec<i64> {
+        SEASONAL_OFFSETS_SEC.to_vec()
+    }
+
+    #[doc = r#"
+        현재 구간의 순변동량을 1일/7일 전 동일 구간의 순변동량과 비교하여 이상 여부를 판단한다.
+
+        1. 현재 구간(기준시각 - agg_term_sec ~ 기준시각)의 순변동량(최종 cnt - 최초 cnt)을 계산
+        2. 비교 구간별 순변동량의 평균을 기대 변동량(계절성 기준선)으로 사용
+           - 비교 구간에 샘플이 없으면 해당 구간은 제외, 모두 없으면 판단하지 않음
+        3. 편차율(%) = |실제 변동량 - 기대 변동량| / 현재 구간 최초 cnt × 100
+        4. 편차율이 `seasonal_band`(미설정 시 `allowable_fluctuation_range`) 이상이면
+           `AlertRule::SeasonalDeviation` 위반 (`ignore_growth` 설정 시 증가 방향 편차는 제외)
+           - 미만이지만 `warning_seasonal_band` 이상이면 Warning 심각도로 위반
+        5. 하한/상한 규칙은 탐지기와 무관하게 항상 검사 (Critical)
+
+        매일 자정의 대량 만료, 월요일 아침의 재색인처럼 주기적으로 반복되는 변동은
+        기대 변동량에 포함되므로 알람이 발생하지 않는다.
+    "#]
+    fn detect(
+        &self,
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+        comparisons: &[ComparisonWindow],
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> LogIndexResult {
+        let index_name: &str = index_config.index_name();
+        let agg_term: i64 = *index_config.agg_term_sec();
+        let window_start_utc: DateTime<Utc> = calc_time_window(cur_timestamp_utc, agg_term);
+
+        let window: Vec<&AlertIndex> = window_samples(samples, window_start_utc);
+
+        let (first_cnt, actual_change) = match (window.first(), Self::net_change(&window)) {
+            (Some(first), Some(actual_change)) => (*first.cnt() as f64, actual_change),
+            _ => return normal_result(index_name, 0.0, 0),
+        };
+
+        let cur_cnt: usize = window.last().map(|sample| *sample.cnt()).unwrap_or(0);
+
+        /* Net change of the same window 1 day / 7 days ago. */
+        let seasonal_changes: Vec<f64> = comparisons
+            .iter()
+            .filter_map(|comparison| {
+                let comparison_start_utc: DateTime<Utc> =
+                    calc_time_window(cur_timestamp_utc, *comparison.offset_sec() + agg_term);
+                Self::net_change(&window_samples(comparison.samples(), comparison_start_utc))
+            })
+            .collect();
+
+        let mut alert_rules: Vec<AlertRule> = Vec::new();
+        let mut warning_rules: Vec<AlertRule> = Vec::new();
+        let mut direction: FluctuationDirection = FluctuationDirection::Flat;
+        let mut deviation_per: f64 = 0.0;
+        let expected_change: Option<f64> = mean(&seasonal_changes);
+
+        if let Some(expected_change) = expected_change {
+            let deviation: f64 = actual_change - expected_change;
+            deviation_per = (deviation.abs() / first_cnt.max(1.0)) * 100.0;
+
+            direction = if deviation < 0.0 {
+                FluctuationDirection::Drop
+            } else if deviation > 0.0 {
+                FluctuationDirection::Growth
+            } else {
+                FluctuationDirection::Flat
+            };
+
+            let band: f64 = index_config
+                .seasonal_band()
+                .unwrap_or(*index_config.allowable_fluctuation_range());
+
+            let ignored: bool =
+                direction == FluctuationDirection::Growth && *index_config.ignore_growth();
+
+            if ignored {
+                /* Growth is not an alert target. */
+            } else if deviation_per >= band {
+                alert_rules.push(AlertRule::SeasonalDeviation);
+            } else if index_config
+                .warning_seasonal_band()
+                .is_some_and(|warning_band| deviation_per >= warning_band)
+            {
+                warning_rules.push(AlertRule::SeasonalDeviation);
+            }
+        }
+
+        alert_rules.extend(evaluate_count_rules(index_config, cur_cnt));
+
+        let severity: AlertSeverity = resolve_severity(&mut alert_rules, warning_rules);
+        let alert_yn: bool = !alert_rules.is_empty();
+
+        let mut log_index_res: LogIndexResult = LogIndexResult::new(
+            index_name.to_string(),
+            alert_yn,
+            alert_yn.then(|| recent_alert_history(&window)),
+            round_fluctuation(deviation_per),
+            cur_cnt,
+            direction,
+            alert_rules,
+            None,
+            None,
+            expected_change.map(|change| change.round()),
+        );
+        log_index_res.set_severity(severity);
+
+        log_index_res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::detector::detector_common::tests::*;
+
+    /* 1일 전 동일 구간 */
+    const ONE_DAY_SEC: i64 = 86400;
+
+    fn comparison(offset_sec: i64, cnts: &[usize]) -> ComparisonWindow {
+        ComparisonWindow::new(
+            offset_sec,
+            test_samples(test_now() - chrono::Duration::seconds(offset_sec), cnts),
+        )
+    }
+
+    fn detect(cnts: &[usize], comparisons: &[ComparisonWindow]) -> LogIndexResult {
+        SeasonalDetectorImpl::new().detect(
+            &test_index_config(json!({})),
+            &test_samples(test_now(), cnts),
+            comparisons,
+            test_now(),
+        )
+    }
+
+    #[test]
+    fn empty_window_is_normal() {
+        let result: LogIndexResult = detect(&[], &[comparison(ONE_DAY_SEC, &[1000, 500])]);
+
+        assert!(!result.alert_yn);
+        assert_eq!(result.cur_cnt, 0);
+    }
+
+    #[test]
+    fn missing_comparison_samples_skip_seasonal_judgement() {
+        let result: LogIndexResult = detect(&[1000, 100], &[comparison(ONE_DAY_SEC, &[])]);
+
+        assert!(!result.alert_yn);
+        assert_eq!(result.expected_change, None);
+    }
+
+    #[test]
+    fn recurring_drop_is_expected() {
+        let result: LogIndexResult = detect(
+            &[1000, 500],
+            &[
+                comparison(ONE_DAY_SEC, &[1000, 500]),
+                comparison(7 * ONE_DAY_SEC, &[1000, 500]),
+            ],
+        );
+
+        assert!(!result.alert_yn);
+        assert_eq!(result.expected_change, Some(-500.0));
+        assert_eq!(result.fluctuation_val, 0.0);
+    }
+
+    #[test]
+    fn drop_not_seen_in_previous_days_alerts() {
+        let result: LogIndexResult = detect(
+            &[1000, 500],
+            &[
+                comparison(ONE_DAY_SEC, &[1000, 1000]),
+                comparison(7 * ONE_DAY_SEC, &[]),
+            ],
+        );
+
+        assert!(result.alert_yn);
+        assert_eq!(result.alert_rules, vec![AlertRule::SeasonalDeviation]);
+        assert_eq!(result.direction, FluctuationDirection::Drop);
+        assert_eq!(result.fluctuation_val, 50.0);
+    }
+
+    #[test]
+    fn deviation_within_warning_band_reports_warning() {
+        let index_config: IndexConfig =
+            test_index_config(json!({ "seasonal_band": 20.0, "warning_seasonal_band": 5.0 }));
+        let detector: SeasonalDetectorImpl = SeasonalDetectorImpl::new();
+
+        let warning: LogIndexResult = detector.detect(
+            &index_config,
+            &test_samples(test_now(), &[1000, 920]),
+            &[comparison(ONE_DAY_SEC, &[1000, 1000])],
+            test_now(),
+        );
+        let critical: LogIndexResult = detector.detect(
+            &index_config,
+            &test_samples(test_now(), &[1000, 500]),
+            &[comparison(ONE_DAY_SEC, &[1000, 1000])],
+            test_now(),
+        );
+
+        assert_eq!(warning.alert_rules, vec![AlertRule::SeasonalDeviation]);
+        assert_eq!(warning.severity, AlertSeverity::Warning);
+        assert_eq!(critical.alert_rules, vec![AlertRule::SeasonalDeviation]);
+        assert_eq!(critical.severity, AlertSeverity::Critical);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::common::*;
+
+use crate::detector::detector_common::*;
+
+use crate::model::index::{alert_index::*, index_config::*};
+
+use crate::dto::{comparison_window::*, log_index_result::*};
+
+use crate::enums::{alert_rule::*, alert_severity::*, fluctuation_direction::*};
+
+use crate::traits::detector_traits::detector::*;
+
+use crate::utils_modules::time_utils::*;
+
+#[derive(Debug, Clone, new)]
+pub struct ThresholdDetectorImpl;
+
+impl ThresholdDetectorImpl {
+    #[doc = r#"
+        인덱스 설정에 정의된 규칙들을 각각 독립적으로 검사하여 위반한 규칙 목록을 반환하는 헬퍼 함수.
+
+        1. 변동률(%) 규칙: 변동 방향별 허용치(`fluctuation_limit`) 이상이면 위반
+        2. 변동량(건) 규칙: 구간 내 최대값 - 최소값이 `max_change_count` 초과 시 위반
+        3. 하한/상한 규칙: `evaluate_count_rules` 참고
+        4. 설정되지 않은 규칙은 검사하지 않음
+    "#]
+    fn evaluate_alert_rules(
+        index_config: &IndexConfig,
+        direction: FluctuationDirection,
+        flunct_val: f64,
+        min_val: f64,
+        max_val: f64,
+        cur_cnt: usize,
+    ) -> Vec<AlertRule> {
+        let mut alert_rules: Vec<AlertRule> = Vec::new();
+
+        if index_config
+            .fluctuation_limit(direction)
+            .is_some_and(|allowable| flunct_val >= allowable)
+        {
+            alert_rules.push(AlertRule::FluctuationRate);
+        }
+
+        if let Some(max_change_count) = index_config.max_change_count() {
+            let change_cnt: usize = (max_val - min_val).abs() as usize;
+            if change_cnt > *max_change_count {
+                alert_rules.push(AlertRule::ChangeCount);
+            }
+        }
+
+        alert_rules.extend(evaluate_count_rules(index_config, cur_cnt));
+
+        alert_rules
+    }
+
+    #[doc = r#"
+        Critical 규칙을 위반하지 않은 경우 Warning 임계치를 검사하여 위반한 규칙 목록을 반환하는 헬퍼 함수.
+
+        1. 변동률(%) 규칙: 변동 방향별 `warning_fluctuation_limit` 이상이면 위반
+        2. 변동량(건) 규칙: 구간 내 최대값 - 최소값이 `warning_change_count` 초과 시 위반
+    "#]
+    fn evaluate_warning_rules(
+        index_config: &IndexConfig,
+        direction: FluctuationDirection,
+        flunct_val: f64,
+        min_val: f64,
+        max_val: f64,
+    ) -> Vec<AlertRule> {
+        let mut alert_rules: Vec<AlertRule> = Vec::new();
+
+        if index_config
+            .warning_fluctuation_limit(direction)
+            .is_some_and(|allowable| flunct_val >= allowable)
+        {
+            alert_rules.push(AlertRule::FluctuationRate);
+        }
+
+        if let Some(warning_change_count) = index_config.warning_change_count() {
+            let change_cnt: usize = (max_val - min_val).abs() as usize;
+            if change_cnt > *warning_change_count {
+                alert_rules.push(AlertRule::ChangeCount);
+            }
+        }
+
+        alert_rules
+    }
+}
+
+impl Detector for ThresholdDetectorImpl {
+    fn lookback_sec(&self, index_config: &IndexConfig) -> i64 {
+        *index_config.agg_term_sec()
+    }
+
+    #[doc = r#"
+        이전 agg_term_sec 동안의 문서 수(`cnt`) 변동을 고정 허용치와 비교한다.
+
+        1. 구간 내 샘플의 최대/최소 값을 구한다
+        2. 구간의 최초/최종 샘플을 기준으로 변동 방향(감소/증가)을 판단
+        3. 최소값과 최대값을 기준으로 변화율(%)을 계산
+        4. `evaluate_alert_rules`를 통해 각 규칙을 독립적으로 검사
+        - 변동률(%): 변동 방향별 허용치(`drop_fluctuation_range`, `growth_fluctuation_range`) 이상
+          (`ignore_growth`가 설정된 경우 증가 방향은 제외)
+        - 변동량(건): `max_change_count` 초과
+        - 하한/상한: 현재 문서 수가 `min_doc_count` 미만 또는 `max_doc_count` 초과
+        5. Critical 규칙 위반이 없으면 `evaluate_warning_rules`로 Warning 임계치를 검사
+        6. 위반한 규칙이 있으면 구간 내 샘플과 심각도를 포함한 `LogIndexResult`를 반환
+    "#]
+    fn detect(
+        &self,
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+        _comparisons: &[ComparisonWindow],
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> LogIndexResult {
+        let index_name: &str = index_config.index_name();
+        let window_start_utc: DateTime<Utc> =
+            calc_time_window(cur_timestamp_utc, *index_config.agg_term_sec());
+
+        let window: Vec<&AlertIndex> = window_samples(samples, window_start_utc);
+
+        let (first, last) = match (window.first(), window.last()) {
+            (Some(first), Some(last)) => (*first.cnt() as f64, *last.cnt() as f64),
+            _ => return normal_result(index_name, 0.0, 0),
+        };
+
+        /* `Maximum` and `Minimum` values of index documents within a specific period  */
+        let min_val: f64 = window
+            .iter()
+            .map(|sample| *sample.cnt() as f64)
+            .fold(f64::INFINITY, f64::min);
+        let max_val: f64 = window
+            .iter()
+            .map(|sample| *sample.cnt() as f64)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        /* `First` and `Last` values of index documents -> direction of change */
+        let direction: FluctuationDirection = determine_direction(first, last, min_val, max_val);
+
+        let flunct_val: f64 = calculate_fluctuation(min_val, max_val);
+        let cur_cnt: usize = last as usize;
+
+        /* Each rule is checked independently. */
+        let mut alert_rules: Vec<AlertRule> = Self::evaluate_alert_rules(
+            index_config,
+            direction,
+            flunct_val,
+            min_val,
+            max_val,
+            cur_cnt,
+        );
+        let mut severity: AlertSeverity = AlertSeverity::Critical;
+
+        /* No critical violation -> check the warning thresholds. */
+        if alert_rules.is_empty() {
+            alert_rules =
+                Self::evaluate_warning_rules(index_config, direction, flunct_val, min_val, max_val);
+            severity = AlertSeverity::Warning;
+        }
+
+        let alert_yn: bool = !alert_rules.is_empty();
+
+        let mut log_index_res: LogIndexResult = LogIndexResult::new(
+            index_name.to_string(),
+            alert_yn,
+            alert_yn.then(|| recent_alert_history(&window)),
+            round_fluctuation(flunct_val),
+            cur_cnt,
+            direction,
+            alert_rules,
+            None,
+            None,
+            None,
+        );
+        log_index_res.set_severity(severity);
+
+        log_index_res
+    }
+}
@@ -2,6 +2,8 @@ use crate::common::*;
 
 use crate::enums::index_status::*;
 
+use crate::dto::breakdown_change::*;
+
 #[derive(Debug, Clone, Getters, new)]
 #[getset(get = "pub")]
 pub struct AlarmIndexDetailInfo {
@@ -11,4 +13,18 @@ pub struct AlarmIndexDetailInfo {
     pub difference: usize,
     pub difference_percent: f64,
     pub alarm_cnt: u64,
+    /* 대상 클러스터명 - 리포트에서 클러스터별 섹션을 나누는 기준 */
+    #[new(default)]
+    pub cluster_name: String,
+    /* 리포트 기간 시작/종료 시점의 저장 용량(byte) - _stats 수집 샘플이 있는 경우 */
+    #[new(default)]
+    pub start_store_size: Option<u64>,
+    #[new(default)]
+    pub end_store_size: Option<u64>,
+    /* 리포트 기간 종료 시점의 원본(SQL Server) 테이블 행 수 - reconcile 이 설정된 경우 */
+    #[new(default)]
+    pub end_source_row_cnt: Option<usize>,
+    /* breakdown_field 기준 리포트 기간 동안 변동량이 가장 큰 버킷 목록 */
+    #[new(default)]
+    pub top_buckets: Vec<BreakdownChange>,
 }
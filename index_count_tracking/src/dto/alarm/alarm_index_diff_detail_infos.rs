@@ -8,4 +8,7 @@ pub struct AlarmIndexDiffDetailInfo {
     pub min_index_cnt: u64,
     pub difference: u64,
     pub difference_percent: f64,
+    /* 대상 클러스터명 - 리포트에서 클러스터별 섹션을 나누는 기준 */
+    #[new(default)]
+    pub cluster_name: String,
 }
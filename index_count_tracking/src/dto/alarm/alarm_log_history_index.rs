@@ -1,9 +1,82 @@
 use crate::common::*;
 
+use crate::enums::{alert_rule::*, alert_severity::*, alert_status::*};
+
+use crate::utils_modules::traits::*;
+
 #[derive(Debug, Clone, Serialize, Deserialize, new)]
+#[allow(clippy::too_many_arguments)]
 pub struct AlarmLogHistoryIndex {
     pub index_name: String,
+    /* 대상 클러스터명 - 다중 클러스터 지원 이전 이력은 None */
+    #[serde(default)]
+    #[new(default)]
+    pub cluster_name: Option<String>,
     pub index_cnt: usize,
     pub fluctuation_val: f64,
     pub timestamp: String,
+    #[serde(default)]
+    pub alert_rules: Vec<AlertRule>,
+    #[serde(default)]
+    #[new(default)]
+    pub severity: AlertSeverity,
+    /* 알람 진행 상태 - 재시작 시 알람 상태 복원에 사용 */
+    #[serde(default)]
+    pub alert_status: AlertStatus,
+    #[serde(default)]
+    pub incident_started_at: Option<String>,
+    #[serde(default)]
+    pub last_notified_at: Option<String>,
+    /* 이 이력에서 실제로 알람을 발송했는지 여부 */
+    #[serde(default)]
+    pub notified: bool,
+    /* 마지막 알람 발송 이후 억제된 알람 수 / 이번 장애에서 이미 알람을 발송한 규칙 - 재시작 시 알람 상태 복원에 사용 */
+    #[serde(default)]
+    #[new(default)]
+    pub suppressed_cnt: u64,
+    #[serde(default)]
+    #[new(default)]
+    pub notified_rules: Vec<AlertRule>,
+    /* 장애 시작 시점의 문서 수 / 장애 기간 중 최대 변동률(%) */
+    #[serde(default)]
+    #[new(default)]
+    pub start_cnt: Option<usize>,
+    #[serde(default)]
+    #[new(default)]
+    pub peak_fluctuation_val: Option<f64>,
+    /* 장애 해소 이력(alert_status = resolved)에만 기록 */
+    #[serde(default)]
+    #[new(default)]
+    pub resolved_at: Option<String>,
+    #[serde(default)]
+    #[new(default)]
+    pub incident_duration_sec: Option<i64>,
+    /* 사일런스(점검 시간) 중 기록된 이력 여부 - 알람 발송 없이 기록만 됨 */
+    #[serde(default)]
+    #[new(default)]
+    pub suppressed: bool,
+}
+
+impl FromAggBucket for AlarmLogHistoryIndex {
+    fn from_agg_bucket(bucket: &Value) -> Result<Self, anyhow::Error> {
+        /* latest.hits.hits[0]._source */
+        let source: &Value = bucket
+            .get("latest")
+            .and_then(|latest| latest.get("hits"))
+            .and_then(|hits| hits.get("hits"))
+            .and_then(|hits| hits.get(0))
+            .and_then(|first| first.get("_source"))
+            .ok_or_else(|| {
+                anyhow!(
+                    "[AlarmLogHistoryIndex->from_agg_bucket] Missing 'latest.hits.hits[0]._source'"
+                )
+            })?;
+
+        serde_json::from_value(source.to_owned()).map_err(|e| {
+            anyhow!(
+                "[AlarmLogHistoryIndex->from_agg_bucket] Failed to deserialize source: {}",
+                e
+            )
+        })
+    }
 }
@@ -0,0 +1,29 @@
+use crate::common::*;
+
+use crate::dto::{
+    alarm::{alarm_index_detail_info::*, alarm_index_diff_detail_infos::*},
+    pair_consistency_info::*,
+};
+
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct DailyReportContents {
+    /* 리포트 시작 시점 전체 문서 수 */
+    pub start_time_all_index_cnt: usize,
+    /* 리포트 종료 시점 전체 문서 수 */
+    pub end_time_all_index_cnt: usize,
+    /* 시작/종료 시점 전체 문서 수 변동량 */
+    pub total_difference: usize,
+    /* 알람 발생 인덱스 수 */
+    pub alaram_index_cnt: u64,
+    /* 전체 알람 발생 건수 */
+    pub total_alarm_cnt: u64,
+    /* 인덱스별 상세 정보 */
+    pub alarm_index_details: Vec<AlarmIndexDetailInfo>,
+    /* 인덱스별 최대 변동 정보 */
+    pub alarm_index_diff_details: Vec<AlarmIndexDiffDetailInfo>,
+    /* primary/replica 쌍별 정합성 정보 */
+    pub pair_consistency_infos: Vec<PairConsistencyInfo>,
+    /* 쌍 이름별 알람 발생 건수 */
+    pub pair_alarm_cnts: HashMap<String, u64>,
+}
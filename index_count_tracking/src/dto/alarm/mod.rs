@@ -3,3 +3,4 @@ pub mod alarm_index_detail_info;
 pub mod alarm_index_diff_detail_infos;
 pub mod alarm_log_history_index;
 pub mod alarm_report_infos;
+pub mod daily_report_contents;
@@ -0,0 +1,80 @@
+use crate::common::*;
+
+use crate::model::index::breakdown_bucket::*;
+
+/* 알람/리포트에 표시할 변동 상위 버킷 수 */
+pub const TOP_BREAKDOWN_CHANGE_CNT: usize = 3;
+
+#[doc = "breakdown_field 버킷별 문서 수 변동 (구간 시작 -> 구간 끝)"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct BreakdownChange {
+    pub key: String,
+    pub prev_cnt: usize,
+    pub cur_cnt: usize,
+    pub change: i64,
+}
+
+impl BreakdownChange {
+    #[doc = r#"
+        두 시점의 버킷별 문서 수를 비교하여 변동량(절대값)이 큰 순서로 상위 `top_n`개를 반환하는 함수.
+
+        한쪽 시점에만 존재하는 버킷은 다른 시점의 문서 수를 0으로 간주하며, 변동이 없는 버킷은 제외한다.
+    "#]
+    pub fn top_changes(
+        prev_buckets: &[BreakdownBucket],
+        cur_buckets: &[BreakdownBucket],
+        top_n: usize,
+    ) -> Vec<BreakdownChange> {
+        let mut keys: Vec<&String> = prev_buckets
+            .iter()
+            .chain(cur_buckets.iter())
+            .map(|bucket| bucket.key())
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        let cnt_of = |buckets: &[BreakdownBucket], key: &str| -> usize {
+            buckets
+                .iter()
+                .find(|bucket| bucket.key() == key)
+                .map_or(0, |bucket| *bucket.cnt())
+        };
+
+        let mut changes: Vec<BreakdownChange> = keys
+            .into_iter()
+            .map(|key| {
+                let prev_cnt: usize = cnt_of(prev_buckets, key);
+                let cur_cnt: usize = cnt_of(cur_buckets, key);
+                BreakdownChange::new(
+                    key.to_string(),
+                    prev_cnt,
+                    cur_cnt,
+                    cur_cnt as i64 - prev_cnt as i64,
+                )
+            })
+            .filter(|change| change.change != 0)
+            .collect();
+
+        changes.sort_by_key(|change| std::cmp::Reverse(change.change.unsigned_abs()));
+        changes.truncate(top_n);
+        changes
+    }
+}
+
+#[doc = "버킷별 변동 목록을 `key: prev → cur (+change)` 형태로 연결한 문자열로 변환"]
+pub fn join_breakdown_changes(changes: &[BreakdownChange], separator: &str) -> String {
+    changes
+        .iter()
+        .map(|change| {
+            format!(
+                "{}: {} → {} ({:+})",
+                change.key(),
+                change.prev_cnt(),
+                change.cur_cnt(),
+                change.change()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(separator)
+}
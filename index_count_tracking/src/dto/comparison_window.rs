@@ -0,0 +1,17 @@
+use crate::common::*;
+
+use crate::model::index::alert_index::*;
+
+#[doc = r#"
+    기준 시각으로부터 `offset_sec` 만큼 이전의 동일한 길이 구간에 저장된 샘플 묶음.
+
+    # Fields
+    * `offset_sec` - 기준 시각 대비 비교 구간의 offset(초) (예: 86400 = 1일 전)
+    * `samples` - 비교 구간의 샘플 (timestamp 오름차순, UTC)
+"#]
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct ComparisonWindow {
+    pub offset_sec: i64,
+    pub samples: Vec<AlertIndex>,
+}
@@ -0,0 +1,17 @@
+use crate::common::*;
+
+#[doc = r#"
+    평가(조회/탐지) 중 오류가 발생한 인덱스 정보 (모니터링 저하 알림용)
+
+    # Fields
+    * `index_name` - 평가에 실패한 인덱스 이름
+    * `cluster_name` - 대상 클러스터명
+    * `error` - 실패 원인 메시지
+"#]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct EvaluationFailure {
+    pub index_name: String,
+    pub cluster_name: String,
+    pub error: String,
+}
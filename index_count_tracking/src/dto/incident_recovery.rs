@@ -0,0 +1,35 @@
+use crate::common::*;
+
+use crate::enums::alert_severity::*;
+
+#[doc = r#"
+    정상 범위로 복귀한 인덱스의 장애 요약 정보 (복구 알림용)
+
+    # Fields
+    * `cluster_name` - 대상 클러스터명
+    * `incident_started_at` - 장애 시작 시각 (UTC 문자열)
+    * `resolved_at` - 장애 해소 시각 (UTC 문자열)
+    * `duration_sec` - 장애 지속 시간(초)
+    * `peak_fluctuation` - 장애 기간 중 최대 변동률(%)
+    * `start_cnt` - 장애 시작 시점의 문서 수
+    * `end_cnt` - 장애 해소 시점의 문서 수
+    * `severity` - 장애의 알람 심각도 (복구 알림 발송 채널 결정에 사용)
+    * `suppressed` - 사일런스 중이거나 장애 알람이 발송되지 않아 복구 알림을 생략하는지 여부
+"#]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, Setters, new)]
+#[getset(get = "pub", set = "pub")]
+pub struct IncidentRecovery {
+    pub index_name: String,
+    #[new(default)]
+    pub cluster_name: String,
+    pub incident_started_at: String,
+    pub resolved_at: String,
+    pub duration_sec: i64,
+    pub peak_fluctuation: f64,
+    pub start_cnt: usize,
+    pub end_cnt: usize,
+    #[new(default)]
+    pub severity: AlertSeverity,
+    #[new(default)]
+    pub suppressed: bool,
+}
@@ -0,0 +1,40 @@
+use crate::common::*;
+
+#[doc = r#"
+    원본/복제 클러스터 문서 수 정합성 검증 결과 (모니터링 인덱스에 기록되는 페어 샘플)
+
+    인덱스 샘플(`AlertIndex`)과 섞이지 않도록 `index_name` 필드를 사용하지 않는다.
+
+    # Fields
+    * `pair_name` - 페어명 (`IndexPairConfig::pair_name`)
+    * `primary_cluster` / `primary_index` - 원본 클러스터명 / 인덱스명
+    * `replica_cluster` / `replica_index` - 복제 클러스터명 / 인덱스명
+    * `primary_cnt` / `replica_cnt` - 원본 / 복제본 문서 수
+    * `diff_cnt` - 문서 수 차이(건)
+    * `diff_percent` - 원본 문서 수 대비 차이율(%)
+    * `diverged` - 허용치를 넘는 불일치 여부
+    * `timestamp` - 검증 시각 (UTC 문자열)
+"#]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[allow(clippy::too_many_arguments)]
+#[getset(get = "pub")]
+pub struct IndexPairSample {
+    pub pair_name: String,
+    pub primary_cluster: String,
+    pub primary_index: String,
+    pub replica_cluster: String,
+    pub replica_index: String,
+    pub primary_cnt: usize,
+    pub replica_cnt: usize,
+    pub diff_cnt: usize,
+    pub diff_percent: f64,
+    pub diverged: bool,
+    pub timestamp: String,
+}
+
+impl IndexPairSample {
+    #[doc = "페어 샘플 문서 ID - 다시 색인해도 중복되지 않도록 `{pair_name}|{timestamp}` 사용"]
+    pub fn doc_id(&self) -> String {
+        format!("{}|{}", self.pair_name, self.timestamp)
+    }
+}
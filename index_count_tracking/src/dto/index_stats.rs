@@ -0,0 +1,55 @@
+use crate::common::*;
+
+#[doc = "`_stats` API 로 조회한 인덱스 통계 (primaries 기준)"]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct IndexStats {
+    /* 저장 용량(byte) */
+    pub store_size_bytes: u64,
+    /* 삭제 표시된(merge 전) 문서 수 */
+    pub docs_deleted: u64,
+    /* 누적 색인 건수 */
+    pub indexing_total: u64,
+    /* 누적 삭제 건수 */
+    pub delete_total: u64,
+    /* 세그먼트 수 */
+    pub segment_cnt: u64,
+}
+
+impl IndexStats {
+    #[doc = "`_stats` 응답에서 `_all.primaries` 통계를 추출하는 함수"]
+    pub fn from_stats_response(response_body: &Value) -> anyhow::Result<Self> {
+        let primaries: &Value = &response_body["_all"]["primaries"];
+
+        if primaries.is_null() {
+            return Err(anyhow!(
+                "[IndexStats->from_stats_response] _all.primaries is missing"
+            ));
+        }
+
+        Ok(Self::from_primaries(primaries))
+    }
+
+    #[doc = "`_stats` 응답의 `primaries` 섹션을 `IndexStats`로 변환하는 함수 - 없는 값은 0"]
+    pub fn from_primaries(primaries: &Value) -> Self {
+        let metric =
+            |section: &str, field: &str| -> u64 { primaries[section][field].as_u64().unwrap_or(0) };
+
+        IndexStats::new(
+            metric("store", "size_in_bytes"),
+            metric("docs", "deleted"),
+            metric("indexing", "index_total"),
+            metric("indexing", "delete_total"),
+            metric("segments", "count"),
+        )
+    }
+
+    #[doc = "다른 인덱스의 통계를 합산하는 함수 (글롭 패턴 대상)"]
+    pub fn accumulate(&mut self, other: &IndexStats) {
+        self.store_size_bytes += other.store_size_bytes;
+        self.docs_deleted += other.docs_deleted;
+        self.indexing_total += other.indexing_total;
+        self.delete_total += other.delete_total;
+        self.segment_cnt += other.segment_cnt;
+    }
+}
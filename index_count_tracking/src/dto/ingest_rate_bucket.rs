@@ -0,0 +1,32 @@
+use crate::common::*;
+
+use crate::utils_modules::traits::*;
+
+#[doc = "timestamp_field date_histogram 집계의 구간별 신규 문서 수"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct IngestRateBucket {
+    /* 구간 시작 시각 (epoch millis) */
+    pub key_millis: i64,
+    pub cnt: usize,
+}
+
+impl FromAggBucket for IngestRateBucket {
+    fn from_agg_bucket(bucket: &Value) -> anyhow::Result<Self> {
+        let key_millis: i64 = bucket.get("key").and_then(|v| v.as_i64()).ok_or_else(|| {
+            anyhow!("[IngestRateBucket->from_agg_bucket] bucket.key is missing or not an i64")
+        })?;
+
+        let cnt: usize = bucket
+            .get("doc_count")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| {
+                anyhow!(
+                    "[IngestRateBucket->from_agg_bucket] bucket.doc_count is missing or not a u64"
+                )
+            })?
+            .try_into()?;
+
+        Ok(IngestRateBucket { key_millis, cnt })
+    }
+}
@@ -2,12 +2,64 @@ use crate::common::*;
 
 use crate::model::index::alert_index::*;
 
-#[derive(Serialize, Deserialize, Debug, Getters, Setters, new)]
+use crate::dto::{breakdown_change::*, index_pair_sample::*, index_stats::*};
+
+use crate::enums::{alert_rule::*, alert_severity::*, alert_status::*, fluctuation_direction::*};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Getters, Setters, new)]
+#[allow(clippy::too_many_arguments)]
 #[getset(get = "pub", set = "pub")]
 pub struct LogIndexResult {
     pub index_name: String,
+    /* 대상 클러스터명 */
+    #[new(default)]
+    pub cluster_name: String,
     pub alert_yn: bool,
     pub alert_index_format: Option<Vec<AlertIndex>>,
     pub fluctuation_val: f64,
     pub cur_cnt: usize,
+    pub direction: FluctuationDirection,
+    pub alert_rules: Vec<AlertRule>,
+    /* 색인 정체(stall) 알람인 경우, 문서 수 변화가 없었던 기간(초) */
+    pub stall_sec: Option<i64>,
+    /* 통계 기반 탐지기(EWMA/MAD)를 사용한 경우, 기준선 대비 이상 점수 */
+    pub anomaly_score: Option<f64>,
+    /* 계절성 탐지기를 사용한 경우, 1일/7일 전 동일 구간으로부터 기대한 변동량(건) */
+    pub expected_change: Option<f64>,
+    /* 신선도 알람인 경우, 최신 문서가 현재 시각보다 뒤처진 시간(초) */
+    #[new(default)]
+    pub freshness_lag_sec: Option<i64>,
+    /* 색인 속도 알람인 경우, 직전 집계 구간의 신규 색인 문서 수 */
+    #[new(default)]
+    pub ingest_cnt: Option<usize>,
+    /* 인덱스 통계 알람인 경우, 최신 _stats 값 */
+    #[new(default)]
+    pub index_stats: Option<IndexStats>,
+    /* 원본 정합성 알람인 경우, 원본(SQL Server) 테이블 행 수 */
+    #[new(default)]
+    pub source_row_cnt: Option<usize>,
+    /* 알람 심각도 (Warning / Critical) */
+    #[new(default)]
+    pub severity: AlertSeverity,
+    /* 알람 진행 상태 (신규 발생 / 지속 중) */
+    #[new(default)]
+    pub alert_status: AlertStatus,
+    /* 이번 주기에 알람을 발송해야 하는지 여부 (중복 알람 억제 결과) */
+    #[new(default)]
+    pub notify_yn: bool,
+    /* 장애 시작 시각 (UTC 문자열) */
+    #[new(default)]
+    pub incident_started_at: Option<String>,
+    /* 마지막 알람 발송 이후 억제된 알람 수 */
+    #[new(default)]
+    pub suppressed_cnt: u64,
+    /* 사일런스(점검 시간)로 인해 알람이 억제되었는지 여부 */
+    #[new(default)]
+    pub suppressed: bool,
+    /* breakdown_field 기준 변동량이 가장 큰 버킷 목록 */
+    #[new(default)]
+    pub breakdown_changes: Vec<BreakdownChange>,
+    /* 복제 정합성 알람인 경우, 원본/복제본 문서 수 비교 결과 */
+    #[new(default)]
+    pub pair_sample: Option<IndexPairSample>,
 }
@@ -1,4 +1,13 @@
 pub mod alarm;
+pub mod breakdown_change;
+pub mod comparison_window;
+pub mod evaluation_failure;
+pub mod incident_recovery;
 pub mod index_count_agg_result;
 pub mod index_name_count;
+pub mod index_pair_sample;
+pub mod index_stats;
+pub mod ingest_rate_bucket;
 pub mod log_index_result;
+pub mod pair_consistency_info;
+pub mod spool_entry;
@@ -0,0 +1,58 @@
+use crate::common::*;
+
+use crate::dto::index_pair_sample::*;
+
+use crate::utils_modules::traits::*;
+
+#[doc = r#"
+    리포트 기간 동안의 페어별 복제 정합성 요약 (리포트 복제 정합성 테이블용)
+
+    # Fields
+    * `latest` - 기간 내 마지막 페어 샘플
+    * `max_diff_cnt` - 기간 중 최대 문서 수 차이(건)
+    * `sample_cnt` - 기간 내 페어 샘플 수
+    * `diverged_cnt` - 기간 내 허용치를 넘은 샘플 수
+"#]
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct PairConsistencyInfo {
+    pub latest: IndexPairSample,
+    pub max_diff_cnt: u64,
+    pub sample_cnt: u64,
+    pub diverged_cnt: u64,
+}
+
+impl FromAggBucket for PairConsistencyInfo {
+    fn from_agg_bucket(bucket: &Value) -> anyhow::Result<Self> {
+        /* latest.hits.hits[0]._source */
+        let source: &Value = bucket
+            .get("latest")
+            .and_then(|latest| latest.get("hits"))
+            .and_then(|hits| hits.get("hits"))
+            .and_then(|hits| hits.get(0))
+            .and_then(|first| first.get("_source"))
+            .ok_or_else(|| {
+                anyhow!(
+                    "[PairConsistencyInfo->from_agg_bucket] Missing 'latest.hits.hits[0]._source'"
+                )
+            })?;
+
+        let latest: IndexPairSample = serde_json::from_value(source.to_owned()).map_err(|e| {
+            anyhow!(
+                "[PairConsistencyInfo->from_agg_bucket] Failed to deserialize source: {}",
+                e
+            )
+        })?;
+
+        let max_diff_cnt: u64 = bucket["max_diff_cnt"]["value"].as_f64().unwrap_or(0.0) as u64;
+        let sample_cnt: u64 = bucket["doc_count"].as_u64().unwrap_or(0);
+        let diverged_cnt: u64 = bucket["diverged"]["doc_count"].as_u64().unwrap_or(0);
+
+        Ok(PairConsistencyInfo::new(
+            latest,
+            max_diff_cnt,
+            sample_cnt,
+            diverged_cnt,
+        ))
+    }
+}
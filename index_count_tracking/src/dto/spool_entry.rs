@@ -0,0 +1,22 @@
+use crate::common::*;
+
+#[doc = r#"
+    모니터링 클러스터에 색인하지 못해 로컬 스풀 파일에 보관한 문서 한 건
+
+    문서(`document`)는 수집 당시의 timestamp 를 그대로 가지고 있고, `index_name` 도 수집 당시의
+    일자별 인덱스명이므로 재전송 시 원래 시각/인덱스에 그대로 기록된다.
+    문서 ID 가 있으면 같은 ID 로 재전송하므로, 이미 색인된 문서를 다시 보내도 덮어쓸 뿐 중복되지 않는다.
+
+    # Fields
+    * `index_name` - 색인 대상 인덱스명
+    * `doc_id` - 문서 ID (없으면 Elasticsearch 가 생성 - 이전 버전 스풀 파일 포함)
+    * `document` - 색인할 문서 (JSON)
+"#]
+#[derive(Debug, Clone, Deserialize, Serialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct SpoolEntry {
+    pub index_name: String,
+    #[serde(default)]
+    pub doc_id: Option<String>,
+    pub document: Value,
+}
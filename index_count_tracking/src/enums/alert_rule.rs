@@ -0,0 +1,71 @@
+use crate::common::*;
+
+#[doc = "알람을 발생시킨 규칙의 종류"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertRule {
+    /* 변동률(%) 허용치 초과 */
+    FluctuationRate,
+    /* 구간 내 문서 변동량(건) 허용치 초과 */
+    ChangeCount,
+    /* 현재 문서 수가 하한(floor) 미만 */
+    FloorCount,
+    /* 현재 문서 수가 상한(ceiling) 초과 */
+    CeilingCount,
+    /* 기대 활동 주기 동안 문서 수 변화 없음 (색인 정체) */
+    Stalled,
+    /* 최신 문서의 timestamp 가 현재 시각보다 허용치 이상 뒤처짐 (데이터 신선도) */
+    FreshnessLag,
+    /* 구간별 신규 색인 문서 수가 하한 미만 */
+    IngestRateFloor,
+    /* 구간별 신규 색인 문서 수가 상한 초과 */
+    IngestRateCeiling,
+    /* 저장 용량이 상한 초과 */
+    StoreSizeCeiling,
+    /* 삭제 표시된(merge 전) 문서 수가 상한 초과 */
+    DeletedDocsCeiling,
+    /* 구간 내 삭제 건수가 상한 초과 (대량 삭제) */
+    DeleteCountCeiling,
+    /* 세그먼트 수가 상한 초과 (merge 이상) */
+    SegmentCountCeiling,
+    /* 원본/복제 클러스터 간 문서 수 차이가 유예 시간 이상 허용치 초과 */
+    PairDivergence,
+    /* 원본(SQL Server) 테이블 행 수와 인덱스 문서 수 차이가 허용치 초과 */
+    SourceDrift,
+    /* 과거 이력으로 학습한 기준선 대비 이상 점수 초과 */
+    AnomalyScore,
+    /* 1일/7일 전 동일 구간의 변동량 대비 허용 범위 초과 */
+    SeasonalDeviation,
+}
+
+impl AlertRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertRule::FluctuationRate => "fluctuation_rate",
+            AlertRule::ChangeCount => "change_count",
+            AlertRule::FloorCount => "floor_count",
+            AlertRule::CeilingCount => "ceiling_count",
+            AlertRule::Stalled => "stalled",
+            AlertRule::FreshnessLag => "freshness_lag",
+            AlertRule::IngestRateFloor => "ingest_rate_floor",
+            AlertRule::IngestRateCeiling => "ingest_rate_ceiling",
+            AlertRule::StoreSizeCeiling => "store_size_ceiling",
+            AlertRule::DeletedDocsCeiling => "deleted_docs_ceiling",
+            AlertRule::DeleteCountCeiling => "delete_count_ceiling",
+            AlertRule::SegmentCountCeiling => "segment_count_ceiling",
+            AlertRule::PairDivergence => "pair_divergence",
+            AlertRule::SourceDrift => "source_drift",
+            AlertRule::AnomalyScore => "anomaly_score",
+            AlertRule::SeasonalDeviation => "seasonal_deviation",
+        }
+    }
+}
+
+#[doc = "알람 규칙 목록을 사람이 읽기 쉬운 문자열로 변환"]
+pub fn join_alert_rules(alert_rules: &[AlertRule]) -> String {
+    alert_rules
+        .iter()
+        .map(|rule| rule.as_str())
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
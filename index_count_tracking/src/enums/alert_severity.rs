@@ -0,0 +1,29 @@
+use crate::common::*;
+
+#[doc = "알람 심각도 - 심각도에 따라 알람 발송 채널이 달라진다. (Warning < Critical)"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertSeverity {
+    /* 변동률/변동량(threshold), 이상 점수(ewma/mad), 계절성 편차(seasonal)의 warning_* 허용치 위반
+    - 문서 수 하한/상한, 색인 정체, 신선도, 색인 속도, _stats, 정합성 규칙은 Warning 단계 없이 항상 Critical */
+    Warning,
+    /* 기존 임계치(allowable_fluctuation_range 등) 위반은 모두 Critical */
+    #[default]
+    Critical,
+}
+
+impl AlertSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertSeverity::Warning => "Warning",
+            AlertSeverity::Critical => "Critical",
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            AlertSeverity::Warning => "🟡",
+            AlertSeverity::Critical => "🔴",
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::common::*;
+
+#[doc = "인덱스 알람의 진행 상태"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertStatus {
+    /* 새로 발생한 알람 */
+    #[default]
+    Firing,
+    /* 이미 발생 중인 알람 - 재알림 주기에 맞춰 요약 발송 */
+    StillFiring,
+    /* 장애 해소 (정상 범위로 복귀) */
+    Resolved,
+}
+
+impl AlertStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertStatus::Firing => "Firing",
+            AlertStatus::StillFiring => "Still firing",
+            AlertStatus::Resolved => "Resolved",
+        }
+    }
+}
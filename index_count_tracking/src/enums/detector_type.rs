@@ -0,0 +1,16 @@
+use crate::common::*;
+
+#[doc = "인덱스별로 사용할 이상 탐지기 종류"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectorType {
+    /* 고정 변동률(%)/변동량(건) 기반 탐지 */
+    #[default]
+    Threshold,
+    /* 지수가중이동평균(EWMA) 기반 z-score 탐지 */
+    Ewma,
+    /* 중앙값 절대편차(MAD) 기반 탐지 */
+    Mad,
+    /* 1일/7일 전 동일 구간 대비 계절성 기준선 탐지 */
+    Seasonal,
+}
@@ -0,0 +1,28 @@
+use crate::common::*;
+
+#[doc = "모니터링 구간 내 문서 수 변동 방향 - 시간순 샘플(최초/최종)을 기준으로 판단한다."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FluctuationDirection {
+    Drop,
+    Growth,
+    Flat,
+}
+
+impl FluctuationDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FluctuationDirection::Drop => "Drop",
+            FluctuationDirection::Growth => "Growth",
+            FluctuationDirection::Flat => "Flat",
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            FluctuationDirection::Drop => "📉",
+            FluctuationDirection::Growth => "📈",
+            FluctuationDirection::Flat => "➖",
+        }
+    }
+}
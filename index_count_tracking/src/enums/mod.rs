@@ -1,3 +1,10 @@
+pub mod alert_rule;
+pub mod alert_severity;
+pub mod alert_status;
+pub mod detector_type;
+pub mod fluctuation_direction;
 pub mod index_status;
 pub mod report_type;
 pub mod sort_order;
+pub mod storage_backend;
+pub mod target_mode;
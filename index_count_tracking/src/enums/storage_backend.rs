@@ -0,0 +1,12 @@
+use crate::common::*;
+
+#[doc = "모니터링 샘플(시계열) 저장소 종류"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackend {
+    /* 모니터링 Elasticsearch 클러스터의 일자별 인덱스 */
+    #[default]
+    Elasticsearch,
+    /* 로컬 디스크의 일자별 JSON Lines 파일 (모니터링 클러스터 없이 동작) */
+    Local,
+}
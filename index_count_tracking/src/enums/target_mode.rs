@@ -0,0 +1,12 @@
+use crate::common::*;
+
+#[doc = "글롭 패턴/별칭/데이터 스트림 대상의 모니터링 방식"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TargetMode {
+    /* 대상에 해당하는 모든 인덱스의 문서 수를 합산하여 하나의 시계열로 기록 */
+    #[default]
+    Sum,
+    /* resolve index API 로 찾은 실제 인덱스마다 별도의 시계열로 기록 */
+    Expand,
+}
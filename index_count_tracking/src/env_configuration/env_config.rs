@@ -125,3 +125,16 @@ pub static DAILY_REPORT_TEMPLATE_PATH: once_lazy<String> =
 // #[doc = "Function to globally initialize the 'INDEX_ALERT_TEMPLATE_PATH' variable"]
 // pub static INDEX_ALERT_TEMPLATE_PATH: once_lazy<String> =
 //     once_lazy::new(|| String::from("html/index_alert_template.html"));
+
+#[doc = r#"
+    알람 사일런스(점검 시간) 설정 파일의 경로.
+
+    별도의 환경변수 없이 `INDEX_LIST_PATH`와 같은 디렉토리의 `silence_list.toml`을 사용한다.
+    파일이 없으면 사일런스가 없는 것으로 간주하며, 모니터링 주기마다 다시 읽어오므로
+    프로그램 재시작 없이 점검 시간을 추가/삭제할 수 있다.
+
+    # 예상 파일 내용
+    `[[silence]]` 배열 (인덱스명/글롭 패턴, cron 반복 또는 1회성 시작/만료 시각)
+"#]
+pub static SILENCE_LIST_PATH: once_lazy<PathBuf> =
+    once_lazy::new(|| Path::new(&*INDEX_LIST_PATH).with_file_name("silence_list.toml"));
@@ -1,17 +1,24 @@
 pub use chrono::{DateTime, Local, TimeZone, Utc};
 pub use deadpool_tiberius::{Manager, Pool};
 pub use elasticsearch::{
-    DeleteParts, Elasticsearch, IndexParts, SearchParts,
+    BulkParts, DeleteParts, Elasticsearch, IndexParts, MsearchParts, SearchParts,
     http::Url,
+    http::request::JsonBody,
     http::response::Response,
     http::transport::Transport as EsTransport,
     http::transport::{SingleNodeConnectionPool, TransportBuilder},
+    ilm::IlmPutLifecycleParts,
+    indices::{
+        IndicesDeleteParts, IndicesPutIndexTemplateParts, IndicesResolveIndexParts,
+        IndicesStatsParts,
+    },
+    params::Level,
 };
 pub use flexi_logger::{Age, Cleanup, Criterion, FileSpec, Logger, Naming, Record};
 pub use futures::{Future, stream::TryStreamExt};
 pub use lettre::{AsyncTransport, Transport};
 pub use once_cell::sync::Lazy as once_lazy;
-pub use rand::{SeedableRng, prelude::SliceRandom, rngs::StdRng};
+pub use rand::{Rng, SeedableRng, prelude::SliceRandom, rngs::StdRng};
 pub use reqwest::Client;
 pub use urlencoding::encode;
 
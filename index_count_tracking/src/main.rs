@@ -12,14 +12,18 @@ mod prelude;
 use common::*;
 
 mod repository;
-use repository::es_repository_impl::*;
+use repository::{es_repository_impl::*, time_series_repository_factory::*};
 
 mod env_configuration;
 
 mod traits;
+use traits::{
+    repository_traits::{alarm_history_repository::*, time_series_repository::*},
+    service_traits::query_service::*,
+};
 
 mod model;
-use model::configs::total_config::*;
+use model::configs::{index_bootstrap_config::*, total_config::*};
 
 mod utils_modules;
 use utils_modules::logger_utils::*;
@@ -35,7 +39,10 @@ use controller::main_controller::*;
 
 mod dto;
 
+mod detector;
+
 mod enums;
+use enums::storage_backend::*;
 
 #[tokio::main]
 async fn main() {
@@ -45,15 +52,6 @@ async fn main() {
 
     info!("Index Tracking program start!");
 
-    /* Elasticsearch connection */
-    let target_es_conn: Arc<EsRepositoryImpl> = Arc::new(
-        EsRepositoryImpl::new(get_elastic_config_info()).unwrap_or_else(|e| {
-            let err_msg: &str = "[main] An issue occurred while initializing target_es_conn.";
-            error!("{} {:?}", err_msg, e);
-            panic!("{} {:?}", err_msg, e);
-        }),
-    );
-
     let mon_es_conn: Arc<EsRepositoryImpl> = Arc::new(
         EsRepositoryImpl::new(get_mon_elastic_config_info()).unwrap_or_else(|e| {
             let err_msg: &str = "[main] An issue occurred while initializing mon_es_conn.";
@@ -62,6 +60,62 @@ async fn main() {
         }),
     );
 
+    /* Storage for the monitoring samples and alarm history - the monitor cluster or local files (storage.backend) */
+    let ts_repo: Arc<dyn TimeSeriesRepository> =
+        build_time_series_repo(get_storage_config_info(), Arc::clone(&mon_es_conn));
+    let alarm_repo: Arc<dyn AlarmHistoryRepository> =
+        build_alarm_history_repo(get_storage_config_info(), Arc::clone(&mon_es_conn));
+
+    /* Elasticsearch connection - one per target cluster */
+    let mut target_query_services: HashMap<String, QueryServiceImpl> = HashMap::new();
+
+    for cluster_config in get_target_cluster_configs() {
+        let target_es_conn: Arc<EsRepositoryImpl> =
+            Arc::new(EsRepositoryImpl::new(cluster_config).unwrap_or_else(|e| {
+                let err_msg: String = format!(
+                    "[main] An issue occurred while initializing target_es_conn ({}).",
+                    cluster_config.elastic_cluster_name()
+                );
+                error!("{} {:?}", err_msg, e);
+                panic!("{} {:?}", err_msg, e);
+            }));
+
+        target_query_services.insert(
+            cluster_config.elastic_cluster_name().to_string(),
+            QueryServiceImpl::new(
+                target_es_conn,
+                Arc::clone(&ts_repo),
+                Arc::clone(&alarm_repo),
+            ),
+        );
+    }
+
+    /* Index templates (explicit mappings) and ILM policies for the monitor/alarm indices */
+    let bootstrap_config: &IndexBootstrapConfig = get_index_bootstrap_config_info();
+
+    if *bootstrap_config.skip() {
+        info!("[main] Index template/ILM bootstrap skipped (index_bootstrap.skip = true)");
+    } else if *get_storage_config_info().backend() == StorageBackend::Local {
+        info!("[main] Index template/ILM bootstrap skipped (storage.backend = local)");
+    } else if let Err(e) = QueryServiceImpl::new(
+        Arc::clone(&mon_es_conn),
+        Arc::clone(&ts_repo),
+        Arc::clone(&alarm_repo),
+    )
+    .bootstrap_index_templates(
+        get_system_config_info().monitor_index_name(),
+        get_alarm_log_index_info().index_name(),
+        bootstrap_config,
+    )
+    .await
+    {
+        /* Monitoring still works with dynamic mapping, so a failure here is not fatal. */
+        error!(
+            "[main] Failed to bootstrap index templates/ILM policies: {:?}",
+            e
+        );
+    }
+
     /* ==================================================== */
     /* =============== Dependency Injection =============== */
     /* ==================================================== */
@@ -73,8 +127,12 @@ async fn main() {
 
     let tracking_monitor_service: TrackingServiceImpl<QueryServiceImpl, NotificationServiceImpl> =
         TrackingServiceImpl::new(
-            QueryServiceImpl::new(Arc::clone(&target_es_conn)),
-            QueryServiceImpl::new(Arc::clone(&mon_es_conn)),
+            target_query_services,
+            QueryServiceImpl::new(
+                Arc::clone(&mon_es_conn),
+                Arc::clone(&ts_repo),
+                Arc::clone(&alarm_repo),
+            ),
             Arc::clone(&notification_service),
         );
 
@@ -84,7 +142,11 @@ async fn main() {
         ChartServiceImpl,
         NotificationServiceImpl,
     > = ReportServiceImpl::new(
-        QueryServiceImpl::new(Arc::clone(&mon_es_conn)),
+        QueryServiceImpl::new(
+            Arc::clone(&mon_es_conn),
+            Arc::clone(&ts_repo),
+            Arc::clone(&alarm_repo),
+        ),
         chart_service,
         Arc::clone(&notification_service),
     );
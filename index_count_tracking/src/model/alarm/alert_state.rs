@@ -0,0 +1,61 @@
+use crate::common::*;
+
+use crate::enums::{alert_rule::*, alert_severity::*};
+
+#[doc = r#"
+    인덱스별 알람 진행 상태 (중복 알람 억제, 재알림 판단용)
+
+    # Fields
+    * `incident_started_at` - 현재(또는 마지막) 장애가 시작된 시각
+    * `last_fired_at` - 마지막으로 이상이 탐지된 시각
+    * `last_notified_at` - 마지막으로 알람을 발송한 시각
+    * `resolved_at` - 장애가 해소된 시각 (발생 중이면 None)
+    * `suppressed_cnt` - 마지막 알람 발송 이후 억제된 알람 수
+    * `peak_fluctuation` - 장애 기간 중 최대 변동률(%)
+    * `start_cnt` - 장애 시작 시점의 문서 수
+    * `last_cnt` - 마지막으로 이상이 탐지된 시점의 문서 수
+    * `severity` - 마지막으로 알람을 발송한 시점의 심각도 (심각도 상승 시 즉시 재알림)
+    * `notified_rules` - 이번 장애에서 이미 알람을 발송한 규칙 목록 (새 규칙이 발생하면 즉시 알림)
+"#]
+#[derive(Debug, Clone, Getters, Setters, new)]
+#[getset(get = "pub", set = "pub")]
+pub struct AlertState {
+    pub incident_started_at: DateTime<Utc>,
+    pub last_fired_at: DateTime<Utc>,
+    pub last_notified_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub suppressed_cnt: u64,
+    #[new(default)]
+    pub peak_fluctuation: f64,
+    #[new(default)]
+    pub start_cnt: usize,
+    #[new(default)]
+    pub last_cnt: usize,
+    #[new(default)]
+    pub severity: AlertSeverity,
+    #[new(default)]
+    pub notified_rules: Vec<AlertRule>,
+}
+
+impl AlertState {
+    #[doc = "장애가 발생 중인지 여부"]
+    pub fn is_firing(&self) -> bool {
+        self.resolved_at.is_none()
+    }
+
+    #[doc = "이번 장애에서 아직 알람을 발송하지 않은 규칙이 포함되어 있는지 여부"]
+    pub fn has_new_rules(&self, alert_rules: &[AlertRule]) -> bool {
+        alert_rules
+            .iter()
+            .any(|alert_rule| !self.notified_rules.contains(alert_rule))
+    }
+
+    #[doc = "알람을 발송한 규칙을 이번 장애의 발송 규칙 목록에 추가"]
+    pub fn add_notified_rules(&mut self, alert_rules: &[AlertRule]) {
+        for alert_rule in alert_rules {
+            if !self.notified_rules.contains(alert_rule) {
+                self.notified_rules.push(*alert_rule);
+            }
+        }
+    }
+}
@@ -1 +1,2 @@
-
+pub mod alert_state;
+pub mod silence_config;
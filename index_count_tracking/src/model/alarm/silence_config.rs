@@ -0,0 +1,288 @@
+use crate::common::*;
+
+use crate::utils_modules::time_utils::*;
+
+#[doc = r#"
+    알람 사일런스(점검 시간) 설정 - 사일런스 중에도 샘플/알람 이력은 기록하지만 알람은 발송하지 않는다.
+
+    # Fields
+    * `index_pattern` - 대상 인덱스명 또는 글롭 패턴 (예: `order_*`)
+    * `reason` - 사일런스 사유 (로그용)
+    * `cron_schedule` - 반복 점검 시작 시각 (로컬 시간 기준 cron, `ReportConfig::cron_schedule`과 동일한 형식)
+    * `duration_min` - 반복 점검 지속 시간(분)
+    * `start_at` - 1회성 점검 시작 시각 (`%Y-%m-%dT%H:%M:%S`, 로컬 시간 / 미설정 시 즉시)
+    * `expires_at` - 1회성 점검 만료 시각 (`%Y-%m-%dT%H:%M:%S`, 로컬 시간)
+"#]
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct SilenceConfig {
+    pub index_pattern: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub cron_schedule: Option<String>,
+    #[serde(default)]
+    pub duration_min: Option<i64>,
+    #[serde(default)]
+    pub start_at: Option<String>,
+    #[serde(default)]
+    pub expires_at: Option<String>,
+}
+
+impl SilenceConfig {
+    #[doc = "인덱스명이 사일런스 대상 패턴과 일치하는지 여부"]
+    pub fn matches(&self, index_name: &str) -> anyhow::Result<bool> {
+        let pattern: glob::Pattern = glob::Pattern::new(&self.index_pattern).map_err(|e| {
+            anyhow!(
+                "[SilenceConfig->matches] Invalid pattern '{}': {:?}",
+                self.index_pattern,
+                e
+            )
+        })?;
+
+        Ok(pattern.matches(index_name))
+    }
+
+    #[doc = r#"
+        현재 시각에 사일런스가 활성화되어 있는지 여부를 반환하는 함수.
+
+        1. 반복 점검(`cron_schedule`): `now - duration_min` 이후 첫 시작 시각이 `now` 이전이면 점검 중
+        2. 1회성 점검(`expires_at`): `start_at` <= `now` < `expires_at` 이면 점검 중
+        3. 둘 다 설정되지 않은 경우 오류 반환
+    "#]
+    pub fn is_active(&self, now: DateTime<Local>) -> anyhow::Result<bool> {
+        if let Some(cron_schedule) = &self.cron_schedule {
+            let schedule: cron::Schedule =
+                cron::Schedule::from_str(cron_schedule).map_err(|e| {
+                    anyhow!(
+                        "[SilenceConfig->is_active] Failed to parse cron schedule '{}': {:?}",
+                        cron_schedule,
+                        e
+                    )
+                })?;
+
+            let duration_min: i64 = self.duration_min.ok_or_else(|| {
+                anyhow!(
+                    "[SilenceConfig->is_active] 'duration_min' is required for recurring silence '{}'",
+                    self.index_pattern
+                )
+            })?;
+
+            let window_start: DateTime<Local> = now - chrono::Duration::minutes(duration_min);
+
+            return Ok(schedule
+                .after(&window_start)
+                .next()
+                .is_some_and(|start| start <= now));
+        }
+
+        if let Some(expires_at) = &self.expires_at {
+            let started: bool = match &self.start_at {
+                Some(start_at) => convert_local_from_str(start_at)? <= now,
+                None => true,
+            };
+
+            return Ok(started && now < convert_local_from_str(expires_at)?);
+        }
+
+        Err(anyhow!(
+            "[SilenceConfig->is_active] Either 'cron_schedule' or 'expires_at' must be set for silence '{}'",
+            self.index_pattern
+        ))
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct SilenceListConfig {
+    #[serde(default)]
+    pub silence: Vec<SilenceConfig>,
+}
+
+impl SilenceListConfig {
+    #[doc = "인덱스에 현재 활성화된 사일런스를 반환 - 잘못된 사일런스 설정은 로깅 후 무시"]
+    pub fn find_active_silence(
+        &self,
+        index_name: &str,
+        now: DateTime<Local>,
+    ) -> Option<&SilenceConfig> {
+        self.silence.iter().find(|silence| {
+            match silence
+                .matches(index_name)
+                .and_then(|matched| Ok(matched && silence.is_active(now)?))
+            {
+                Ok(silenced) => silenced,
+                Err(e) => {
+                    error!("[SilenceListConfig->find_active_silence] {:?}", e);
+                    false
+                }
+            }
+        })
+    }
+
+    #[doc = "인덱스가 현재 활성화된 사일런스에 해당하는지 여부"]
+    pub fn is_silenced(&self, index_name: &str, now: DateTime<Local>) -> bool {
+        self.find_active_silence(index_name, now).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local_time(day: u32, hour: u32, min: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2026, 10, day, hour, min, 0)
+            .single()
+            .unwrap()
+    }
+
+    fn silence_list(toml_str: &str) -> SilenceListConfig {
+        toml::from_str(toml_str).unwrap()
+    }
+
+    #[test]
+    fn matches_glob_pattern() {
+        let silences: SilenceListConfig = silence_list(
+            r#"
+            [[silence]]
+            index_pattern = "order_*"
+            expires_at = "2026-10-20T00:00:00"
+            "#,
+        );
+
+        assert!(silences.silence[0].matches("order_2026").unwrap());
+        assert!(!silences.silence[0].matches("payment_2026").unwrap());
+    }
+
+    #[test]
+    fn recurring_silence_is_active_within_duration() {
+        /* 매일 02:00 부터 60분 */
+        let silences: SilenceListConfig = silence_list(
+            r#"
+            [[silence]]
+            index_pattern = "order_*"
+            cron_schedule = "0 0 2 * * *"
+            duration_min = 60
+            "#,
+        );
+        let silence: &SilenceConfig = &silences.silence[0];
+
+        assert!(!silence.is_active(local_time(17, 1, 59)).unwrap());
+        assert!(silence.is_active(local_time(17, 2, 0)).unwrap());
+        assert!(silence.is_active(local_time(17, 2, 59)).unwrap());
+        assert!(!silence.is_active(local_time(17, 3, 1)).unwrap());
+    }
+
+    #[test]
+    fn recurring_silence_requires_duration() {
+        let silences: SilenceListConfig = silence_list(
+            r#"
+            [[silence]]
+            index_pattern = "order_*"
+            cron_schedule = "0 0 2 * * *"
+            "#,
+        );
+
+        assert!(silences.silence[0].is_active(local_time(17, 2, 0)).is_err());
+    }
+
+    #[test]
+    fn one_off_silence_respects_start_and_expiry() {
+        let silences: SilenceListConfig = silence_list(
+            r#"
+            [[silence]]
+            index_pattern = "order_*"
+            start_at = "2026-10-17T09:00:00"
+            expires_at = "2026-10-17T10:00:00"
+
+            [[silence]]
+            index_pattern = "order_*"
+            "#,
+        );
+
+        assert!(
+            !silences.silence[0]
+                .is_active(local_time(17, 8, 59))
+                .unwrap()
+        );
+        assert!(
+            silences.silence[0]
+                .is_active(local_time(17, 9, 30))
+                .unwrap()
+        );
+        assert!(
+            !silences.silence[0]
+                .is_active(local_time(17, 10, 0))
+                .unwrap()
+        );
+        assert!(
+            silences.silence[1]
+                .is_active(local_time(17, 9, 30))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn overlapping_silences_cover_their_union() {
+        /* 09:00~10:00 1회성 점검과 매일 09:30~10:30 반복 점검이 겹친다 */
+        let silences: SilenceListConfig = silence_list(
+            r#"
+            [[silence]]
+            index_pattern = "order_*"
+            reason = "one-off"
+            start_at = "2026-10-17T09:00:00"
+            expires_at = "2026-10-17T10:00:00"
+
+            [[silence]]
+            index_pattern = "order_2026*"
+            reason = "recurring"
+            cron_schedule = "0 30 9 * * *"
+            duration_min = 60
+            "#,
+        );
+
+        let active_reason = |index_name: &str, now: DateTime<Local>| {
+            silences
+                .find_active_silence(index_name, now)
+                .and_then(|silence| silence.reason().clone())
+        };
+
+        assert_eq!(
+            active_reason("order_2026", local_time(17, 9, 10)).as_deref(),
+            Some("one-off")
+        );
+        /* 둘 다 활성화된 구간에서는 먼저 설정된 사일런스를 반환 */
+        assert_eq!(
+            active_reason("order_2026", local_time(17, 9, 45)).as_deref(),
+            Some("one-off")
+        );
+        /* 1회성 점검이 끝나도 반복 점검이 이어서 적용 */
+        assert_eq!(
+            active_reason("order_2026", local_time(17, 10, 15)).as_deref(),
+            Some("recurring")
+        );
+        assert!(!silences.is_silenced("order_2026", local_time(17, 10, 31)));
+        /* 반복 점검 패턴에 맞지 않는 인덱스는 1회성 점검만 적용 */
+        assert!(!silences.is_silenced("order_2025", local_time(17, 10, 15)));
+    }
+
+    #[test]
+    fn invalid_silence_is_ignored_in_favor_of_valid_ones() {
+        let silences: SilenceListConfig = silence_list(
+            r#"
+            [[silence]]
+            index_pattern = "order_*"
+            cron_schedule = "not a cron"
+            duration_min = 60
+
+            [[silence]]
+            index_pattern = "order_*"
+            expires_at = "2026-10-17T10:00:00"
+            "#,
+        );
+
+        assert!(silences.is_silenced("order_2026", local_time(17, 9, 0)));
+        assert!(!silences.is_silenced("order_2026", local_time(17, 11, 0)));
+    }
+}
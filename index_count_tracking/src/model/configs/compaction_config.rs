@@ -0,0 +1,39 @@
+use crate::common::*;
+
+/* 롤업 인덱스 기본 보관 기간(일) */
+const DEFAULT_ROLLUP_RETENTION_DAYS: u32 = 730;
+
+#[derive(Debug, Default, Deserialize, Serialize, Getters)]
+#[getset(get = "pub")]
+pub struct CompactionConfig {
+    /* 원본 샘플 보관 기간(일) - 이 기간이 지난 일자별 모니터링 인덱스는 시간 단위 롤업으로 압축 후 삭제 (미설정 시 압축하지 않음) */
+    #[serde(default)]
+    pub raw_retention_days: Option<u32>,
+    /* 롤업 인덱스 접두어 + `YYYYMM` - 미설정 시 `rollup_{monitor_index_name}` (monitor_index_name* 패턴과 겹치지 않아야 함) */
+    #[serde(default)]
+    pub rollup_index_name: Option<String>,
+    /* 롤업 인덱스 보관 기간(일) - 미설정 시 730 */
+    #[serde(default)]
+    pub rollup_retention_days: Option<u32>,
+}
+
+impl CompactionConfig {
+    #[doc = "롤업 인덱스 접두어"]
+    pub fn resolve_rollup_index_name(&self, mon_index_name: &str) -> String {
+        self.rollup_index_name
+            .clone()
+            .unwrap_or_else(|| format!("rollup_{}", mon_index_name))
+    }
+
+    #[doc = "롤업 인덱스 보관 기간(일)"]
+    pub fn resolve_rollup_retention_days(&self) -> u32 {
+        self.rollup_retention_days
+            .unwrap_or(DEFAULT_ROLLUP_RETENTION_DAYS)
+    }
+
+    #[doc = "이 시각 이전의 원본 샘플은 롤업으로만 남아 있음 - 압축을 사용하지 않으면 None"]
+    pub fn raw_cutoff_time(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.raw_retention_days
+            .map(|raw_retention_days| now - chrono::Duration::days(raw_retention_days as i64))
+    }
+}
@@ -0,0 +1,15 @@
+use crate::common::*;
+
+#[derive(Debug, Default, Deserialize, Serialize, Getters)]
+#[getset(get = "pub")]
+pub struct IndexBootstrapConfig {
+    /* true 인 경우 시작 시 인덱스 템플릿/ILM 정책 생성 단계를 건너뜀 (클러스터에서 직접 관리하는 경우) */
+    #[serde(default)]
+    pub skip: bool,
+    /* 모니터링(문서 수 샘플) 인덱스 보관 기간(일) - 미설정 시 90 */
+    #[serde(default)]
+    pub monitor_retention_days: Option<u32>,
+    /* 알람 이력 인덱스 보관 기간(일) - 미설정 시 365 */
+    #[serde(default)]
+    pub alarm_retention_days: Option<u32>,
+}
@@ -1,8 +1,11 @@
 pub mod alarm_log_index;
+pub mod compaction_config;
 pub mod elastic_server_config;
+pub mod index_bootstrap_config;
 pub mod rdb_config;
 pub mod receiver_email_config;
 pub mod smtp_config;
+pub mod storage_config;
 pub mod system_config;
 pub mod telegram_config;
 pub mod total_config;
@@ -1,9 +1,21 @@
 use crate::common::*;
 
+use crate::enums::alert_severity::*;
+
 #[derive(Serialize, Deserialize, Debug, Getters)]
 #[getset(get = "pub")]
 pub struct ReceiverEmail {
     pub email_id: String,
+    /* 수신할 최소 알람 심각도 - 미설정 시 warning (모든 알람 수신) */
+    #[serde(default)]
+    pub min_severity: Option<AlertSeverity>,
+}
+
+impl ReceiverEmail {
+    #[doc = "수신할 최소 알람 심각도 (미설정 시 Warning)"]
+    pub fn resolve_min_severity(&self) -> AlertSeverity {
+        self.min_severity.unwrap_or(AlertSeverity::Warning)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Getters)]
@@ -0,0 +1,28 @@
+use crate::common::*;
+
+use crate::enums::storage_backend::*;
+
+#[doc = r#"
+    모니터링 데이터 저장소 설정
+
+    backend 가 저장하는 대상: 인덱스 샘플, 페어(원본/복제) 샘플, 알람 이력.
+
+    backend = "local" 이어도 Elasticsearch 가 필요한 부분:
+    - 수집 대상 클러스터(target cluster) - 문서 수/인덱스 통계 조회는 항상 Elasticsearch
+    - 모니터링 클러스터 설정(mon_elasticsearch) - 연결 객체 생성에 필요하지만 요청은 보내지 않음
+
+    backend = "local" 이면 동작하지 않는 기능:
+    - 인덱스 템플릿/ILM 부트스트랩 (index_bootstrap)
+    - 롤업 압축 (compaction) 및 ILM 보관 기간 - 오래된 파일은 직접 정리
+    - 스풀 재전송 (로컬 저장소는 스풀을 사용하지 않음)
+"#]
+#[derive(Debug, Default, Deserialize, Serialize, Getters)]
+#[getset(get = "pub")]
+pub struct StorageConfig {
+    /* 모니터링 데이터 저장소 - "elasticsearch"(모니터링 클러스터) 또는 "local"(로컬 파일), 미설정 시 elasticsearch */
+    #[serde(default)]
+    pub backend: StorageBackend,
+    /* backend = "local" 인 경우 샘플 파일 디렉토리 - 미설정 시 ./storage */
+    #[serde(default)]
+    pub local_dir: Option<String>,
+}
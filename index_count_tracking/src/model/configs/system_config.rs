@@ -6,4 +6,16 @@ pub struct SystemConfig {
     pub monitor_index_name: String,
     pub message_chunk_size: usize,
     pub ticker_sec: u64,
+    /* 장애 해소 후 이 시간(초) 내에 다시 발생하면 같은 장애로 간주 - 인덱스별 설정이 우선 */
+    #[serde(default)]
+    pub alert_cooldown_sec: Option<i64>,
+    /* 장애가 지속되는 동안 재알림을 보내는 주기(분) - 인덱스별 설정이 우선 */
+    #[serde(default)]
+    pub alert_renotify_min: Option<i64>,
+    /* 동시에 평가하는 인덱스 수의 상한 - 없으면 기본값(8) 사용 */
+    #[serde(default)]
+    pub eval_concurrency: Option<usize>,
+    /* 모니터링 클러스터 장애 시 색인하지 못한 문서를 보관할 로컬 디렉토리 - 없으면 ./spool */
+    #[serde(default)]
+    pub spool_dir: Option<String>,
 }
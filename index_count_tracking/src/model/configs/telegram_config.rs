@@ -1,8 +1,20 @@
 use crate::common::*;
 
+use crate::enums::alert_severity::*;
+
 #[derive(Serialize, Deserialize, Debug, Getters)]
 #[getset(get = "pub")]
 pub struct TelegramConfig {
     pub bot_token: String,
     pub chat_room_id: String,
+    /* 텔레그램으로 발송할 최소 알람 심각도 - 미설정 시 critical (Warning 은 이메일로만 발송) */
+    #[serde(default)]
+    pub min_severity: Option<AlertSeverity>,
+}
+
+impl TelegramConfig {
+    #[doc = "해당 심각도의 알람을 텔레그램으로 발송하는지 여부"]
+    pub fn accepts(&self, severity: AlertSeverity) -> bool {
+        severity >= self.min_severity.unwrap_or(AlertSeverity::Critical)
+    }
 }
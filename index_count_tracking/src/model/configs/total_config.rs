@@ -1,8 +1,8 @@
 use crate::common::*;
 
 use crate::model::configs::{
-    alarm_log_index::*, elastic_server_config::*, rdb_config::*, smtp_config::*, system_config::*,
-    telegram_config::*,
+    alarm_log_index::*, compaction_config::*, elastic_server_config::*, index_bootstrap_config::*,
+    rdb_config::*, smtp_config::*, storage_config::*, system_config::*, telegram_config::*,
 };
 
 use crate::model::report::report_config::*;
@@ -23,6 +23,9 @@ pub fn initialize_server_config() -> TotalConfig {
 #[getset(get = "pub")]
 pub struct TotalConfig {
     pub elasticsearch: ElasticServerConfig,
+    /* 추가 대상 클러스터 목록 - elastic_cluster_name 으로 IndexConfig.cluster 에서 참조 */
+    #[serde(default)]
+    pub target_clusters: Vec<ElasticServerConfig>,
     pub mon_elasticsearch: ElasticServerConfig,
     pub sqlserver: RdbConfig,
     pub telegram: TelegramConfig,
@@ -30,15 +33,31 @@ pub struct TotalConfig {
     #[allow(dead_code)]
     pub smtp: SmtpConfig,
     pub alarm_log_index: AlarmLogIndex,
+    /* 모니터링/알람 이력 인덱스의 템플릿(매핑)·ILM 정책 생성 설정 - 미설정 시 기본값으로 생성 */
+    #[serde(default)]
+    pub index_bootstrap: IndexBootstrapConfig,
+    /* 오래된 모니터링 샘플의 시간 단위 롤업 압축 설정 - 미설정 시 압축하지 않음 */
+    #[serde(default)]
+    pub compaction: CompactionConfig,
+    /* 모니터링 샘플(시계열) 저장소 설정 - 미설정 시 모니터링 클러스터(elasticsearch) 사용 */
+    #[serde(default)]
+    pub storage: StorageConfig,
     pub daily_report: ReportConfig,
     pub weekly_report: ReportConfig,
     pub monthly_report: ReportConfig,
     pub yearly_report: ReportConfig,
 }
 
-#[doc = "Elasticsearch config 정보"]
-pub fn get_elastic_config_info() -> &'static ElasticServerConfig {
-    &TOTAL_CONFIG.elasticsearch
+#[doc = "대상 Elasticsearch 클러스터 전체 config 정보 - 기본 클러스터(elasticsearch)가 항상 첫 번째"]
+pub fn get_target_cluster_configs() -> Vec<&'static ElasticServerConfig> {
+    std::iter::once(&TOTAL_CONFIG.elasticsearch)
+        .chain(TOTAL_CONFIG.target_clusters.iter())
+        .collect()
+}
+
+#[doc = "cluster 가 지정되지 않은 인덱스가 속하는 기본 대상 클러스터명"]
+pub fn get_default_cluster_name() -> &'static str {
+    TOTAL_CONFIG.elasticsearch.elastic_cluster_name()
 }
 
 #[doc = "모니터링용 Elasticsearch config 정보"]
@@ -61,6 +80,21 @@ pub fn get_alarm_log_index_info() -> &'static AlarmLogIndex {
     &TOTAL_CONFIG.alarm_log_index
 }
 
+#[doc = "인덱스 템플릿/ILM 정책 생성 설정 정보"]
+pub fn get_index_bootstrap_config_info() -> &'static IndexBootstrapConfig {
+    &TOTAL_CONFIG.index_bootstrap
+}
+
+#[doc = "모니터링 샘플 롤업 압축 설정 정보"]
+pub fn get_compaction_config_info() -> &'static CompactionConfig {
+    &TOTAL_CONFIG.compaction
+}
+
+#[doc = "모니터링 샘플 저장소 설정 정보"]
+pub fn get_storage_config_info() -> &'static StorageConfig {
+    &TOTAL_CONFIG.storage
+}
+
 #[doc = "system 설정 정보"]
 pub fn get_system_config_info() -> &'static SystemConfig {
     &TOTAL_CONFIG.system
@@ -1,6 +1,10 @@
 use crate::common::*;
 
-#[derive(Debug, Deserialize, Serialize, Getters, Setters, new)]
+use crate::model::index::breakdown_bucket::*;
+
+use crate::dto::index_stats::*;
+
+#[derive(Debug, Clone, Deserialize, Serialize, Getters, Setters, new)]
 #[getset(get = "pub", set = "pub")]
 pub struct AlertIndex {
     pub index_name: String,
@@ -9,4 +13,43 @@ pub struct AlertIndex {
     // pub prev_cnt: usize,
     // pub cur_prev_diff: usize,
     pub timestamp: String,
+    /* expand 모드로 수집된 샘플인 경우 원래 대상(패턴/별칭)명 */
+    #[serde(default)]
+    #[new(default)]
+    pub target_name: Option<String>,
+    /* 수집 대상 클러스터명 - 다중 클러스터 지원 이전 샘플은 None */
+    #[serde(default)]
+    #[new(default)]
+    pub cluster_name: Option<String>,
+    /* breakdown_field 가 설정된 경우 버킷별 문서 수 */
+    #[serde(default)]
+    #[new(default)]
+    pub breakdown: Option<Vec<BreakdownBucket>>,
+    /* timestamp_field 가 설정된 경우 현재 시각 - 최신 문서 시각 (초) */
+    #[serde(default)]
+    #[new(default)]
+    pub freshness_lag_sec: Option<i64>,
+    /* timestamp_field 가 설정된 경우 직전 집계 구간(ingest_interval_sec)에 신규 색인된 문서 수 */
+    #[serde(default)]
+    #[new(default)]
+    pub ingest_cnt: Option<usize>,
+    /* _stats API 로 조회한 저장 용량/삭제 문서/누적 색인·삭제 건수/세그먼트 수 */
+    #[serde(default)]
+    #[new(default)]
+    pub index_stats: Option<IndexStats>,
+    /* reconcile 이 설정된 경우 같은 주기에 조회한 원본(SQL Server) 테이블 행 수 */
+    #[serde(default)]
+    #[new(default)]
+    pub source_row_cnt: Option<usize>,
+    /* 사일런스(점검 시간) 중 수집된 샘플 여부 */
+    #[serde(default)]
+    #[new(default)]
+    pub suppressed: bool,
+}
+
+impl AlertIndex {
+    #[doc = "샘플 문서 ID - 스풀 재전송이나 일부 실패한 `_bulk` 재시도로 다시 색인해도 중복되지 않도록 `{index_name}|{timestamp}` 사용"]
+    pub fn doc_id(&self) -> String {
+        format!("{}|{}", self.index_name, self.timestamp)
+    }
 }
@@ -0,0 +1,38 @@
+use crate::common::*;
+
+use crate::utils_modules::traits::*;
+
+#[doc = "breakdown_field terms 집계의 버킷별 문서 수"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct BreakdownBucket {
+    pub key: String,
+    pub cnt: usize,
+}
+
+impl FromAggBucket for BreakdownBucket {
+    fn from_agg_bucket(bucket: &Value) -> anyhow::Result<Self> {
+        /* 숫자/날짜 필드는 key_as_string 을 우선 사용 */
+        let key: String = match bucket.get("key_as_string").or_else(|| bucket.get("key")) {
+            Some(Value::String(key)) => key.to_string(),
+            Some(key) if !key.is_null() => key.to_string(),
+            _ => {
+                return Err(anyhow!(
+                    "[BreakdownBucket->from_agg_bucket] bucket.key is missing"
+                ));
+            }
+        };
+
+        let cnt: usize = bucket
+            .get("doc_count")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| {
+                anyhow!(
+                    "[BreakdownBucket->from_agg_bucket] bucket.doc_count is missing or not a u64"
+                )
+            })?
+            .try_into()?;
+
+        Ok(BreakdownBucket::new(key, cnt))
+    }
+}
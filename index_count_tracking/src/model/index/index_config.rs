@@ -1,9 +1,264 @@
 use crate::common::*;
 
-#[derive(Debug, Deserialize, Getters)]
+use crate::enums::{detector_type::*, fluctuation_direction::*, target_mode::*};
+
+use crate::model::{
+    configs::total_config::*,
+    index::{named_query_filter::*, reconcile_config::*},
+};
+
+/* 장애 해소 후 같은 장애로 간주하는 기본 시간(초) */
+const DEFAULT_ALERT_COOLDOWN_SEC: i64 = 300;
+/* 장애 지속 중 재알림 기본 주기(분) */
+pub const DEFAULT_ALERT_RENOTIFY_MIN: i64 = 30;
+/* breakdown_field terms 집계 기본 버킷 수 */
+const DEFAULT_BREAKDOWN_SIZE: usize = 20;
+/* 신규 색인 속도 기본 집계 구간(초) */
+const DEFAULT_INGEST_INTERVAL_SEC: i64 = 600;
+
+#[derive(Debug, Clone, Deserialize, Getters)]
 #[getset(get = "pub")]
 pub struct IndexConfig {
+    /* 인덱스명 - 글롭 패턴(logs-2026.10.*), 별칭, 데이터 스트림도 가능 */
     pub index_name: String,
+    /* 대상 클러스터명 (server config 의 elastic_cluster_name) - 미설정 시 기본 클러스터(elasticsearch) */
+    #[serde(default)]
+    pub cluster: Option<String>,
+    /* 패턴/별칭/데이터 스트림 대상의 모니터링 방식 (sum: 합산 / expand: 실제 인덱스별) */
+    #[serde(default)]
+    pub target_mode: TargetMode,
+    /* expand 모드로 펼쳐진 설정인 경우 원래 대상(패턴/별칭)명 - 설정 파일에서는 읽지 않음 */
+    #[serde(skip)]
+    pub target_name: Option<String>,
+    /* 문서 수 집계에 사용할 query DSL - 미설정 시 match_all */
+    #[serde(default)]
+    pub query_filter: Option<Value>,
+    /* 이름 있는 필터 - 필터마다 `{index_name}#{name}` 시계열로 별도 기록/검증 */
+    #[serde(default)]
+    pub filters: Vec<NamedQueryFilter>,
+    /* 필터 시계열로 펼쳐진 설정인 경우 실제 집계 대상 인덱스명 - 설정 파일에서는 읽지 않음 */
+    #[serde(skip)]
+    pub source_index: Option<String>,
+    /* 카테고리별 문서 수를 함께 기록할 필드 (terms 집계 - keyword 필드 지정) */
+    #[serde(default)]
+    pub breakdown_field: Option<String>,
+    /* breakdown_field terms 집계 버킷 수 (미설정 시 20) */
+    #[serde(default)]
+    pub breakdown_size: Option<usize>,
     pub allowable_fluctuation_range: f64,
     pub agg_term_sec: i64,
+    /* 문서 수 수집/검증 주기(초) - 미설정 시 system.ticker_sec */
+    #[serde(default)]
+    pub sample_interval_sec: Option<u64>,
+    /* 문서 감소 방향 허용 변동률(%) - 미설정 시 allowable_fluctuation_range 사용 */
+    #[serde(default)]
+    pub drop_fluctuation_range: Option<f64>,
+    /* 문서 증가 방향 허용 변동률(%) - 미설정 시 allowable_fluctuation_range 사용 */
+    #[serde(default)]
+    pub growth_fluctuation_range: Option<f64>,
+    /* true 인 경우 문서 증가는 알람 대상에서 제외 */
+    #[serde(default)]
+    pub ignore_growth: bool,
+    /* 집계 구간(agg_term_sec) 내 허용 문서 변동량(건) */
+    #[serde(default)]
+    pub max_change_count: Option<usize>,
+    /* Warning 심각도 허용 변동률(%) - 위의 변동률 허용치(Critical)보다 작게 설정 */
+    #[serde(default)]
+    pub warning_fluctuation_range: Option<f64>,
+    /* Warning 심각도 허용 문서 변동량(건) - max_change_count(Critical)보다 작게 설정 */
+    #[serde(default)]
+    pub warning_change_count: Option<usize>,
+    /* 문서 수 하한 - 현재 문서 수가 이 값보다 작으면 알람 */
+    #[serde(default)]
+    pub min_doc_count: Option<usize>,
+    /* 문서 수 상한 - 현재 문서 수가 이 값보다 크면 알람 */
+    #[serde(default)]
+    pub max_doc_count: Option<usize>,
+    /* 기대 활동 주기(초) - 이 시간 동안 문서 수 변화가 없으면 색인 정체(stall) 알람 */
+    #[serde(default)]
+    pub expected_activity_sec: Option<i64>,
+    /* 데이터 신선도 측정용 timestamp 필드 - 최신 문서의 값과 현재 시각의 차이(lag)를 기록 */
+    #[serde(default)]
+    pub timestamp_field: Option<String>,
+    /* 허용 신선도 지연(초) - 최신 문서가 이 시간보다 오래되면 알람 */
+    #[serde(default)]
+    pub max_freshness_lag_sec: Option<i64>,
+    /* 신규 색인 속도 집계 구간(초) - timestamp_field 기준 date_histogram 간격 (미설정 시 600) */
+    #[serde(default)]
+    pub ingest_interval_sec: Option<i64>,
+    /* 구간별 신규 색인 문서 수 하한 - 이 값보다 적게 들어오면 알람 */
+    #[serde(default)]
+    pub min_ingest_rate: Option<usize>,
+    /* 구간별 신규 색인 문서 수 상한 - 이 값보다 많이 들어오면 알람 */
+    #[serde(default)]
+    pub max_ingest_rate: Option<usize>,
+    /* 저장 용량 상한(byte) - _stats 의 store.size_in_bytes 가 이 값보다 크면 알람 */
+    #[serde(default)]
+    pub max_store_size_bytes: Option<u64>,
+    /* 삭제 표시된(merge 전) 문서 수 상한 - _stats 의 docs.deleted 가 이 값보다 크면 알람 */
+    #[serde(default)]
+    pub max_docs_deleted: Option<u64>,
+    /* 집계 구간(agg_term_sec) 내 삭제 건수 상한 - indexing.delete_total 증가량이 이 값보다 크면 알람 */
+    #[serde(default)]
+    pub max_delete_count: Option<u64>,
+    /* 세그먼트 수 상한 - _stats 의 segments.count 가 이 값보다 크면 알람 */
+    #[serde(default)]
+    pub max_segment_cnt: Option<u64>,
+    /* 원본 SQL Server 테이블 행 수와의 정합성 검증 (필터/expand 시계열에는 적용하지 않음) */
+    #[serde(default)]
+    pub reconcile: Option<ReconcileConfig>,
+    /* 이상 탐지기 종류 - 미설정 시 threshold */
+    #[serde(default)]
+    pub detector: DetectorType,
+    /* 통계 기반 탐지기가 기준선을 학습할 과거 이력 구간(초) */
+    #[serde(default)]
+    pub baseline_sec: Option<i64>,
+    /* 통계 기반 탐지기의 이상 점수 허용치 (z-score 기준) */
+    #[serde(default)]
+    pub anomaly_score_limit: Option<f64>,
+    /* 통계 기반 탐지기의 Warning 심각도 이상 점수 허용치 - anomaly_score_limit(Critical)보다 작게 설정 */
+    #[serde(default)]
+    pub warning_anomaly_score_limit: Option<f64>,
+    /* EWMA 탐지기의 평활 계수 (0 < alpha <= 1) */
+    #[serde(default)]
+    pub ewma_alpha: Option<f64>,
+    /* 계절성 탐지기의 허용 범위(%) - 기대 변동량과 실제 변동량의 차이가 이 값 이상이면 알람 */
+    #[serde(default)]
+    pub seasonal_band: Option<f64>,
+    /* 계절성 탐지기의 Warning 심각도 허용 범위(%) - seasonal_band(Critical)보다 작게 설정 */
+    #[serde(default)]
+    pub warning_seasonal_band: Option<f64>,
+    /* 장애 해소 후 이 시간(초) 내에 다시 발생하면 같은 장애로 간주 (미설정 시 system.alert_cooldown_sec) */
+    #[serde(default)]
+    pub cooldown_sec: Option<i64>,
+    /* 장애가 지속되는 동안 재알림을 보내는 주기(분) (미설정 시 system.alert_renotify_min) */
+    #[serde(default)]
+    pub renotify_min: Option<i64>,
+}
+
+impl IndexConfig {
+    #[doc = "변동 방향에 해당하는 허용 변동률(%)을 반환 - None 이면 해당 방향은 알람 대상이 아님"]
+    pub fn fluctuation_limit(&self, direction: FluctuationDirection) -> Option<f64> {
+        match direction {
+            FluctuationDirection::Drop => Some(
+                self.drop_fluctuation_range
+                    .unwrap_or(self.allowable_fluctuation_range),
+            ),
+            FluctuationDirection::Growth if self.ignore_growth => None,
+            FluctuationDirection::Growth => Some(
+                self.growth_fluctuation_range
+                    .unwrap_or(self.allowable_fluctuation_range),
+            ),
+            FluctuationDirection::Flat => None,
+        }
+    }
+
+    #[doc = "변동 방향에 해당하는 Warning 허용 변동률(%)을 반환 - 해당 방향이 알람 대상이 아니면 None"]
+    pub fn warning_fluctuation_limit(&self, direction: FluctuationDirection) -> Option<f64> {
+        self.fluctuation_limit(direction)
+            .and(self.warning_fluctuation_range)
+    }
+
+    #[doc = "expand 모드 대상을 실제 인덱스 하나에 대한 설정으로 복제 - 나머지 임계치 설정은 그대로 상속"]
+    pub fn expand_to(&self, concrete_index_name: &str) -> IndexConfig {
+        let mut expanded: IndexConfig = self.clone();
+        expanded.index_name = concrete_index_name.to_string();
+        expanded.target_mode = TargetMode::Sum;
+        expanded.target_name = Some(self.index_name.clone());
+        /* 원본 테이블은 대상 전체(합산)와 대응되므로 개별 인덱스에서는 비교하지 않음 */
+        expanded.reconcile = None;
+        expanded
+    }
+
+    #[doc = "이름 있는 필터마다 별도의 시계열 설정을 만들어 반환 - 전체(또는 query_filter) 시계열이 항상 첫 번째"]
+    pub fn expand_filters(&self) -> Vec<IndexConfig> {
+        let mut series_configs: Vec<IndexConfig> = vec![self.as_single_series()];
+
+        for filter in &self.filters {
+            let mut filtered: IndexConfig = self.as_single_series();
+            filtered.index_name = format!("{}#{}", self.index_name, filter.name());
+            filtered.source_index = Some(self.count_target().to_string());
+            filtered.query_filter = Some(filter.query().clone());
+            filtered.reconcile = None;
+            series_configs.push(filtered);
+        }
+
+        series_configs
+    }
+
+    #[doc = "이름 있는 필터를 제외한 단일 시계열 설정으로 복제"]
+    pub fn as_single_series(&self) -> IndexConfig {
+        let mut single: IndexConfig = self.clone();
+        single.filters = Vec::new();
+        single
+    }
+
+    #[doc = "대상 클러스터명 - 인덱스 설정 > 기본 클러스터 순"]
+    pub fn resolve_cluster_name(&self) -> &str {
+        self.cluster
+            .as_deref()
+            .unwrap_or_else(|| get_default_cluster_name())
+    }
+
+    #[doc = "기본 클러스터가 아닌 경우 시계열명을 `{cluster}:{index_name}` 으로 구분 - 클러스터 간 같은 인덱스명이 섞이지 않도록 함"]
+    pub fn with_cluster_series(&self) -> IndexConfig {
+        let mut series: IndexConfig = self.clone();
+        let cluster_name: &str = self.resolve_cluster_name();
+
+        if cluster_name == get_default_cluster_name() {
+            return series;
+        }
+
+        series.source_index = Some(self.count_target().to_string());
+        series.index_name = format!("{}:{}", cluster_name, self.index_name);
+        series.target_name = self
+            .target_name
+            .as_ref()
+            .map(|target_name| format!("{}:{}", cluster_name, target_name));
+        series
+    }
+
+    #[doc = "이름 있는 필터로 펼쳐진 시계열인지 여부 - ES 인덱스명에는 '#' 을 쓸 수 없으므로 `{index_name}#{name}` 형태로 판별"]
+    pub fn is_filter_series(&self) -> bool {
+        self.index_name.contains('#')
+    }
+
+    #[doc = "문서 수를 집계할 실제 인덱스명 (필터/타 클러스터 시계열은 원본 인덱스)"]
+    pub fn count_target(&self) -> &str {
+        self.source_index.as_deref().unwrap_or(&self.index_name)
+    }
+
+    #[doc = "장애 해소 후 같은 장애로 간주하는 시간(초) - 인덱스 설정 > system 설정 > 기본값 순"]
+    pub fn resolve_cooldown_sec(&self) -> i64 {
+        self.cooldown_sec
+            .or(*get_system_config_info().alert_cooldown_sec())
+            .unwrap_or(DEFAULT_ALERT_COOLDOWN_SEC)
+    }
+
+    #[doc = "장애 지속 중 재알림 주기(초) - 인덱스 설정 > system 설정 > 기본값 순"]
+    pub fn resolve_renotify_sec(&self) -> i64 {
+        self.renotify_min
+            .or(*get_system_config_info().alert_renotify_min())
+            .unwrap_or(DEFAULT_ALERT_RENOTIFY_MIN)
+            * 60
+    }
+
+    #[doc = "문서 수 수집/검증 주기(초) - 인덱스 설정 > system.ticker_sec 순"]
+    pub fn resolve_sample_interval_sec(&self) -> u64 {
+        self.sample_interval_sec
+            .filter(|interval_sec| *interval_sec > 0)
+            .unwrap_or_else(|| *get_system_config_info().ticker_sec())
+    }
+
+    #[doc = "breakdown_field terms 집계 버킷 수"]
+    pub fn resolve_breakdown_size(&self) -> usize {
+        self.breakdown_size.unwrap_or(DEFAULT_BREAKDOWN_SIZE)
+    }
+
+    #[doc = "신규 색인 속도 집계 구간(초)"]
+    pub fn resolve_ingest_interval_sec(&self) -> i64 {
+        self.ingest_interval_sec
+            .filter(|interval_sec| *interval_sec > 0)
+            .unwrap_or(DEFAULT_INGEST_INTERVAL_SEC)
+    }
 }
@@ -1,9 +1,13 @@
 use crate::common::*;
 
-use crate::model::index::index_config::*;
+use crate::model::index::{index_config::*, index_pair_config::*};
 
-#[derive(Debug, Deserialize, Getters)]
+#[derive(Debug, Deserialize, Getters, new)]
 #[getset(get = "pub")]
 pub struct IndexListConfig {
     pub index: Vec<IndexConfig>,
+    /* 원본/복제 클러스터 간 문서 수 정합성 검증 대상 */
+    #[serde(default)]
+    #[new(default)]
+    pub pair: Vec<IndexPairConfig>,
 }
@@ -0,0 +1,84 @@
+use crate::common::*;
+
+use crate::model::configs::total_config::*;
+
+/* 원본/복제본 문서 수 불일치를 장애로 판단하기까지의 기본 유예 시간(초) */
+const DEFAULT_PAIR_GRACE_PERIOD_SEC: i64 = 600;
+
+#[doc = r#"
+    원본(primary) 클러스터와 복제(replica, DR) 클러스터 간 동일 논리 인덱스의 문서 수 정합성 검증 설정
+
+    두 클러스터의 문서 수 차이가 허용치를 넘는 상태가 유예 시간 이상 지속되면 알람을 발송한다.
+
+    # Fields
+    * `index_name` - 원본 클러스터의 인덱스명 (별칭/패턴도 가능)
+    * `replica_index_name` - 복제 클러스터의 인덱스명 (미설정 시 index_name 과 동일 - CCR follower 명이 다른 경우 지정)
+    * `primary_cluster` - 원본 클러스터명 (미설정 시 기본 클러스터)
+    * `replica_cluster` - 복제 클러스터명
+    * `max_diff_count` - 허용 문서 수 차이(건)
+    * `max_diff_percent` - 허용 문서 수 차이율(%) - 원본 문서 수 기준 / 두 허용치가 모두 없으면 차이가 있으면 불일치
+    * `grace_period_sec` - 불일치가 이 시간(초) 이상 지속되어야 알람 (미설정 시 600)
+"#]
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct IndexPairConfig {
+    pub index_name: String,
+    #[serde(default)]
+    pub replica_index_name: Option<String>,
+    #[serde(default)]
+    pub primary_cluster: Option<String>,
+    pub replica_cluster: String,
+    #[serde(default)]
+    pub max_diff_count: Option<usize>,
+    #[serde(default)]
+    pub max_diff_percent: Option<f64>,
+    #[serde(default)]
+    pub grace_period_sec: Option<i64>,
+}
+
+impl IndexPairConfig {
+    #[doc = "원본 클러스터명 - 페어 설정 > 기본 클러스터 순"]
+    pub fn resolve_primary_cluster(&self) -> &str {
+        self.primary_cluster
+            .as_deref()
+            .unwrap_or_else(|| get_default_cluster_name())
+    }
+
+    #[doc = "복제 클러스터의 인덱스명"]
+    pub fn resolve_replica_index_name(&self) -> &str {
+        self.replica_index_name
+            .as_deref()
+            .unwrap_or(&self.index_name)
+    }
+
+    #[doc = "불일치 유예 시간(초)"]
+    pub fn resolve_grace_period_sec(&self) -> i64 {
+        self.grace_period_sec
+            .filter(|grace_period_sec| *grace_period_sec >= 0)
+            .unwrap_or(DEFAULT_PAIR_GRACE_PERIOD_SEC)
+    }
+
+    #[doc = "알람/이력/리포트에서 사용하는 페어명 - `{primary_cluster}:{index_name} ↔ {replica_cluster}:{replica_index_name}`"]
+    pub fn pair_name(&self) -> String {
+        format!(
+            "{}:{} ↔ {}:{}",
+            self.resolve_primary_cluster(),
+            self.index_name,
+            self.replica_cluster,
+            self.resolve_replica_index_name()
+        )
+    }
+
+    #[doc = "원본/복제본 문서 수 차이가 허용치를 넘는지 여부"]
+    pub fn is_diverged(&self, diff_cnt: usize, diff_percent: f64) -> bool {
+        if self.max_diff_count.is_none() && self.max_diff_percent.is_none() {
+            return diff_cnt > 0;
+        }
+
+        self.max_diff_count
+            .is_some_and(|max_diff_count| diff_cnt > max_diff_count)
+            || self
+                .max_diff_percent
+                .is_some_and(|max_diff_percent| diff_percent > max_diff_percent)
+    }
+}
@@ -0,0 +1,210 @@
+use crate::common::*;
+
+use crate::model::index::{index_config::*, index_list_config::*};
+
+#[doc = r#"
+    인덱스별 수집/검증 스케줄 - 인덱스마다 `sample_interval_sec` 주기로 실행 시각을 관리한다.
+
+    처음 등록되는 인덱스는 주기 내의 임의 시각(jitter)에 첫 실행을 배치하여
+    같은 주기의 인덱스들이 한 시점에 몰리지 않도록 분산한다.
+
+    # Fields
+    * `next_due` - 시계열(인덱스)별 다음 실행 시각 (UTC)
+"#]
+#[derive(Debug, Default)]
+pub struct IndexSchedule {
+    next_due: HashMap<String, DateTime<Utc>>,
+}
+
+impl IndexSchedule {
+    #[doc = r#"
+        현재 시각에 실행할 인덱스 설정만 골라 반환하는 함수.
+
+        1. 설정에서 사라진 인덱스(rollover 등)는 스케줄에서 제거
+        2. 새로 등록된 인덱스는 `now + [0, 주기)` 범위의 임의 시각을 첫 실행 시각으로 배치
+        3. 실행 시각이 된 인덱스는 다음 실행 시각을 한 주기 뒤로 옮긴다
+           - 주기를 놓친 경우(처리 지연) 밀린 실행을 몰아서 하지 않고 `now + 주기`로 재배치
+
+        # Arguments
+        * `resolved_index_list` - 펼쳐진(expand) 전체 인덱스 설정
+        * `now` - 현재 시각 (UTC)
+
+        # Returns
+        * `IndexListConfig` - 이번에 실행할 인덱스 설정
+    "#]
+    pub fn take_due(
+        &mut self,
+        resolved_index_list: &IndexListConfig,
+        now: DateTime<Utc>,
+    ) -> IndexListConfig {
+        let mut rng: StdRng = StdRng::from_entropy();
+
+        self.next_due.retain(|index_name, _| {
+            resolved_index_list
+                .index()
+                .iter()
+                .any(|index_config| index_config.index_name() == index_name)
+        });
+
+        let mut due_configs: Vec<IndexConfig> = Vec::new();
+
+        for index_config in resolved_index_list.index() {
+            let interval_ms: i64 = (index_config.resolve_sample_interval_sec() * 1000) as i64;
+
+            let next_due: &mut DateTime<Utc> = self
+                .next_due
+                .entry(index_config.index_name().to_string())
+                .or_insert_with(|| {
+                    now + chrono::Duration::milliseconds(rng.gen_range(0..interval_ms))
+                });
+
+            if *next_due > now {
+                continue;
+            }
+
+            *next_due += chrono::Duration::milliseconds(interval_ms);
+
+            if *next_due <= now {
+                *next_due = now + chrono::Duration::milliseconds(interval_ms);
+            }
+
+            due_configs.push(index_config.clone());
+        }
+
+        IndexListConfig::new(due_configs)
+    }
+
+    #[doc = "가장 먼저 돌아오는 실행 시각 - 등록된 인덱스가 없으면 None"]
+    pub fn next_wake_at(&self) -> Option<DateTime<Utc>> {
+        self.next_due.values().min().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* 테스트 인덱스 수집 주기(초) */
+    const INTERVAL_SEC: i64 = 60;
+
+    fn index_list(index_names: &[&str]) -> IndexListConfig {
+        IndexListConfig::new(
+            index_names
+                .iter()
+                .map(|index_name| {
+                    serde_json::from_value(json!({
+                        "index_name": index_name,
+                        "allowable_fluctuation_range": 10.0,
+                        "agg_term_sec": 600,
+                        "sample_interval_sec": INTERVAL_SEC
+                    }))
+                    .unwrap()
+                })
+                .collect(),
+        )
+    }
+
+    fn due_names(due_list: &IndexListConfig) -> Vec<&str> {
+        due_list
+            .index()
+            .iter()
+            .map(|index_config| index_config.index_name().as_str())
+            .collect()
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn empty_schedule_has_no_wake_time() {
+        let mut schedule: IndexSchedule = IndexSchedule::default();
+
+        assert_eq!(schedule.next_wake_at(), None);
+        assert!(
+            schedule
+                .take_due(&index_list(&[]), now())
+                .index()
+                .is_empty()
+        );
+        assert_eq!(schedule.next_wake_at(), None);
+    }
+
+    #[test]
+    fn new_index_is_jittered_within_one_interval() {
+        let mut schedule: IndexSchedule = IndexSchedule::default();
+        let interval: chrono::Duration = chrono::Duration::seconds(INTERVAL_SEC);
+
+        schedule.take_due(&index_list(&["a", "b", "c"]), now());
+
+        for next_due in schedule.next_due.values() {
+            assert!(*next_due >= now());
+            assert!(*next_due <= now() + interval);
+        }
+
+        /* 첫 실행 시각이 주기 내 어디든 한 주기 뒤에는 모두 실행된다 */
+        let due_list: IndexListConfig =
+            schedule.take_due(&index_list(&["a", "b", "c"]), now() + interval);
+
+        assert_eq!(due_names(&due_list), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn due_index_moves_one_interval_forward() {
+        let mut schedule: IndexSchedule = IndexSchedule::default();
+        let interval: chrono::Duration = chrono::Duration::seconds(INTERVAL_SEC);
+        let first_due: DateTime<Utc> = now() - chrono::Duration::seconds(10);
+
+        schedule.next_due.insert("a".to_string(), first_due);
+
+        assert_eq!(
+            due_names(&schedule.take_due(&index_list(&["a"]), now())),
+            vec!["a"]
+        );
+        assert_eq!(schedule.next_wake_at(), Some(first_due + interval));
+
+        /* 다음 실행 시각 전에는 실행하지 않는다 */
+        assert!(
+            schedule
+                .take_due(&index_list(&["a"]), now())
+                .index()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn missed_intervals_are_not_replayed() {
+        let mut schedule: IndexSchedule = IndexSchedule::default();
+        let interval: chrono::Duration = chrono::Duration::seconds(INTERVAL_SEC);
+        let late_now: DateTime<Utc> = now() + interval * 10;
+
+        schedule.next_due.insert("a".to_string(), now());
+
+        assert_eq!(
+            due_names(&schedule.take_due(&index_list(&["a"]), late_now)),
+            vec!["a"]
+        );
+        assert_eq!(schedule.next_wake_at(), Some(late_now + interval));
+        assert!(
+            schedule
+                .take_due(&index_list(&["a"]), late_now)
+                .index()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn removed_index_is_dropped_from_schedule() {
+        let mut schedule: IndexSchedule = IndexSchedule::default();
+
+        schedule.next_due.insert("a".to_string(), now());
+        schedule
+            .next_due
+            .insert("gone".to_string(), now() - chrono::Duration::seconds(30));
+
+        let due_list: IndexListConfig = schedule.take_due(&index_list(&["a"]), now());
+
+        assert_eq!(due_names(&due_list), vec!["a"]);
+        assert!(!schedule.next_due.contains_key("gone"));
+    }
+}
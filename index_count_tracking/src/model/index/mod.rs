@@ -1,4 +1,10 @@
 pub mod alert_index;
 pub mod alert_index_format;
+pub mod breakdown_bucket;
 pub mod index_config;
 pub mod index_list_config;
+pub mod index_pair_config;
+pub mod index_schedule;
+pub mod monitor_rollup;
+pub mod named_query_filter;
+pub mod reconcile_config;
@@ -0,0 +1,131 @@
+use crate::common::*;
+
+use crate::dto::index_stats::*;
+
+use crate::utils_modules::{time_utils::*, traits::*};
+
+#[doc = r#"
+    원본 보관 기간이 지난 모니터링 샘플을 시계열(인덱스)별 1시간 단위로 압축한 롤업 문서
+
+    `index_name` / `cnt` / `timestamp` 필드는 샘플(`AlertIndex`)과 같은 의미로 기록하므로
+    리포트 조회 쿼리는 원본 샘플과 롤업을 구분하지 않고 함께 읽을 수 있다 (시간 단위 해상도).
+
+    # Fields
+    * `index_name` - 시계열(인덱스)명
+    * `target_name` - expand 모드로 수집된 경우 원래 대상(패턴/별칭)명
+    * `cluster_name` - 수집 대상 클러스터명
+    * `timestamp` - 롤업 구간(1시간) 시작 시각 (UTC)
+    * `cnt` - 구간의 마지막 문서 수 (= `last_cnt`)
+    * `first_cnt` / `last_cnt` - 구간의 처음/마지막 문서 수
+    * `min_cnt` / `max_cnt` - 구간의 최소/최대 문서 수
+    * `sample_cnt` - 압축된 원본 샘플 수
+    * `index_stats` - 구간의 마지막 `_stats` 값 (수집된 경우)
+"#]
+#[derive(Debug, Clone, Deserialize, Serialize, Getters, new)]
+#[allow(clippy::too_many_arguments)]
+#[getset(get = "pub")]
+pub struct MonitorRollup {
+    pub index_name: String,
+    pub target_name: Option<String>,
+    pub cluster_name: Option<String>,
+    pub timestamp: String,
+    pub cnt: usize,
+    pub first_cnt: usize,
+    pub last_cnt: usize,
+    pub min_cnt: usize,
+    pub max_cnt: usize,
+    pub sample_cnt: usize,
+    pub index_stats: Option<IndexStats>,
+}
+
+impl MonitorRollup {
+    #[doc = "롤업 문서 ID - 같은 구간을 다시 압축해도 중복되지 않도록 `{index_name}|{timestamp}` 사용"]
+    pub fn doc_id(&self) -> String {
+        format!("{}|{}", self.index_name, self.timestamp)
+    }
+}
+
+impl FromAggBucket for MonitorRollup {
+    fn from_agg_bucket(bucket: &Value) -> anyhow::Result<Self> {
+        /* composite key: { index_name, hour(epoch millis) } */
+        let index_name: String = bucket["key"]["index_name"]
+            .as_str()
+            .ok_or_else(|| {
+                anyhow!("[MonitorRollup->from_agg_bucket] Missing or invalid 'key.index_name'")
+            })?
+            .to_string();
+
+        let hour_start: DateTime<Utc> = bucket["key"]["hour"]
+            .as_i64()
+            .and_then(|hour_millis| Utc.timestamp_millis_opt(hour_millis).single())
+            .ok_or_else(|| {
+                anyhow!("[MonitorRollup->from_agg_bucket] Missing or invalid 'key.hour'")
+            })?;
+
+        let metric_of = |agg_name: &str, field: &str| -> Option<u64> {
+            bucket[agg_name]["top"][0]["metrics"][field]
+                .as_f64()
+                .map(|value| value as u64)
+        };
+
+        let first_cnt: u64 = metric_of("first", "cnt").ok_or_else(|| {
+            anyhow!("[MonitorRollup->from_agg_bucket] Missing 'first.top[0].metrics.cnt'")
+        })?;
+        let last_cnt: u64 = metric_of("last", "cnt").ok_or_else(|| {
+            anyhow!("[MonitorRollup->from_agg_bucket] Missing 'last.top[0].metrics.cnt'")
+        })?;
+
+        let min_cnt: u64 = bucket["min_cnt"]["value"]
+            .as_f64()
+            .map(|value| value as u64)
+            .unwrap_or(first_cnt.min(last_cnt));
+        let max_cnt: u64 = bucket["max_cnt"]["value"]
+            .as_f64()
+            .map(|value| value as u64)
+            .unwrap_or(first_cnt.max(last_cnt));
+
+        /* _stats 를 수집하지 않는 인덱스는 None */
+        let index_stats: Option<IndexStats> = match (
+            metric_of("last", "index_stats.store_size_bytes"),
+            metric_of("last", "index_stats.docs_deleted"),
+            metric_of("last", "index_stats.indexing_total"),
+            metric_of("last", "index_stats.delete_total"),
+            metric_of("last", "index_stats.segment_cnt"),
+        ) {
+            (
+                Some(store_size_bytes),
+                Some(docs_deleted),
+                Some(indexing_total),
+                Some(delete_total),
+                Some(segment_cnt),
+            ) => Some(IndexStats::new(
+                store_size_bytes,
+                docs_deleted,
+                indexing_total,
+                delete_total,
+                segment_cnt,
+            )),
+            _ => None,
+        };
+
+        let first_term_of = |agg_name: &str| -> Option<String> {
+            bucket[agg_name]["buckets"][0]["key"]
+                .as_str()
+                .map(str::to_string)
+        };
+
+        Ok(MonitorRollup::new(
+            index_name,
+            first_term_of("target_name"),
+            first_term_of("cluster_name"),
+            convert_date_to_str(hour_start, Utc),
+            last_cnt as usize,
+            first_cnt as usize,
+            last_cnt as usize,
+            min_cnt as usize,
+            max_cnt as usize,
+            bucket["doc_count"].as_u64().unwrap_or(0) as usize,
+            index_stats,
+        ))
+    }
+}
@@ -0,0 +1,15 @@
+use crate::common::*;
+
+#[doc = r#"
+    인덱스 내 특정 조건을 만족하는 문서 수를 별도의 시계열로 추적하기 위한 이름 있는 필터
+
+    # Fields
+    * `name` - 필터명 (시계열명: `{index_name}#{name}`)
+    * `query` - Elasticsearch query DSL (예: `{ term = { status = "open" } }`)
+"#]
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct NamedQueryFilter {
+    pub name: String,
+    pub query: Value,
+}
@@ -0,0 +1,37 @@
+use crate::common::*;
+
+#[doc = r#"
+    원본 데이터(SQL Server 테이블) 대비 인덱스 문서 수 정합성 검증 설정
+
+    매 수집 주기마다 `count_query` 로 원본 테이블의 행 수를 조회하여 인덱스 문서 수와 비교하고,
+    차이가 허용치를 넘으면 알람을 발송한다.
+
+    # Fields
+    * `count_query` - 원본 행 수를 조회하는 SQL (첫 번째 행의 첫 번째 컬럼을 행 수로 사용 - 예: `SELECT COUNT_BIG(*) FROM dbo.TB_NOTICE WITH (NOLOCK)`)
+    * `max_diff_count` - 허용 행 수 차이(건)
+    * `max_diff_percent` - 허용 행 수 차이율(%) - 원본 행 수 기준 / 두 허용치가 모두 없으면 차이가 있으면 불일치
+"#]
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct ReconcileConfig {
+    pub count_query: String,
+    #[serde(default)]
+    pub max_diff_count: Option<usize>,
+    #[serde(default)]
+    pub max_diff_percent: Option<f64>,
+}
+
+impl ReconcileConfig {
+    #[doc = "원본 행 수와 인덱스 문서 수 차이가 허용치를 넘는지 여부"]
+    pub fn is_drifted(&self, diff_cnt: usize, diff_percent: f64) -> bool {
+        if self.max_diff_count.is_none() && self.max_diff_percent.is_none() {
+            return diff_cnt > 0;
+        }
+
+        self.max_diff_count
+            .is_some_and(|max_diff_count| diff_cnt > max_diff_count)
+            || self
+                .max_diff_percent
+                .is_some_and(|max_diff_percent| diff_percent > max_diff_percent)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod alarm;
 pub mod configs;
 pub mod index;
 pub mod report;
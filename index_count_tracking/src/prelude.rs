@@ -1,8 +1,9 @@
 pub use std::{
-    env, fmt::Display, fs, io::Write, path::Path, path::PathBuf, str::FromStr, sync::Arc,
+    collections::HashMap, env, fmt::Display, fs, io::Write, path::Path, path::PathBuf,
+    str::FromStr, sync::Arc, sync::Mutex,
 };
 
-pub use tokio::time::{Duration, Instant, Interval, interval, sleep, sleep_until};
+pub use tokio::time::{Duration, Instant, sleep, sleep_until};
 
 pub use anyhow::{Context, anyhow};
 pub use async_trait::async_trait;
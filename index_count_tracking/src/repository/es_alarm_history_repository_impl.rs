@@ -0,0 +1,207 @@
+use crate::common::*;
+
+use crate::traits::repository_traits::{
+    alarm_history_repository::*, es_repository::*, spool_repository::*,
+};
+
+use crate::repository::{es_repository_impl::*, spool_repository_impl::*};
+
+use crate::dto::{
+    alarm::{alarm_log_history_index::*, alarm_report_infos::*},
+    index_name_count::*,
+    spool_entry::*,
+};
+
+use crate::utils_modules::{io_utils::*, time_utils::*, traits::*};
+
+#[doc = r#"
+    모니터링 Elasticsearch 클러스터에 알람 이력을 저장/조회하는 알람 이력 저장소
+
+    - 알람 이력은 알람 인덱스(`alarm_log_index.index_name`)에 한 건씩 색인하고,
+      색인에 실패하면 로컬 스풀에 보관해 클러스터 복구 후 재전송한다
+
+    # Fields
+    * `es_conn` - 모니터링 클러스터 연결
+"#]
+#[derive(Debug, new)]
+pub struct EsAlarmHistoryRepositoryImpl {
+    es_conn: Arc<EsRepositoryImpl>,
+}
+
+impl EsAlarmHistoryRepositoryImpl {
+    #[doc = "timestamp 구간 조회 range query"]
+    fn time_range_query(start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> Value {
+        json!({
+            "range": {
+                "timestamp": {
+                    "gte": convert_date_to_str(start_time, Utc),
+                    "lte": convert_date_to_str(end_time, Utc)
+                }
+            }
+        })
+    }
+
+    #[doc = "집계 응답의 `aggregations.[agg_name].buckets` 를 `FromAggBucket` 으로 변환"]
+    fn parse_agg_buckets<T>(response_body: &Value, agg_name: &str) -> anyhow::Result<Vec<T>>
+    where
+        T: FromAggBucket,
+    {
+        let buckets: &Vec<Value> = response_body["aggregations"][agg_name]["buckets"]
+            .as_array()
+            .ok_or_else(|| {
+                anyhow!(
+                    "[EsAlarmHistoryRepositoryImpl->parse_agg_buckets] Missing 'aggregations.{}.buckets'",
+                    agg_name
+                )
+            })?;
+
+        buckets.iter().map(T::from_agg_bucket).collect()
+    }
+}
+
+#[async_trait]
+impl AlarmHistoryRepository for EsAlarmHistoryRepositoryImpl {
+    #[doc = "알람 이력을 알람 인덱스에 문서로 색인(저장)하는 함수 - 실패 시 로컬 스풀에 보관."]
+    async fn write_alarm_history(
+        &self,
+        index_name: &str,
+        alarm_history: &AlarmLogHistoryIndex,
+    ) -> Result<(), anyhow::Error> {
+        if let Err(e) = self
+            .es_conn
+            .post_query_struct(alarm_history, index_name)
+            .await
+        {
+            error!(
+                "[EsAlarmHistoryRepositoryImpl->write_alarm_history] Spooling alarm history for '{}': {:?}",
+                index_name, e
+            );
+
+            let document: Value = convert_json_from_struct(alarm_history)?;
+            get_spool_repo().append_entries(&[SpoolEntry::new(
+                index_name.to_string(),
+                None,
+                document,
+            )])?;
+        }
+
+        Ok(())
+    }
+
+    #[doc = r#"
+        구간 내 인덱스별 가장 최근 알람 이력을 조회하는 함수.
+
+        1. 시간 범위(`start_time` ~ `end_time`) 내의 알람 이력 문서를 대상으로 검색
+        2. `index_name.keyword` 필드로 그룹화하고 `top_hits`로 timestamp 기준 최신 1건을 조회
+
+        # Arguments
+        * `alarm_index_name` - 알람 이력이 저장된 인덱스명
+        * `start_time` - 조회 시작 시간 (UTC)
+        * `end_time` - 조회 종료 시간 (UTC)
+
+        # Returns
+        * `Vec<AlarmLogHistoryIndex>` - 인덱스별 최신 알람 이력 목록
+        * `anyhow::Error` - ES 조회 실패 또는 파싱 실패 시
+    "#]
+    async fn read_latest_alarm_histories(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<AlarmLogHistoryIndex>, anyhow::Error> {
+        let search_query: Value = json!({
+            "size": 0,
+            "track_total_hits": false,
+            "query": Self::time_range_query(start_time, end_time),
+            "aggs": {
+                "by_index_name": {
+                    "terms": {
+                        "field": "index_name.keyword",
+                        "size": 10000
+                    },
+                    "aggs": {
+                        "latest": {
+                            "top_hits": {
+                                "size": 1,
+                                "sort": [ { "timestamp": { "order": "desc" } } ]
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        let response_body: Value = self
+            .es_conn
+            .get_search_query(&search_query, alarm_index_name)
+            .await?;
+
+        Self::parse_agg_buckets(&response_body, "by_index_name")
+    }
+
+    #[doc = r#"
+        구간 내 인덱스별 알람 발생 건수와 알람이 발생한 인덱스 수를 조회하는 함수.
+
+        장애 해소(`alert_status = resolved`) 이력은 알람 건수에서 제외한다.
+
+        # Arguments
+        * `alarm_index_name` - 알람 이력이 저장된 인덱스명
+        * `start_time` - 조회 시작 시간 (UTC)
+        * `end_time` - 조회 종료 시간 (UTC)
+
+        # Returns
+        * `AlarmReportInfos` - 인덱스별 알람 건수(건수 내림차순)와 알람 발생 인덱스 수
+        * `anyhow::Error` - ES 조회 실패 또는 파싱 실패 시
+    "#]
+    async fn read_alarm_index_counts(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<AlarmReportInfos, anyhow::Error> {
+        let search_query: Value = json!({
+            "size": 0,
+            "track_total_hits": false,
+            "query": {
+                "bool": {
+                    "filter": [ Self::time_range_query(start_time, end_time) ],
+                    "must_not": [
+                        { "term": { "alert_status.keyword": "resolved" } }
+                    ]
+                }
+            },
+            "aggs": {
+                "by_index_name": {
+                    "terms": {
+                        "field": "index_name.keyword",
+                        "size": 10000
+                    }
+                },
+                "distinct_index_name_count": {
+                    "cardinality": {
+                        "field": "index_name.keyword"
+                    }
+                }
+            }
+        });
+
+        let response_body: Value = self
+            .es_conn
+            .get_search_query(&search_query, alarm_index_name)
+            .await?;
+
+        let buckets: Vec<IndexNameCount> =
+            Self::parse_agg_buckets(&response_body, "by_index_name")?;
+
+        let distinct_count_u64: u64 = response_body["aggregations"]["distinct_index_name_count"]
+            ["value"]
+            .as_u64()
+            .ok_or_else(|| {
+                anyhow!(
+                    "[EsAlarmHistoryRepositoryImpl->read_alarm_index_counts] Missing 'aggregations.distinct_index_name_count.value'"
+                )
+            })?;
+
+        Ok(AlarmReportInfos::new(buckets, distinct_count_u64))
+    }
+}
@@ -71,6 +71,74 @@ impl EsRepositoryImpl {
             last_error
         ))
     }
+
+    #[doc = "Common logic: bulk indexing - documents without an id get an id generated by Elasticsearch"]
+    async fn post_bulk_documents(
+        &self,
+        documents: &[(Option<&str>, &Value)],
+        index_name: &str,
+    ) -> Result<(), anyhow::Error> {
+        if documents.is_empty() {
+            return Ok(());
+        }
+
+        let response: Response = self
+            .execute_on_any_node(|es_client| async move {
+                let body: Vec<JsonBody<Value>> = documents
+                    .iter()
+                    .flat_map(|(doc_id, document)| {
+                        let action: Value = match doc_id {
+                            Some(doc_id) => json!({ "index": { "_id": doc_id } }),
+                            None => json!({ "index": {} }),
+                        };
+
+                        [JsonBody::new(action), JsonBody::new((*document).clone())]
+                    })
+                    .collect();
+
+                let response: Response = es_client
+                    .es_conn
+                    .bulk(BulkParts::Index(index_name))
+                    .body(body)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if !response.status_code().is_success() {
+            let error_message = format!(
+                "[node_bulk_query()] Failed to bulk index documents: Status Code: {}",
+                response.status_code()
+            );
+            return Err(anyhow!(error_message));
+        }
+
+        /* A bulk request succeeds as a whole even if some items fail. */
+        let response_body: Value = response.json::<Value>().await?;
+
+        if response_body["errors"].as_bool().unwrap_or(false) {
+            let failed_items: Vec<&Value> = response_body["items"]
+                .as_array()
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter(|item| !item["index"]["error"].is_null())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            return Err(anyhow!(
+                "[node_bulk_query()] {} of {} documents failed: {:?}",
+                failed_items.len(),
+                documents.len(),
+                failed_items.first().map(|item| &item["index"]["error"])
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -106,6 +174,46 @@ impl EsRepository for EsRepositoryImpl {
         }
     }
 
+    #[doc = "Function that EXECUTES elasticsearch queries - multi search (one round trip for several (index, query) pairs)"]
+    async fn get_msearch_query(
+        &self,
+        es_queries: &[(String, Value)],
+    ) -> Result<Value, anyhow::Error> {
+        let response: Response = self
+            .execute_on_any_node(|es_client| async move {
+                let body: Vec<JsonBody<Value>> = es_queries
+                    .iter()
+                    .flat_map(|(index_name, es_query)| {
+                        [
+                            JsonBody::new(json!({ "index": index_name })),
+                            JsonBody::new(es_query.clone()),
+                        ]
+                    })
+                    .collect();
+
+                let response: Response = es_client
+                    .es_conn
+                    .msearch(MsearchParts::None)
+                    .body(body)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            Ok(response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(
+                "[node_msearch_query()] response status is failed: {:?}",
+                error_body
+            ))
+        }
+    }
+
     #[doc = "Function that EXECUTES elasticsearch queries - indexing struct"]
     async fn post_query_struct<T: Serialize + Sync>(
         &self,
@@ -144,6 +252,208 @@ impl EsRepository for EsRepositoryImpl {
         }
     }
 
+    #[doc = "Function that EXECUTES elasticsearch queries - bulk indexing (one round trip for several documents)"]
+    async fn post_bulk_query(
+        &self,
+        documents: &[Value],
+        index_name: &str,
+    ) -> Result<(), anyhow::Error> {
+        let documents: Vec<(Option<&str>, &Value)> =
+            documents.iter().map(|document| (None, document)).collect();
+
+        self.post_bulk_documents(&documents, index_name).await
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - bulk indexing with document ids (re-indexing the same id overwrites the document)"]
+    async fn post_bulk_query_with_ids(
+        &self,
+        documents: &[(String, Value)],
+        index_name: &str,
+    ) -> Result<(), anyhow::Error> {
+        let documents: Vec<(Option<&str>, &Value)> = documents
+            .iter()
+            .map(|(doc_id, document)| (Some(doc_id.as_str()), document))
+            .collect();
+
+        self.post_bulk_documents(&documents, index_name).await
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - resolve index (indices/aliases/data streams matching a name or pattern)"]
+    async fn get_resolve_index(&self, index_name: &str) -> Result<Value, anyhow::Error> {
+        let response: Response = self
+            .execute_on_any_node(|es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .resolve_index(IndicesResolveIndexParts::Name(&[index_name]))
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            Ok(response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(
+                "[node_resolve_index()] response status is failed: {:?}",
+                error_body
+            ))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - index stats (store / docs / indexing / segments)"]
+    async fn get_index_stats(&self, index_name: &str) -> Result<Value, anyhow::Error> {
+        let response: Response = self
+            .execute_on_any_node(|es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .stats(IndicesStatsParts::IndexMetric(
+                        &[index_name],
+                        &["store", "docs", "indexing", "segments"],
+                    ))
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            Ok(response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(
+                "[node_index_stats()] response status is failed: {:?}",
+                error_body
+            ))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - stats of several indices at once (per-index level)"]
+    async fn get_indices_stats(&self, index_names: &[&str]) -> Result<Value, anyhow::Error> {
+        let response: Response = self
+            .execute_on_any_node(|es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .stats(IndicesStatsParts::IndexMetric(
+                        index_names,
+                        &["store", "docs", "indexing", "segments"],
+                    ))
+                    .level(Level::Indices)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            Ok(response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(
+                "[node_indices_stats()] response status is failed: {:?}",
+                error_body
+            ))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - create or update a composable index template"]
+    async fn put_index_template(
+        &self,
+        template_name: &str,
+        template_body: &Value,
+    ) -> Result<(), anyhow::Error> {
+        let response: Response = self
+            .execute_on_any_node(|es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .put_index_template(IndicesPutIndexTemplateParts::Name(template_name))
+                    .body(template_body)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            Ok(())
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(
+                "[node_put_index_template()] response status is failed: {:?}",
+                error_body
+            ))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - create or update an ILM policy"]
+    async fn put_ilm_policy(
+        &self,
+        policy_name: &str,
+        policy_body: &Value,
+    ) -> Result<(), anyhow::Error> {
+        let response: Response = self
+            .execute_on_any_node(|es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .ilm()
+                    .put_lifecycle(IlmPutLifecycleParts::Policy(policy_name))
+                    .body(policy_body)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            Ok(())
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(
+                "[node_put_ilm_policy()] response status is failed: {:?}",
+                error_body
+            ))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - delete an index"]
+    async fn delete_index(&self, index_name: &str) -> Result<(), anyhow::Error> {
+        let response: Response = self
+            .execute_on_any_node(|es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .delete(IndicesDeleteParts::Index(&[index_name]))
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            Ok(())
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(
+                "[node_delete_index()] response status is failed: {:?}",
+                error_body
+            ))
+        }
+    }
+
     #[doc = "Function that EXECUTES elasticsearch queries - delete"]
     async fn delete_query(&self, doc_id: &str, index_name: &str) -> Result<(), anyhow::Error> {
         let response: Response = self
@@ -0,0 +1,541 @@
+use crate::common::*;
+
+use crate::model::configs::{compaction_config::*, total_config::*};
+use crate::model::index::{alert_index::*, alert_index_format::*};
+
+use crate::traits::repository_traits::{
+    es_repository::*, spool_repository::*, time_series_repository::*,
+};
+
+use crate::repository::{es_repository_impl::*, spool_repository_impl::*};
+
+use crate::dto::{
+    index_count_agg_result::*, index_name_count::*, index_pair_sample::*, pair_consistency_info::*,
+    spool_entry::*,
+};
+
+use crate::enums::sort_order::*;
+
+use crate::utils_modules::{io_utils::*, time_utils::*, traits::*};
+
+#[doc = r#"
+    모니터링 Elasticsearch 클러스터에 샘플을 저장/조회하는 시계열 저장소
+
+    - 샘플은 `{monitor_index_name}YYYYMMDD` 일자별 인덱스에 문서 ID 를 지정해 `_bulk` 로 색인하고,
+      색인에 실패하면 로컬 스풀에 보관해 클러스터 복구 후 같은 ID 로 재전송한다
+    - 조회 구간이 원본 보관 기간(`compaction.raw_retention_days`)보다 오래된 경우
+      시간 단위 롤업 인덱스를 함께 조회한다
+
+    # Fields
+    * `es_conn` - 모니터링 클러스터 연결
+"#]
+#[derive(Debug, new)]
+pub struct EsTimeSeriesRepositoryImpl {
+    es_conn: Arc<EsRepositoryImpl>,
+}
+
+impl EsTimeSeriesRepositoryImpl {
+    #[doc = r#"
+        조회 대상 인덱스 - 조회 구간이 원본 보관 기간보다 오래된 경우 롤업 인덱스를 함께 조회한다.
+
+        압축된 일자별 인덱스는 삭제되므로 원본 샘플과 롤업 문서는 시간상 겹치지 않는다.
+
+        # Arguments
+        * `mon_index_name` - 모니터링 인덱스명(패턴)
+        * `start_time` - 조회 시작 시각 (UTC)
+
+        # Returns
+        * `String` - `{mon_index_name}` 또는 `{mon_index_name},{rollup_index_name}*`
+    "#]
+    fn with_rollup_indices(&self, mon_index_name: &str, start_time: DateTime<Utc>) -> String {
+        let compaction_config: &CompactionConfig = get_compaction_config_info();
+
+        match compaction_config.raw_cutoff_time(Utc::now()) {
+            Some(raw_cutoff_time) if start_time < raw_cutoff_time => format!(
+                "{},{}*",
+                mon_index_name,
+                compaction_config
+                    .resolve_rollup_index_name(get_system_config_info().monitor_index_name())
+            ),
+            _ => mon_index_name.to_string(),
+        }
+    }
+
+    #[doc = r#"
+        문서 목록을 한 번의 `_bulk` 요청으로 색인하고, 실패(일부 문서 실패 포함) 시 로컬 스풀에 보관하는 함수.
+
+        일부 문서만 실패한 경우에도 요청 전체를 스풀에 보관하지만, 문서 ID 가 고정되어 있으므로
+        이미 색인된 문서는 재전송 시 덮어써질 뿐 중복되지 않는다.
+
+        # Arguments
+        * `index_name` - 문서를 저장할 인덱스명
+        * `documents` - 색인할 (문서 ID, 문서) 목록
+
+        # Returns
+        * `anyhow::Error` - 색인과 스풀 기록이 모두 실패한 경우
+    "#]
+    async fn post_bulk_or_spool(
+        &self,
+        index_name: &str,
+        documents: Vec<(String, Value)>,
+    ) -> anyhow::Result<()> {
+        let Err(e) = self
+            .es_conn
+            .post_bulk_query_with_ids(&documents, index_name)
+            .await
+        else {
+            return Ok(());
+        };
+
+        error!(
+            "[EsTimeSeriesRepositoryImpl->post_bulk_or_spool] Spooling {} documents for '{}': {:?}",
+            documents.len(),
+            index_name,
+            e
+        );
+
+        let spool_entries: Vec<SpoolEntry> = documents
+            .into_iter()
+            .map(|(doc_id, document)| {
+                SpoolEntry::new(index_name.to_string(), Some(doc_id), document)
+            })
+            .collect();
+
+        get_spool_repo().append_entries(&spool_entries)
+    }
+
+    #[doc = "시계열 하나의 구간 조회 filter (timestamp range + index_name term)"]
+    fn series_range_filter(
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Value {
+        json!({
+            "bool": {
+                "filter": [
+                    {
+                        "range": {
+                            "timestamp": {
+                                "gte": convert_date_to_str(start_time, Utc),
+                                "lte": convert_date_to_str(end_time, Utc)
+                            }
+                        }
+                    },
+                    {
+                        "term": {
+                            "index_name.keyword": series_name
+                        }
+                    }
+                ]
+            }
+        })
+    }
+
+    #[doc = "검색 응답의 `hits.hits` 를 샘플(`AlertIndex`) 목록으로 변환"]
+    fn parse_sample_hits(response_body: &Value) -> anyhow::Result<Vec<AlertIndex>> {
+        let hits: &Vec<Value> = response_body["hits"]["hits"].as_array().ok_or_else(|| {
+            anyhow!("[EsTimeSeriesRepositoryImpl->parse_sample_hits] Missing 'hits.hits'")
+        })?;
+
+        hits.iter()
+            .map(|hit| {
+                let doc_id: String = hit["_id"].as_str().unwrap_or_default().to_string();
+                let source: AlertIndex = serde_json::from_value(hit["_source"].clone())
+                    .map_err(|e| {
+                        anyhow!(
+                            "[EsTimeSeriesRepositoryImpl->parse_sample_hits] Failed to deserialize source: {:?}",
+                            e
+                        )
+                    })?;
+
+                Ok(AlertIndexFormat::from_search_hit(doc_id, source).alert_index)
+            })
+            .collect()
+    }
+
+    #[doc = "집계 응답의 `aggregations.[agg_name].buckets` 를 `FromAggBucket` 으로 변환"]
+    fn parse_agg_buckets<T>(response_body: &Value, agg_name: &str) -> anyhow::Result<Vec<T>>
+    where
+        T: FromAggBucket,
+    {
+        let buckets: &Vec<Value> = response_body["aggregations"][agg_name]["buckets"]
+            .as_array()
+            .ok_or_else(|| {
+                anyhow!(
+                    "[EsTimeSeriesRepositoryImpl->parse_agg_buckets] Missing 'aggregations.{}.buckets'",
+                    agg_name
+                )
+            })?;
+
+        buckets.iter().map(T::from_agg_bucket).collect()
+    }
+}
+
+#[async_trait]
+impl TimeSeriesRepository for EsTimeSeriesRepositoryImpl {
+    #[doc = r#"
+        샘플 목록을 일자별 모니터링 인덱스에 한 번의 `_bulk` 요청으로 색인하는 함수.
+
+        색인 실패(일부 문서 실패 포함) 시 로컬 스풀에 보관하고 성공으로 반환한다 (이후 재전송).
+
+        # Arguments
+        * `index_name` - 문서를 저장할 일자별 모니터링 인덱스명
+        * `samples` - 색인할 샘플 목록
+
+        # Returns
+        * `anyhow::Error` - 직렬화 실패 또는 색인과 스풀 기록이 모두 실패한 경우
+    "#]
+    async fn write_samples(
+        &self,
+        index_name: &str,
+        samples: &[AlertIndex],
+    ) -> Result<(), anyhow::Error> {
+        let documents: Vec<(String, Value)> = samples
+            .iter()
+            .map(|sample| Ok((sample.doc_id(), convert_json_from_struct(sample)?)))
+            .collect::<anyhow::Result<Vec<(String, Value)>>>()?;
+
+        self.post_bulk_or_spool(index_name, documents).await
+    }
+
+    #[doc = "시계열 하나의 구간 내 샘플을 timestamp 순으로 최대 `size` 건 조회하는 함수."]
+    async fn read_samples(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        order: SortOrder,
+        size: usize,
+    ) -> Result<Vec<AlertIndex>, anyhow::Error> {
+        let search_query: Value = json!({
+            "query": Self::series_range_filter(series_name, start_time, end_time),
+            "sort": [SortSpec { field: "timestamp", order }.to_es_json()],
+            "size": size
+        });
+
+        let response_body: Value = self
+            .es_conn
+            .get_search_query(
+                &search_query,
+                &self.with_rollup_indices(mon_index_name, start_time),
+            )
+            .await?;
+
+        Self::parse_sample_hits(&response_body)
+    }
+
+    #[doc = "시계열 하나의 구간 내에서 문서 수가 `cnt` 와 다른 가장 최근 샘플을 조회하는 함수."]
+    async fn read_last_changed_sample(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        cnt: usize,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<AlertIndex>, anyhow::Error> {
+        let mut query: Value = Self::series_range_filter(series_name, start_time, end_time);
+        query["bool"]["must_not"] = json!([{ "term": { "cnt": cnt } }]);
+
+        let search_query: Value = json!({
+            "query": query,
+            "sort": [SortSpec { field: "timestamp", order: SortOrder::Desc }.to_es_json()],
+            "size": 1
+        });
+
+        let response_body: Value = self
+            .es_conn
+            .get_search_query(
+                &search_query,
+                &self.with_rollup_indices(mon_index_name, start_time),
+            )
+            .await?;
+
+        Ok(Self::parse_sample_hits(&response_body)?.into_iter().next())
+    }
+
+    #[doc = r#"
+        시계열 하나의 구간 내 최소/최대 문서 수를 조회하는 함수.
+
+        롤업 문서는 구간 내 최소/최대 문서 수를 `min_cnt`/`max_cnt` 필드로 가지고 있으므로
+        원본 샘플의 `cnt` 집계와 합쳐서 계산한다. 구간 내 문서가 없으면 None.
+    "#]
+    async fn read_min_max_cnt(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<(f64, f64)>, anyhow::Error> {
+        let search_query: Value = json!({
+            "size": 0,
+            "track_total_hits": false,
+            "query": Self::series_range_filter(series_name, start_time, end_time),
+            "aggs": {
+                "cnt_max": { "max": { "field": "cnt" } },
+                "cnt_min": { "min": { "field": "cnt" } },
+                "rollup_cnt_max": { "max": { "field": "max_cnt" } },
+                "rollup_cnt_min": { "min": { "field": "min_cnt" } }
+            }
+        });
+
+        let response_body: Value = self
+            .es_conn
+            .get_search_query(
+                &search_query,
+                &self.with_rollup_indices(mon_index_name, start_time),
+            )
+            .await?;
+
+        /* 문서가 없는 집계는 null - NaN/무한대 방어 */
+        let metric = |agg_name: &str| -> Option<f64> {
+            response_body["aggregations"][agg_name]["value"]
+                .as_f64()
+                .filter(|value| value.is_finite())
+        };
+
+        let cnt_min: Option<f64> = match (metric("cnt_min"), metric("rollup_cnt_min")) {
+            (Some(cnt_min), Some(rollup_cnt_min)) => Some(cnt_min.min(rollup_cnt_min)),
+            (cnt_min, rollup_cnt_min) => cnt_min.or(rollup_cnt_min),
+        };
+
+        let cnt_max: Option<f64> = match (metric("cnt_max"), metric("rollup_cnt_max")) {
+            (Some(cnt_max), Some(rollup_cnt_max)) => Some(cnt_max.max(rollup_cnt_max)),
+            (cnt_max, rollup_cnt_max) => cnt_max.or(rollup_cnt_max),
+        };
+
+        Ok(cnt_min.zip(cnt_max))
+    }
+
+    #[doc = "시계열 하나의 구간 내 최초/최종 샘플을 조회하는 함수 - 둘 중 하나라도 없으면 None."]
+    async fn read_first_last_samples(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<(AlertIndex, AlertIndex)>, anyhow::Error> {
+        let first: Option<AlertIndex> = self
+            .read_samples(
+                mon_index_name,
+                series_name,
+                start_time,
+                end_time,
+                SortOrder::Asc,
+                1,
+            )
+            .await?
+            .into_iter()
+            .next();
+
+        let last: Option<AlertIndex> = self
+            .read_samples(
+                mon_index_name,
+                series_name,
+                start_time,
+                end_time,
+                SortOrder::Desc,
+                1,
+            )
+            .await?
+            .into_iter()
+            .next();
+
+        Ok(first.zip(last))
+    }
+
+    #[doc = r#"
+        구간 내 모든 시계열의 최초(`Asc`) 또는 최종(`Desc`) 문서 수를 조회하는 함수.
+
+        `index_name` terms 집계 아래의 top_metrics 로 시계열마다 한 건씩 구한다.
+    "#]
+    async fn read_boundary_cnts(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        order: SortOrder,
+    ) -> Result<Vec<IndexCountAggResult>, anyhow::Error> {
+        let search_query: Value = json!({
+            "size": 1,
+            "track_total_hits": false,
+            "query": {
+                "range": {
+                    "timestamp": {
+                        "gte": convert_date_to_str(start_time, Utc),
+                        "lte": convert_date_to_str(end_time, Utc)
+                    }
+                }
+            },
+            "aggs": {
+                "by_index": {
+                    "terms": {
+                        "field": "index_name.keyword",
+                        "size": 1000
+                    },
+                    "aggs": {
+                        "est_cnt": {
+                            "top_metrics": {
+                                "sort": [SortSpec { field: "timestamp", order }.to_es_json()],
+                                "metrics": [
+                                    { "field": "cnt" },
+                                    { "field": "timestamp" }
+                                ]
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        let response_body: Value = self
+            .es_conn
+            .get_search_query(
+                &search_query,
+                &self.with_rollup_indices(mon_index_name, start_time),
+            )
+            .await?;
+
+        Self::parse_agg_buckets(&response_body, "by_index")
+    }
+
+    #[doc = "expand 모드 대상(`target_name`)으로 구간 내 수집된 시계열명 목록을 오름차순으로 조회하는 함수."]
+    async fn read_series_names(
+        &self,
+        mon_index_name: &str,
+        target_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<String>, anyhow::Error> {
+        let search_query: Value = json!({
+            "size": 0,
+            "track_total_hits": false,
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "target_name.keyword": target_name } },
+                        {
+                            "range": {
+                                "timestamp": {
+                                    "gte": convert_date_to_str(start_time, Utc),
+                                    "lte": convert_date_to_str(end_time, Utc)
+                                }
+                            }
+                        }
+                    ]
+                }
+            },
+            "aggs": {
+                "by_index_name": {
+                    "terms": {
+                        "field": "index_name.keyword",
+                        "size": 10000,
+                        "order": { "_key": "asc" }
+                    }
+                }
+            }
+        });
+
+        let response_body: Value = self
+            .es_conn
+            .get_search_query(
+                &search_query,
+                &self.with_rollup_indices(mon_index_name, start_time),
+            )
+            .await?;
+
+        let buckets: Vec<IndexNameCount> =
+            Self::parse_agg_buckets(&response_body, "by_index_name")?;
+
+        Ok(buckets.into_iter().map(|bucket| bucket.name).collect())
+    }
+
+    #[doc = "원본/복제 클러스터 정합성 검증 결과(페어 샘플)를 일자별 모니터링 인덱스에 한 번의 `_bulk` 요청으로 색인하는 함수 - 실패 시 로컬 스풀에 보관."]
+    async fn write_pair_samples(
+        &self,
+        index_name: &str,
+        pair_samples: &[IndexPairSample],
+    ) -> Result<(), anyhow::Error> {
+        let documents: Vec<(String, Value)> = pair_samples
+            .iter()
+            .map(|pair_sample| Ok((pair_sample.doc_id(), convert_json_from_struct(pair_sample)?)))
+            .collect::<anyhow::Result<Vec<(String, Value)>>>()?;
+
+        self.post_bulk_or_spool(index_name, documents).await
+    }
+
+    #[doc = r#"
+        구간 내 페어별 복제 정합성 요약을 조회하는 함수.
+
+        1. `pair_name` 필드가 있는 문서(페어 샘플)만 기간으로 필터링
+           - 압축된 일자별 인덱스의 페어 샘플은 롤업 인덱스로 옮겨지므로 필요 시 롤업 인덱스를 함께 조회
+        2. `pair_name` terms 집계 아래에서
+           - `latest`: 가장 최근 샘플 (top_hits)
+           - `max_diff_cnt`: 기간 중 최대 문서 수 차이
+           - `diverged`: 허용치를 넘은 샘플 수
+        3. 각 버킷을 `PairConsistencyInfo`로 변환
+
+        # Arguments
+        * `mon_index_name` - 모니터링 인덱스명(패턴)
+        * `start_time` - 조회 시작 시각 (UTC)
+        * `end_time` - 조회 종료 시각 (UTC)
+
+        # Returns
+        * `Vec<PairConsistencyInfo>` - 페어명 오름차순 정합성 요약
+    "#]
+    async fn read_pair_consistency_infos(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<PairConsistencyInfo>, anyhow::Error> {
+        let search_query: Value = json!({
+            "size": 0,
+            "track_total_hits": false,
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "exists": { "field": "pair_name" } },
+                        {
+                            "range": {
+                                "timestamp": {
+                                    "gte": convert_date_to_str(start_time, Utc),
+                                    "lte": convert_date_to_str(end_time, Utc)
+                                }
+                            }
+                        }
+                    ]
+                }
+            },
+            "aggs": {
+                "by_pair_name": {
+                    "terms": {
+                        "field": "pair_name.keyword",
+                        "size": 1000,
+                        "order": { "_key": "asc" }
+                    },
+                    "aggs": {
+                        "latest": {
+                            "top_hits": {
+                                "size": 1,
+                                "sort": [{ "timestamp": { "order": "desc" } }]
+                            }
+                        },
+                        "max_diff_cnt": { "max": { "field": "diff_cnt" } },
+                        "diverged": { "filter": { "term": { "diverged": true } } }
+                    }
+                }
+            }
+        });
+
+        let response_body: Value = self
+            .es_conn
+            .get_search_query(
+                &search_query,
+                &self.with_rollup_indices(mon_index_name, start_time),
+            )
+            .await?;
+
+        Self::parse_agg_buckets(&response_body, "by_pair_name")
+    }
+}
@@ -0,0 +1,180 @@
+use crate::common::*;
+
+use crate::traits::repository_traits::alarm_history_repository::*;
+
+use crate::dto::{
+    alarm::{alarm_log_history_index::*, alarm_report_infos::*},
+    index_name_count::*,
+};
+
+use crate::enums::alert_status::*;
+
+use crate::utils_modules::{io_utils::*, time_utils::*};
+
+/* 알람 이력 파일 확장자 */
+const ALARM_FILE_EXTENSION: &str = "jsonl";
+
+#[doc = r#"
+    모니터링 클러스터 없이 로컬 디스크에 알람 이력을 저장/조회하는 알람 이력 저장소
+
+    - 알람 이력은 알람 인덱스명과 같은 이름의 JSON Lines 파일(`{storage_dir}/{index_name}.jsonl`)에
+      한 줄씩 추가 기록하고 디스크에 동기화(fsync)한다
+    - 조회는 디렉토리를 훑지 않고 알람 인덱스명(패턴의 `*` 제외)의 파일 하나만 읽어 메모리에서 필터링/집계한다
+    - 파일 입출력은 `spawn_blocking` 으로 실행해 비동기 런타임 스레드를 막지 않는다
+    - ILM 보관 기간이 적용되지 않으므로 오래된 이력은 직접 정리해야 한다
+
+    # Fields
+    * `storage_dir` - 알람 이력 파일 디렉토리
+    * `file_lock` - 파일 접근 직렬화용 잠금 (await 중에도 유지되는 비동기 잠금)
+"#]
+#[derive(Debug, Getters, new)]
+#[getset(get = "pub")]
+pub struct LocalAlarmHistoryRepositoryImpl {
+    storage_dir: PathBuf,
+    #[new(default)]
+    file_lock: tokio::sync::Mutex<()>,
+}
+
+impl LocalAlarmHistoryRepositoryImpl {
+    #[doc = r#"
+        알람 인덱스명(패턴)에 해당하는 파일에서 조회 구간에 속하는 알람 이력을 모두 읽는 함수.
+
+        # Arguments
+        * `alarm_index_name` - 알람 인덱스명(패턴, 예: `alarm_index_name*`)
+        * `start_time` - 조회 시작 시각 (UTC)
+        * `end_time` - 조회 종료 시각 (UTC)
+
+        # Returns
+        * `Vec<(DateTime<Utc>, AlarmLogHistoryIndex)>` - (이력 시각, 이력) 목록 (정렬되지 않음)
+        * `anyhow::Error` - 파일 읽기 실패 시
+    "#]
+    async fn read_range(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<(DateTime<Utc>, AlarmLogHistoryIndex)>> {
+        let file_path: PathBuf = self.storage_dir.join(format!(
+            "{}.{}",
+            alarm_index_name.trim_end_matches('*'),
+            ALARM_FILE_EXTENSION
+        ));
+
+        let _guard = self.file_lock.lock().await;
+
+        let handle: tokio::task::JoinHandle<anyhow::Result<Vec<AlarmLogHistoryIndex>>> =
+            tokio::task::spawn_blocking(move || {
+                if !file_path.exists() {
+                    return Ok(Vec::new());
+                }
+
+                read_jsonl_file::<AlarmLogHistoryIndex>(&file_path)
+            });
+
+        let alarm_histories: Vec<AlarmLogHistoryIndex> = handle
+            .await
+            .context("[LocalAlarmHistoryRepositoryImpl->read_range] blocking task join failed")??;
+
+        Ok(alarm_histories
+            .into_iter()
+            .filter_map(|alarm_history| {
+                let history_time: DateTime<Utc> =
+                    convert_utc_from_str(&alarm_history.timestamp).ok()?;
+                (history_time >= start_time && history_time <= end_time)
+                    .then_some((history_time, alarm_history))
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl AlarmHistoryRepository for LocalAlarmHistoryRepositoryImpl {
+    #[doc = "알람 이력을 알람 이력 파일 끝에 한 줄 추가 기록하는 함수."]
+    async fn write_alarm_history(
+        &self,
+        index_name: &str,
+        alarm_history: &AlarmLogHistoryIndex,
+    ) -> Result<(), anyhow::Error> {
+        let file_path: PathBuf = self
+            .storage_dir
+            .join(format!("{}.{}", index_name, ALARM_FILE_EXTENSION));
+        let alarm_history: AlarmLogHistoryIndex = alarm_history.clone();
+
+        let _guard = self.file_lock.lock().await;
+
+        let handle: tokio::task::JoinHandle<anyhow::Result<()>> =
+            tokio::task::spawn_blocking(move || {
+                append_jsonl_file(&file_path, std::slice::from_ref(&alarm_history))
+            });
+
+        handle.await.context(
+            "[LocalAlarmHistoryRepositoryImpl->write_alarm_history] blocking task join failed",
+        )?
+    }
+
+    #[doc = "구간 내 인덱스별 가장 최근 알람 이력을 조회하는 함수."]
+    async fn read_latest_alarm_histories(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<AlarmLogHistoryIndex>, anyhow::Error> {
+        /* 인덱스명 -> (이력 시각, 최근 이력) */
+        let mut latest_histories: HashMap<String, (DateTime<Utc>, AlarmLogHistoryIndex)> =
+            HashMap::new();
+
+        for (history_time, alarm_history) in self
+            .read_range(alarm_index_name, start_time, end_time)
+            .await?
+        {
+            let is_latest: bool = latest_histories
+                .get(&alarm_history.index_name)
+                .is_none_or(|(latest_time, _)| history_time > *latest_time);
+
+            if is_latest {
+                latest_histories.insert(
+                    alarm_history.index_name.clone(),
+                    (history_time, alarm_history),
+                );
+            }
+        }
+
+        Ok(latest_histories
+            .into_values()
+            .map(|(_, alarm_history)| alarm_history)
+            .collect())
+    }
+
+    #[doc = "구간 내 인덱스별 알람 발생 건수(장애 해소 이력 제외)와 알람이 발생한 인덱스 수를 조회하는 함수."]
+    async fn read_alarm_index_counts(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<AlarmReportInfos, anyhow::Error> {
+        let mut alarm_cnts: HashMap<String, u64> = HashMap::new();
+
+        for (_, alarm_history) in self
+            .read_range(alarm_index_name, start_time, end_time)
+            .await?
+        {
+            if alarm_history.alert_status == AlertStatus::Resolved {
+                continue;
+            }
+
+            *alarm_cnts.entry(alarm_history.index_name).or_insert(0) += 1;
+        }
+
+        let mut buckets: Vec<IndexNameCount> = alarm_cnts
+            .into_iter()
+            .map(|(index_name, count)| IndexNameCount::new(index_name, count))
+            .collect();
+
+        /* terms 집계와 같은 순서 - 건수 내림차순, 같으면 인덱스명 오름차순 */
+        buckets.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+
+        let distinct_count_u64: u64 = buckets.len() as u64;
+
+        Ok(AlarmReportInfos::new(buckets, distinct_count_u64))
+    }
+}
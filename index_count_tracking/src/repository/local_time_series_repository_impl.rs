@@ -0,0 +1,468 @@
+use crate::common::*;
+
+use crate::model::index::alert_index::*;
+
+use crate::traits::repository_traits::time_series_repository::*;
+
+use crate::dto::{index_count_agg_result::*, index_pair_sample::*, pair_consistency_info::*};
+
+use crate::enums::sort_order::*;
+
+use crate::utils_modules::{io_utils::*, time_utils::*};
+
+/* 샘플 파일 확장자 */
+const SAMPLE_FILE_EXTENSION: &str = "jsonl";
+
+/* 페어 샘플 파일 하위 디렉토리 - 인덱스 샘플(`AlertIndex`) 파일과 섞이지 않도록 분리 */
+const PAIR_SAMPLE_DIR: &str = "pair";
+
+#[doc = r#"
+    모니터링 클러스터 없이 로컬 디스크에 샘플을 저장/조회하는 시계열 저장소
+
+    - 샘플은 일자별 모니터링 인덱스명과 같은 이름의 JSON Lines 파일(`{storage_dir}/{index_name}.jsonl`)에
+      한 줄씩 추가 기록하고 디스크에 동기화(fsync)한다
+    - 페어 샘플은 같은 이름으로 `{storage_dir}/pair/` 아래에 따로 기록한다
+    - 조회는 디렉토리를 훑지 않고 조회 구간의 일자(앞뒤 1일 포함)로 파일명을 만들어 해당 파일만 읽고
+      메모리에서 필터링/정렬/집계한다 - 소규모 환경이나 테스트 용도
+    - 파일 입출력은 `spawn_blocking` 으로 실행해 비동기 런타임 스레드를 막지 않는다
+    - 롤업 압축(`compaction`)과 ILM 보관 기간은 적용되지 않으므로 오래된 파일은 직접 정리해야 한다
+
+    # Fields
+    * `storage_dir` - 샘플 파일 디렉토리
+    * `file_lock` - 파일 접근 직렬화용 잠금 (await 중에도 유지되는 비동기 잠금)
+"#]
+#[derive(Debug, Getters, new)]
+#[getset(get = "pub")]
+pub struct LocalTimeSeriesRepositoryImpl {
+    storage_dir: PathBuf,
+    #[new(default)]
+    file_lock: tokio::sync::Mutex<()>,
+}
+
+impl LocalTimeSeriesRepositoryImpl {
+    #[doc = r#"
+        레코드 목록을 `{record_dir}/{index_name}.jsonl` 파일 끝에 한 줄씩 추가 기록하는 함수.
+
+        # Arguments
+        * `record_dir` - 레코드 파일 디렉토리
+        * `index_name` - 일자별 모니터링 인덱스명 (파일명)
+        * `records` - 기록할 레코드 목록
+
+        # Returns
+        * `anyhow::Error` - 직렬화/파일 기록 실패 시
+    "#]
+    async fn append_records<T>(
+        &self,
+        record_dir: &Path,
+        index_name: &str,
+        records: &[T],
+    ) -> anyhow::Result<()>
+    where
+        T: Serialize + Clone + Send + 'static,
+    {
+        let file_path: PathBuf =
+            record_dir.join(format!("{}.{}", index_name, SAMPLE_FILE_EXTENSION));
+        let records: Vec<T> = records.to_vec();
+
+        let _guard = self.file_lock.lock().await;
+
+        let handle: tokio::task::JoinHandle<anyhow::Result<()>> =
+            tokio::task::spawn_blocking(move || append_jsonl_file(&file_path, &records));
+
+        handle
+            .await
+            .context("[LocalTimeSeriesRepositoryImpl->append_records] blocking task join failed")?
+    }
+
+    #[doc = r#"
+        조회 구간에 해당하는 일자별 파일의 레코드를 모두 읽는 함수.
+
+        1. 조회 구간 앞뒤 1일 이내의 일자마다 `{record_dir}/{prefix}YYYYMMDD.jsonl` 파일명을 만든다
+           - 일자별 인덱스명은 수집 시각 기준이므로 시간대 차이를 고려해 앞뒤 1일을 함께 읽는다
+           - 패턴(`*`)이 아닌 인덱스명이 주어지면 그 이름의 파일 하나만 읽는다
+        2. 존재하는 파일만 읽어 각 줄을 `T` 로 변환 - 깨진 줄(기록 도중 종료 등)은 건너뛴다
+
+        # Arguments
+        * `record_dir` - 레코드 파일 디렉토리
+        * `mon_index_name` - 모니터링 인덱스명(패턴, 예: `monitor_index_name*`)
+        * `start_time` - 조회 시작 시각 (UTC)
+        * `end_time` - 조회 종료 시각 (UTC)
+
+        # Returns
+        * `Vec<T>` - 선택된 파일의 레코드 목록 (timestamp 로 걸러지지 않음)
+        * `anyhow::Error` - 파일 읽기 실패 시
+    "#]
+    async fn read_range_records<T>(
+        &self,
+        record_dir: &Path,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let file_paths: Vec<PathBuf> = match mon_index_name.strip_suffix('*') {
+            Some(index_prefix) => {
+                let first_date: chrono::NaiveDate =
+                    (start_time - chrono::Duration::days(1)).date_naive();
+                let last_date: chrono::NaiveDate =
+                    (end_time + chrono::Duration::days(1)).date_naive();
+
+                first_date
+                    .iter_days()
+                    .take_while(|file_date| *file_date <= last_date)
+                    .map(|file_date| {
+                        record_dir.join(format!(
+                            "{}{}.{}",
+                            index_prefix,
+                            file_date.format("%Y%m%d"),
+                            SAMPLE_FILE_EXTENSION
+                        ))
+                    })
+                    .collect()
+            }
+            None => vec![record_dir.join(format!("{}.{}", mon_index_name, SAMPLE_FILE_EXTENSION))],
+        };
+
+        let _guard = self.file_lock.lock().await;
+
+        let handle: tokio::task::JoinHandle<anyhow::Result<Vec<T>>> =
+            tokio::task::spawn_blocking(move || {
+                let mut records: Vec<T> = Vec::new();
+
+                for file_path in file_paths.iter().filter(|file_path| file_path.exists()) {
+                    records.extend(read_jsonl_file::<T>(file_path)?);
+                }
+
+                Ok(records)
+            });
+
+        handle.await.context(
+            "[LocalTimeSeriesRepositoryImpl->read_range_records] blocking task join failed",
+        )?
+    }
+
+    #[doc = r#"
+        모니터링 인덱스명(패턴)과 조회 구간에 해당하는 샘플을 모두 읽어 시각과 함께 반환하는 함수.
+
+        timestamp 가 조회 구간(`start_time` ~ `end_time`, 양 끝 포함)에 속하는 샘플만 반환한다.
+
+        # Arguments
+        * `mon_index_name` - 모니터링 인덱스명(패턴, 예: `monitor_index_name*`)
+        * `start_time` - 조회 시작 시각 (UTC)
+        * `end_time` - 조회 종료 시각 (UTC)
+
+        # Returns
+        * `Vec<(DateTime<Utc>, AlertIndex)>` - (샘플 시각, 샘플) 목록 (정렬되지 않음)
+        * `anyhow::Error` - 디렉토리/파일 읽기 실패 시
+    "#]
+    async fn read_range(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<(DateTime<Utc>, AlertIndex)>> {
+        Ok(self
+            .read_range_records::<AlertIndex>(
+                &self.storage_dir,
+                mon_index_name,
+                start_time,
+                end_time,
+            )
+            .await?
+            .into_iter()
+            .filter_map(|sample| {
+                let sample_time: DateTime<Utc> = convert_utc_from_str(sample.timestamp()).ok()?;
+                (sample_time >= start_time && sample_time <= end_time)
+                    .then_some((sample_time, sample))
+            })
+            .collect())
+    }
+
+    #[doc = "시계열 하나의 구간 내 샘플을 시각 순으로 정렬해 반환하는 함수"]
+    async fn read_series(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        order: SortOrder,
+    ) -> anyhow::Result<Vec<AlertIndex>> {
+        let mut samples: Vec<(DateTime<Utc>, AlertIndex)> = self
+            .read_range(mon_index_name, start_time, end_time)
+            .await?
+            .into_iter()
+            .filter(|(_, sample)| sample.index_name() == series_name)
+            .collect();
+
+        match order {
+            SortOrder::Asc => samples.sort_by_key(|(sample_time, _)| *sample_time),
+            SortOrder::Desc => {
+                samples.sort_by_key(|(sample_time, _)| std::cmp::Reverse(*sample_time))
+            }
+        }
+
+        Ok(samples.into_iter().map(|(_, sample)| sample).collect())
+    }
+}
+
+#[async_trait]
+impl TimeSeriesRepository for LocalTimeSeriesRepositoryImpl {
+    #[doc = "샘플 목록을 일자별 샘플 파일 끝에 한 줄씩 추가 기록하는 함수."]
+    async fn write_samples(
+        &self,
+        index_name: &str,
+        samples: &[AlertIndex],
+    ) -> Result<(), anyhow::Error> {
+        self.append_records(&self.storage_dir, index_name, samples)
+            .await
+    }
+
+    #[doc = "시계열 하나의 구간 내 샘플을 timestamp 순으로 최대 `size` 건 조회하는 함수."]
+    async fn read_samples(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        order: SortOrder,
+        size: usize,
+    ) -> Result<Vec<AlertIndex>, anyhow::Error> {
+        let mut samples: Vec<AlertIndex> = self
+            .read_series(mon_index_name, series_name, start_time, end_time, order)
+            .await?;
+
+        samples.truncate(size);
+
+        Ok(samples)
+    }
+
+    #[doc = "시계열 하나의 구간 내에서 문서 수가 `cnt` 와 다른 가장 최근 샘플을 조회하는 함수."]
+    async fn read_last_changed_sample(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        cnt: usize,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<AlertIndex>, anyhow::Error> {
+        Ok(self
+            .read_series(
+                mon_index_name,
+                series_name,
+                start_time,
+                end_time,
+                SortOrder::Desc,
+            )
+            .await?
+            .into_iter()
+            .find(|sample| *sample.cnt() != cnt))
+    }
+
+    #[doc = "시계열 하나의 구간 내 최소/최대 문서 수를 조회하는 함수 - 구간 내 샘플이 없으면 None."]
+    async fn read_min_max_cnt(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<(f64, f64)>, anyhow::Error> {
+        let samples: Vec<AlertIndex> = self
+            .read_series(
+                mon_index_name,
+                series_name,
+                start_time,
+                end_time,
+                SortOrder::Asc,
+            )
+            .await?;
+
+        let cnt_min: Option<usize> = samples.iter().map(|sample| *sample.cnt()).min();
+        let cnt_max: Option<usize> = samples.iter().map(|sample| *sample.cnt()).max();
+
+        Ok(cnt_min
+            .zip(cnt_max)
+            .map(|(cnt_min, cnt_max)| (cnt_min as f64, cnt_max as f64)))
+    }
+
+    #[doc = "시계열 하나의 구간 내 최초/최종 샘플을 조회하는 함수 - 구간 내 샘플이 없으면 None."]
+    async fn read_first_last_samples(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<(AlertIndex, AlertIndex)>, anyhow::Error> {
+        let mut samples: Vec<AlertIndex> = self
+            .read_series(
+                mon_index_name,
+                series_name,
+                start_time,
+                end_time,
+                SortOrder::Asc,
+            )
+            .await?;
+
+        let last: Option<AlertIndex> = samples.pop();
+        let first: Option<AlertIndex> = if samples.is_empty() {
+            last.clone()
+        } else {
+            Some(samples.swap_remove(0))
+        };
+
+        Ok(first.zip(last))
+    }
+
+    #[doc = "구간 내 모든 시계열의 최초(`Asc`) 또는 최종(`Desc`) 문서 수를 시계열명 순으로 조회하는 함수."]
+    async fn read_boundary_cnts(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        order: SortOrder,
+    ) -> Result<Vec<IndexCountAggResult>, anyhow::Error> {
+        /* 시계열명 -> (샘플 수, 경계 시각, 경계 샘플) */
+        let mut boundaries: HashMap<String, (usize, DateTime<Utc>, AlertIndex)> = HashMap::new();
+
+        for (sample_time, sample) in self
+            .read_range(mon_index_name, start_time, end_time)
+            .await?
+        {
+            match boundaries.get_mut(sample.index_name()) {
+                Some((doc_count, boundary_time, boundary_sample)) => {
+                    *doc_count += 1;
+
+                    let is_boundary: bool = match order {
+                        SortOrder::Asc => sample_time < *boundary_time,
+                        SortOrder::Desc => sample_time > *boundary_time,
+                    };
+
+                    if is_boundary {
+                        *boundary_time = sample_time;
+                        *boundary_sample = sample;
+                    }
+                }
+                None => {
+                    boundaries.insert(sample.index_name().to_string(), (1, sample_time, sample));
+                }
+            }
+        }
+
+        let mut results: Vec<IndexCountAggResult> = boundaries
+            .into_iter()
+            .map(|(index_name, (doc_count, _, sample))| {
+                IndexCountAggResult::new(
+                    index_name,
+                    doc_count,
+                    *sample.cnt(),
+                    sample.timestamp().to_string(),
+                )
+            })
+            .collect();
+
+        results.sort_by(|a, b| a.index_name().cmp(b.index_name()));
+
+        Ok(results)
+    }
+
+    #[doc = "expand 모드 대상(`target_name`)으로 구간 내 수집된 시계열명 목록을 오름차순으로 조회하는 함수."]
+    async fn read_series_names(
+        &self,
+        mon_index_name: &str,
+        target_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<String>, anyhow::Error> {
+        let mut series_names: Vec<String> = self
+            .read_range(mon_index_name, start_time, end_time)
+            .await?
+            .into_iter()
+            .filter(|(_, sample)| sample.target_name().as_deref() == Some(target_name))
+            .map(|(_, sample)| sample.index_name)
+            .collect();
+
+        series_names.sort();
+        series_names.dedup();
+
+        Ok(series_names)
+    }
+
+    #[doc = "페어 샘플 목록을 일자별 페어 샘플 파일(`pair/{index_name}.jsonl`) 끝에 한 줄씩 추가 기록하는 함수."]
+    async fn write_pair_samples(
+        &self,
+        index_name: &str,
+        pair_samples: &[IndexPairSample],
+    ) -> Result<(), anyhow::Error> {
+        self.append_records(
+            &self.storage_dir.join(PAIR_SAMPLE_DIR),
+            index_name,
+            pair_samples,
+        )
+        .await
+    }
+
+    #[doc = "구간 내 페어별 복제 정합성 요약(최근 샘플, 최대 문서 수 차이, 불일치 샘플 수)을 페어명 오름차순으로 조회하는 함수."]
+    async fn read_pair_consistency_infos(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<PairConsistencyInfo>, anyhow::Error> {
+        /* 페어명 -> (최근 샘플 시각, 정합성 요약) */
+        let mut pair_infos: HashMap<String, (DateTime<Utc>, PairConsistencyInfo)> = HashMap::new();
+
+        let pair_samples: Vec<IndexPairSample> = self
+            .read_range_records(
+                &self.storage_dir.join(PAIR_SAMPLE_DIR),
+                mon_index_name,
+                start_time,
+                end_time,
+            )
+            .await?;
+
+        for pair_sample in pair_samples {
+            let Ok(sample_time) = convert_utc_from_str(pair_sample.timestamp()) else {
+                continue;
+            };
+
+            if sample_time < start_time || sample_time > end_time {
+                continue;
+            }
+
+            let diff_cnt: u64 = *pair_sample.diff_cnt() as u64;
+            let diverged_cnt: u64 = u64::from(*pair_sample.diverged());
+
+            match pair_infos.get_mut(pair_sample.pair_name()) {
+                Some((latest_time, pair_info)) => {
+                    pair_info.max_diff_cnt = pair_info.max_diff_cnt.max(diff_cnt);
+                    pair_info.sample_cnt += 1;
+                    pair_info.diverged_cnt += diverged_cnt;
+
+                    if sample_time > *latest_time {
+                        *latest_time = sample_time;
+                        pair_info.latest = pair_sample;
+                    }
+                }
+                None => {
+                    pair_infos.insert(
+                        pair_sample.pair_name().to_string(),
+                        (
+                            sample_time,
+                            PairConsistencyInfo::new(pair_sample, diff_cnt, 1, diverged_cnt),
+                        ),
+                    );
+                }
+            }
+        }
+
+        let mut results: Vec<PairConsistencyInfo> = pair_infos
+            .into_values()
+            .map(|(_, pair_info)| pair_info)
+            .collect();
+
+        results.sort_by(|a, b| a.latest().pair_name().cmp(b.latest().pair_name()));
+
+        Ok(results)
+    }
+}
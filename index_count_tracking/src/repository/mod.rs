@@ -1,3 +1,9 @@
+pub mod es_alarm_history_repository_impl;
 pub mod es_repository_impl;
+pub mod es_time_series_repository_impl;
+pub mod local_alarm_history_repository_impl;
+pub mod local_time_series_repository_impl;
+pub mod spool_repository_impl;
 pub mod sqlserver_repository_impl;
 pub mod telegram_repository_impl;
+pub mod time_series_repository_factory;
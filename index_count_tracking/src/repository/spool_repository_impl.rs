@@ -0,0 +1,122 @@
+use crate::common::*;
+
+use crate::model::configs::total_config::*;
+
+use crate::traits::repository_traits::spool_repository::*;
+
+use crate::dto::spool_entry::*;
+
+use crate::utils_modules::io_utils::*;
+
+/* 스풀 디렉토리 기본 경로 (system.spool_dir 미설정 시) */
+const DEFAULT_SPOOL_DIR: &str = "./spool";
+/* 색인 실패 문서를 추가 기록하는 파일 */
+const SPOOL_FILE_NAME: &str = "mon_spool.jsonl";
+/* 재전송 중인 문서 파일 - 재전송하는 동안에도 새 문서는 SPOOL_FILE_NAME 에 계속 추가된다 */
+const REPLAY_FILE_NAME: &str = "mon_spool.replay.jsonl";
+
+#[doc = "전역 Spool 인스턴스 선언"]
+static SPOOL_REPO: once_lazy<Arc<SpoolRepositoryImpl>> = once_lazy::new(initialize_spool_repo);
+
+#[doc = r#"
+    모니터링 클러스터 장애 시 문서를 보관하는 로컬 스풀(append-only JSON Lines 파일)
+
+    - 추가(`append_entries`)는 항상 스풀 파일 끝에 한 줄씩 기록하고 디스크에 동기화(fsync)한다
+    - 재전송(`take_replay_entries`)은 스풀 파일을 재전송 파일로 옮긴(rename) 뒤 읽으므로,
+      재전송 중에 새로 스풀된 문서와 섞이지 않는다
+    - 재전송이 끝나면(`complete_replay`) 남은 문서만 재전송 파일에 다시 쓰고, 남은 문서가 없으면 파일을 삭제한다
+
+    # Fields
+    * `spool_path` - 스풀 파일 경로
+    * `replay_path` - 재전송 파일 경로
+    * `file_lock` - 파일 접근 직렬화용 잠금
+"#]
+#[derive(Debug, Getters, new)]
+#[getset(get = "pub")]
+pub struct SpoolRepositoryImpl {
+    spool_path: PathBuf,
+    replay_path: PathBuf,
+    #[new(default)]
+    file_lock: Mutex<()>,
+}
+
+#[doc = "로컬 스풀 초기화 - 스풀 디렉토리가 없으면 생성"]
+fn initialize_spool_repo() -> Arc<SpoolRepositoryImpl> {
+    info!("initialize_spool_repo() START!");
+
+    let spool_dir: PathBuf = PathBuf::from(
+        get_system_config_info()
+            .spool_dir()
+            .as_deref()
+            .unwrap_or(DEFAULT_SPOOL_DIR),
+    );
+
+    if let Err(e) = fs::create_dir_all(&spool_dir) {
+        error!(
+            "[ERROR][initialize_spool_repo] Failed to create spool directory {:?}: {:?}",
+            spool_dir, e
+        );
+    }
+
+    Arc::new(SpoolRepositoryImpl::new(
+        spool_dir.join(SPOOL_FILE_NAME),
+        spool_dir.join(REPLAY_FILE_NAME),
+    ))
+}
+
+#[doc = "로컬 스풀을 Thread-safe 하게 이용하는 함수."]
+pub fn get_spool_repo() -> Arc<SpoolRepositoryImpl> {
+    Arc::clone(&SPOOL_REPO)
+}
+
+impl SpoolRepository for SpoolRepositoryImpl {
+    #[doc = "색인하지 못한 문서를 스풀 파일 끝에 추가하고 디스크에 동기화"]
+    fn append_entries(&self, entries: &[SpoolEntry]) -> Result<(), anyhow::Error> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let _guard = self
+            .file_lock
+            .lock()
+            .map_err(|e| anyhow!("[SpoolRepositoryImpl->append_entries] {:?}", e))?;
+
+        append_jsonl_file(&self.spool_path, entries)
+    }
+
+    #[doc = "재전송할 문서 목록 - 이전 재전송에서 남은 문서가 있으면 그것부터, 없으면 스풀 파일을 재전송 파일로 옮겨서 읽는다"]
+    fn take_replay_entries(&self) -> Result<Vec<SpoolEntry>, anyhow::Error> {
+        let _guard = self
+            .file_lock
+            .lock()
+            .map_err(|e| anyhow!("[SpoolRepositoryImpl->take_replay_entries] {:?}", e))?;
+
+        if !self.replay_path.exists() {
+            if !self.spool_path.exists() {
+                return Ok(Vec::new());
+            }
+
+            fs::rename(&self.spool_path, &self.replay_path)?;
+        }
+
+        read_jsonl_file::<SpoolEntry>(&self.replay_path)
+    }
+
+    #[doc = "재전송 결과 반영 - 남은 문서만 재전송 파일에 다시 쓰고, 모두 전송되었으면 재전송 파일 삭제"]
+    fn complete_replay(&self, remaining_entries: &[SpoolEntry]) -> Result<(), anyhow::Error> {
+        let _guard = self
+            .file_lock
+            .lock()
+            .map_err(|e| anyhow!("[SpoolRepositoryImpl->complete_replay] {:?}", e))?;
+
+        if remaining_entries.is_empty() {
+            if self.replay_path.exists() {
+                fs::remove_file(&self.replay_path)?;
+            }
+            return Ok(());
+        }
+
+        /* 임시 파일에 쓴 뒤 교체하여 기록 도중 종료되어도 재전송 파일이 깨지지 않도록 함 */
+        replace_jsonl_file(&self.replay_path, remaining_entries)
+    }
+}
@@ -115,4 +115,37 @@ impl SqlServerRepository for SqlServerRepositoryImpl {
 
         Ok(())
     }
+
+    #[doc = "원본 테이블 행 수 조회 - COUNT(*) (int) / COUNT_BIG(*) (bigint) 결과 모두 지원"]
+    async fn get_row_count(&self, count_query: &str) -> Result<usize, anyhow::Error> {
+        /* 풀에서 커넥션 가져오기 */
+        let pool: &deadpool_tiberius::deadpool::managed::Pool<Manager> = self.pool();
+        let mut client: deadpool_tiberius::deadpool::managed::Object<Manager> = pool.get().await?;
+
+        let row: tiberius::Row = client
+            .simple_query(count_query)
+            .await?
+            .into_row()
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "[SqlServerRepositoryImpl->get_row_count] no row returned: {}",
+                    count_query
+                )
+            })?;
+
+        let row_cnt: i64 = match row.try_get::<i64, usize>(0) {
+            Ok(Some(row_cnt)) => row_cnt,
+            Ok(None) => 0,
+            Err(_) => row.try_get::<i32, usize>(0)?.map(i64::from).unwrap_or(0),
+        };
+
+        usize::try_from(row_cnt).map_err(|e| {
+            anyhow!(
+                "[SqlServerRepositoryImpl->get_row_count] invalid row count {}: {:?}",
+                row_cnt,
+                e
+            )
+        })
+    }
 }
@@ -0,0 +1,54 @@
+use crate::common::*;
+
+use crate::model::configs::storage_config::*;
+
+use crate::repository::{
+    es_alarm_history_repository_impl::*, es_repository_impl::*, es_time_series_repository_impl::*,
+    local_alarm_history_repository_impl::*, local_time_series_repository_impl::*,
+};
+
+use crate::traits::repository_traits::{alarm_history_repository::*, time_series_repository::*};
+
+use crate::enums::storage_backend::*;
+
+/* 로컬 샘플 저장소 기본 경로 (storage.local_dir 미설정 시) */
+const DEFAULT_LOCAL_STORAGE_DIR: &str = "./storage";
+
+/* 로컬 저장소 내 알람 이력 파일 하위 디렉토리 */
+const ALARM_HISTORY_DIR: &str = "alarm";
+
+#[doc = "로컬 저장소 디렉토리 - storage.local_dir 미설정 시 기본 경로"]
+fn local_storage_dir(storage_config: &StorageConfig) -> PathBuf {
+    PathBuf::from(
+        storage_config
+            .local_dir()
+            .as_deref()
+            .unwrap_or(DEFAULT_LOCAL_STORAGE_DIR),
+    )
+}
+
+#[doc = "storage.backend 설정에 해당하는 시계열(모니터링 샘플/페어 샘플) 저장소 구현체를 반환"]
+pub fn build_time_series_repo(
+    storage_config: &StorageConfig,
+    mon_es_conn: Arc<EsRepositoryImpl>,
+) -> Arc<dyn TimeSeriesRepository> {
+    match storage_config.backend() {
+        StorageBackend::Elasticsearch => Arc::new(EsTimeSeriesRepositoryImpl::new(mon_es_conn)),
+        StorageBackend::Local => Arc::new(LocalTimeSeriesRepositoryImpl::new(local_storage_dir(
+            storage_config,
+        ))),
+    }
+}
+
+#[doc = "storage.backend 설정에 해당하는 알람 이력 저장소 구현체를 반환"]
+pub fn build_alarm_history_repo(
+    storage_config: &StorageConfig,
+    mon_es_conn: Arc<EsRepositoryImpl>,
+) -> Arc<dyn AlarmHistoryRepository> {
+    match storage_config.backend() {
+        StorageBackend::Elasticsearch => Arc::new(EsAlarmHistoryRepositoryImpl::new(mon_es_conn)),
+        StorageBackend::Local => Arc::new(LocalAlarmHistoryRepositoryImpl::new(
+            local_storage_dir(storage_config).join(ALARM_HISTORY_DIR),
+        )),
+    }
+}
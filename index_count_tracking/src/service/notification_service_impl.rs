@@ -2,7 +2,7 @@ use crate::common::*;
 
 use crate::model::{
     configs::{
-        elastic_server_config::*, receiver_email_config::*, smtp_config::*, system_config::*,
+        receiver_email_config::*, smtp_config::*, system_config::*, telegram_config::*,
         total_config::*,
     },
     index::alert_index::*,
@@ -13,11 +13,21 @@ use crate::traits::service_traits::notification_service::*;
 
 use crate::repository::{sqlserver_repository_impl::*, telegram_repository_impl::*};
 
-use crate::utils_modules::io_utils::*;
+use crate::utils_modules::{io_utils::*, stats_utils::*, time_utils::*};
 
 use crate::env_configuration::env_config::*;
 
-use crate::dto::{alarm::alarm_image_info::*, log_index_result::*};
+use crate::dto::{
+    alarm::alarm_image_info::*, breakdown_change::*, evaluation_failure::*, incident_recovery::*,
+    log_index_result::*,
+};
+
+use crate::enums::{alert_rule::*, alert_severity::*, alert_status::*};
+
+/* 복구 알림 이메일 템플릿 - 알람 템플릿(HTML_TEMPLATE_PATH)과 같은 디렉토리에 위치 */
+const RECOVERY_TEMPLATE_FILE_NAME: &str = "index_recovery_template.html";
+/* 모니터링 저하 알림 이메일 템플릿 - 알람 템플릿(HTML_TEMPLATE_PATH)과 같은 디렉토리에 위치 */
+const DEGRADED_TEMPLATE_FILE_NAME: &str = "monitoring_degraded_template.html";
 
 #[derive(Debug, Getters)]
 #[getset(get = "pub")]
@@ -30,6 +40,36 @@ impl NotificationServiceImpl {
     const TABLE_CELL_STYLE: &'static str = "border: 1px solid #ddd; padding: 12px; text-align: left; vertical-align: top; background-color: #fff;";
     const TABLE_CELL_STYLE_RED: &'static str = "border: 1px solid #ddd; padding: 12px; text-align: left; vertical-align: top; background-color: #fff; color: red;";
 
+    #[doc = "심각도에 따른 테이블 셀 스타일 - Critical 은 빨간색으로 강조"]
+    fn severity_cell_style(severity: AlertSeverity) -> &'static str {
+        match severity {
+            AlertSeverity::Critical => Self::TABLE_CELL_STYLE_RED,
+            AlertSeverity::Warning => Self::TABLE_CELL_STYLE,
+        }
+    }
+
+    #[doc = "알림 대상들의 클러스터명을 중복 없이 (등장 순서대로) ', ' 로 연결 - 이메일 제목 영역의 `{cluster_name}` 에 사용"]
+    fn join_cluster_names<'a>(cluster_names: impl Iterator<Item = &'a str>) -> String {
+        let mut unique_names: Vec<&str> = Vec::new();
+
+        for cluster_name in cluster_names {
+            if !unique_names.contains(&cluster_name) {
+                unique_names.push(cluster_name);
+            }
+        }
+
+        unique_names.join(", ")
+    }
+
+    #[doc = "변동 상위 버킷 컬럼 HTML - breakdown_field 가 없거나 변동 버킷이 없으면 '-'"]
+    fn breakdown_cell_html(breakdown_changes: &[BreakdownChange]) -> String {
+        if breakdown_changes.is_empty() {
+            return String::from("-");
+        }
+
+        join_breakdown_changes(breakdown_changes, "<br>")
+    }
+
     #[doc = r#"
         NotificationServiceImpl 구조체의 생성자 함수.
 
@@ -196,8 +236,9 @@ impl NotificationServiceImpl {
            - 청크 크기는 `system_config.message_chunk_size`로 결정
            - 텔레그램 메시지 길이 제한을 고려한 분할 처리
         3. 각 청크별로 알람 메시지 포맷을 구성:
+           - 텔레그램 수신 심각도(`telegram.min_severity`, 기본 Critical) 미만의 알람은 제외
            - 🚨 헤더로 시작하는 알람 메시지
-           - 📌 인덱스명과 📊💾🕐 아이콘으로 정보 표시
+           - 📌 인덱스명, 🔴🟡 심각도, 📉📈 변동 방향/변동률, 🔔 위반 규칙과 📊💾🕐 아이콘으로 정보 표시
            - ⚠️ 주의사항으로 마무리
         4. `tele_repo.bot_send`를 통해 각 청크별로 순차 발송
         5. 모든 청크 발송 완료 시 성공 반환
@@ -219,14 +260,133 @@ impl NotificationServiceImpl {
     ) -> anyhow::Result<()> {
         let tele_repo: Arc<TelebotRepositoryImpl> = get_telegram_repo();
         let system_config: &'static SystemConfig = get_system_config_info();
+        let telegram_config: &'static TelegramConfig = get_telegram_config_info();
         let msg_chunk_size: usize = *system_config.message_chunk_size();
 
+        /* 텔레그램 수신 심각도(기본: Critical) 이상의 알람만 발송 */
+        let log_index_results: Vec<&LogIndexResult> = log_index_results
+            .iter()
+            .filter(|log_result| telegram_config.accepts(*log_result.severity()))
+            .collect();
+
         /* LogIndexResult를 chunk 단위로 처리 */
         for chunk in log_index_results.chunks(msg_chunk_size) {
             let mut msg_format: String = String::from("🚨 [Index Count Alert] 🚨\n\n");
 
             for log_result in chunk {
-                msg_format.push_str(&format!("📌 {} 📌\n", log_result.index_name()));
+                msg_format.push_str(&format!(
+                    "📌 {} 📌\n🖥️ Cluster: {}\n{} Severity: {}\n",
+                    log_result.index_name(),
+                    log_result.cluster_name(),
+                    log_result.severity().emoji(),
+                    log_result.severity().as_str()
+                ));
+
+                /* 이미 알림이 발송된 장애는 샘플 목록 없이 요약만 발송 */
+                if *log_result.alert_status() == AlertStatus::StillFiring {
+                    msg_format.push_str(&format!(
+                        "🔁 {} since {} ({} alerts suppressed)\n🔔 Rule: {}\n💾 Count: {} ({}%)\n\n",
+                        log_result.alert_status().as_str(),
+                        log_result.incident_started_at().as_deref().unwrap_or("-"),
+                        log_result.suppressed_cnt(),
+                        join_alert_rules(log_result.alert_rules()),
+                        log_result.cur_cnt(),
+                        log_result.fluctuation_val()
+                    ));
+                    continue;
+                }
+
+                if let Some(stall_sec) = log_result.stall_sec() {
+                    msg_format.push_str(&format!(
+                        "⏸️ Stalled: no document count change for {} sec\n💾 Count: {}\n\n",
+                        stall_sec,
+                        log_result.cur_cnt()
+                    ));
+                    continue;
+                }
+
+                if let Some(pair_sample) = log_result.pair_sample() {
+                    msg_format.push_str(&format!(
+                        "🔀 Replica diverged: primary {} / replica {} (diff {}, {}%)\n🔔 Rule: {}\n\n",
+                        pair_sample.primary_cnt(),
+                        pair_sample.replica_cnt(),
+                        pair_sample.diff_cnt(),
+                        pair_sample.diff_percent(),
+                        join_alert_rules(log_result.alert_rules())
+                    ));
+                    continue;
+                }
+
+                if let Some(source_row_cnt) = log_result.source_row_cnt() {
+                    msg_format.push_str(&format!(
+                        "🗃️ Source drift: table {} rows / index {} docs (diff {}, {}%)\n🔔 Rule: {}\n\n",
+                        source_row_cnt,
+                        log_result.cur_cnt(),
+                        source_row_cnt.abs_diff(*log_result.cur_cnt()),
+                        log_result.fluctuation_val(),
+                        join_alert_rules(log_result.alert_rules())
+                    ));
+                    continue;
+                }
+
+                if let Some(index_stats) = log_result.index_stats() {
+                    msg_format.push_str(&format!(
+                        "🗄️ Stats: size {}, deleted docs {}, segments {}\n🔔 Rule: {}\n💾 Count: {}\n\n",
+                        format_bytes(*index_stats.store_size_bytes()),
+                        index_stats.docs_deleted(),
+                        index_stats.segment_cnt(),
+                        join_alert_rules(log_result.alert_rules()),
+                        log_result.cur_cnt()
+                    ));
+                    continue;
+                }
+
+                if let Some(ingest_cnt) = log_result.ingest_cnt() {
+                    msg_format.push_str(&format!(
+                        "📥 Ingest rate: {} new documents in the last interval\n🔔 Rule: {}\n💾 Count: {}\n\n",
+                        ingest_cnt,
+                        join_alert_rules(log_result.alert_rules()),
+                        log_result.cur_cnt()
+                    ));
+                    continue;
+                }
+
+                if let Some(freshness_lag_sec) = log_result.freshness_lag_sec() {
+                    msg_format.push_str(&format!(
+                        "🕰️ Stale data: newest document is {} old\n💾 Count: {}\n\n",
+                        format_duration_sec(*freshness_lag_sec),
+                        log_result.cur_cnt()
+                    ));
+                    continue;
+                }
+
+                msg_format.push_str(&format!(
+                    "{} Direction: {} ({}%)\n🔔 Rule: {}\n",
+                    log_result.direction().emoji(),
+                    log_result.direction().as_str(),
+                    log_result.fluctuation_val(),
+                    join_alert_rules(log_result.alert_rules())
+                ));
+
+                if let Some(anomaly_score) = log_result.anomaly_score() {
+                    msg_format.push_str(&format!("🧮 Anomaly score: {}\n", anomaly_score));
+                }
+
+                if let Some(expected_change) = log_result.expected_change() {
+                    msg_format.push_str(&format!(
+                        "📅 Seasonal expected change (1d/7d): {}\n",
+                        expected_change
+                    ));
+                }
+
+                if !log_result.breakdown_changes().is_empty() {
+                    msg_format.push_str(&format!(
+                        "🧩 Top buckets: {}\n",
+                        join_breakdown_changes(log_result.breakdown_changes(), ", ")
+                    ));
+                }
+
+                msg_format.push('\n');
 
                 if let Some(alert_formats) = log_result.alert_index_format() {
                     for alert_format in alert_formats {
@@ -249,10 +409,267 @@ impl NotificationServiceImpl {
         Ok(())
     }
 
+    #[doc = r#"
+        정상 범위로 복귀한 인덱스의 복구 알림을 텔레그램 메시지로 발송하는 함수.
+
+        1. 텔레그램 수신 심각도 미만의 장애는 제외하고 `message_chunk_size` 단위로 분할
+        2. 각 인덱스별로 장애 지속 시간, 최대 변동률, 장애 시작/종료 시점의 문서 수를 표시
+        3. `tele_repo.bot_send`를 통해 각 청크별로 순차 발송
+
+        # Arguments
+        * `recoveries` - 장애가 해소된 인덱스 정보 배열
+
+        # Returns
+        * `Ok(())` - 모든 메시지 발송 성공
+        * `Err(anyhow::Error)` - 텔레그램 봇 발송 실패 시
+    "#]
+    async fn send_telegram_recovery_alert(
+        &self,
+        recoveries: &[IncidentRecovery],
+    ) -> anyhow::Result<()> {
+        let tele_repo: Arc<TelebotRepositoryImpl> = get_telegram_repo();
+        let telegram_config: &'static TelegramConfig = get_telegram_config_info();
+        let msg_chunk_size: usize = *get_system_config_info().message_chunk_size();
+
+        /* 장애 알람과 동일하게 텔레그램 수신 심각도 이상인 장애만 발송 */
+        let recoveries: Vec<&IncidentRecovery> = recoveries
+            .iter()
+            .filter(|recovery| telegram_config.accepts(*recovery.severity()))
+            .collect();
+
+        for chunk in recoveries.chunks(msg_chunk_size) {
+            let mut msg_format: String = String::from("✅ [Index Count Resolved] ✅\n\n");
+
+            for recovery in chunk {
+                msg_format.push_str(&format!(
+                    "📌 {} 📌\n🖥️ Cluster: {}\n⏱️ Duration: {} ({} ~ {})\n📈 Peak fluctuation: {}%\n💾 Count: {} → {}\n\n",
+                    recovery.index_name(),
+                    recovery.cluster_name(),
+                    format_duration_sec(*recovery.duration_sec()),
+                    recovery.incident_started_at(),
+                    recovery.resolved_at(),
+                    recovery.peak_fluctuation(),
+                    recovery.start_cnt(),
+                    recovery.end_cnt()
+                ));
+            }
+
+            msg_format.push_str("👍 The index document count is back within its thresholds.");
+
+            tele_repo.bot_send(&msg_format).await?;
+        }
+
+        Ok(())
+    }
+
+    #[doc = r#"
+        정상 범위로 복귀한 인덱스의 복구 알림을 이메일로 발송하는 함수.
+
+        1. 알람 템플릿(`HTML_TEMPLATE_PATH`)과 같은 디렉토리의 `index_recovery_template.html`을 읽어온다
+        2. 수신자별 최소 심각도(`min_severity`)에 따라 수신자 그룹과 복구 대상을 나눈다
+        3. 인덱스별 복구 정보를 테이블 행으로 변환하여 `{recovery_rows}` 플레이스홀더에 삽입
+        4. `send_alert_infos_to_receivers`를 통해 각 수신자 그룹에 발송
+
+        # Arguments
+        * `recoveries` - 장애가 해소된 인덱스 정보 배열
+
+        # Returns
+        * `anyhow::Result<()>` - 발송 프로세스 완료 여부
+    "#]
+    async fn send_email_recovery_alert(
+        &self,
+        recoveries: &[IncidentRecovery],
+    ) -> anyhow::Result<()> {
+        let template_path: PathBuf =
+            Path::new(&*HTML_TEMPLATE_PATH).with_file_name(RECOVERY_TEMPLATE_FILE_NAME);
+        let template_content: String = fs::read_to_string(&template_path)?;
+
+        for min_severity in [AlertSeverity::Warning, AlertSeverity::Critical] {
+            let receivers: Vec<&ReceiverEmail> = self
+                .receiver_email_list()
+                .emails
+                .iter()
+                .filter(|receiver| receiver.resolve_min_severity() == min_severity)
+                .collect();
+
+            let target_recoveries: Vec<&IncidentRecovery> = recoveries
+                .iter()
+                .filter(|recovery| *recovery.severity() >= min_severity)
+                .collect();
+
+            let mut recovery_rows: String = String::new();
+
+            for recovery in &target_recoveries {
+                recovery_rows.push_str(&format!(
+                    r#"<tr>
+                        <td style="{}">{}</td>
+                        <td style="{}">{}</td>
+                        <td style="{}">{}</td>
+                        <td style="{}">{}</td>
+                        <td style="{}">{}</td>
+                        <td style="{}">{}%</td>
+                        <td style="{}">{} → {}</td>
+                    </tr>"#,
+                    Self::TABLE_CELL_STYLE,
+                    recovery.index_name(),
+                    Self::TABLE_CELL_STYLE,
+                    recovery.cluster_name(),
+                    Self::TABLE_CELL_STYLE,
+                    recovery.incident_started_at(),
+                    Self::TABLE_CELL_STYLE,
+                    recovery.resolved_at(),
+                    Self::TABLE_CELL_STYLE,
+                    format_duration_sec(*recovery.duration_sec()),
+                    Self::TABLE_CELL_STYLE,
+                    recovery.peak_fluctuation(),
+                    Self::TABLE_CELL_STYLE,
+                    recovery.start_cnt(),
+                    recovery.end_cnt()
+                ));
+            }
+
+            if receivers.is_empty() || recovery_rows.is_empty() {
+                continue;
+            }
+
+            let cluster_names: String = Self::join_cluster_names(
+                target_recoveries
+                    .iter()
+                    .map(|recovery| recovery.cluster_name().as_str()),
+            );
+
+            let html_content: String = template_content
+                .replace("{cluster_name}", &cluster_names)
+                .replace(
+                    "{alert_time}",
+                    &chrono::Utc::now()
+                        .format("%Y-%m-%d %H:%M:%S UTC")
+                        .to_string(),
+                )
+                .replace("{recovery_rows}", &recovery_rows);
+
+            self.send_alert_infos_to_receivers(
+                "[Elasticsearch] Index Document Count Recovered",
+                &html_content,
+                &receivers,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[doc = r#"
+        평가에 실패한 인덱스 목록을 텔레그램으로 발송하는 함수.
+
+        모니터링 자체가 저하된 상태이므로 심각도 필터 없이 발송하며,
+        `message_chunk_size` 단위로 메시지를 나누어 보낸다.
+
+        # Arguments
+        * `failures` - 평가에 실패한 인덱스 정보 배열
+        * `total_cnt` - 이번 주기에 평가 대상이었던 전체 인덱스 수
+
+        # Returns
+        * `anyhow::Result<()>` - 발송 성공 여부
+    "#]
+    async fn send_telegram_degraded_alert(
+        &self,
+        failures: &[EvaluationFailure],
+        total_cnt: usize,
+    ) -> anyhow::Result<()> {
+        let tele_repo: Arc<TelebotRepositoryImpl> = get_telegram_repo();
+        let msg_chunk_size: usize = *get_system_config_info().message_chunk_size();
+
+        for chunk in failures.chunks(msg_chunk_size) {
+            let mut msg_format: String = format!(
+                "⚠️ [Index Monitoring Degraded] ⚠️\n\n🔎 Failed: {} / {} indices\n\n",
+                failures.len(),
+                total_cnt
+            );
+
+            for failure in chunk {
+                msg_format.push_str(&format!(
+                    "📌 {} 📌\n❌ {}\n\n",
+                    failure.index_name(),
+                    failure.error()
+                ));
+            }
+
+            msg_format.push_str(
+                "🛠️ Alerts for these indices are not evaluated until the errors are resolved.",
+            );
+
+            tele_repo.bot_send(&msg_format).await?;
+        }
+
+        Ok(())
+    }
+
+    #[doc = r#"
+        평가에 실패한 인덱스 목록을 이메일로 발송하는 함수.
+
+        1. 알람 템플릿(`HTML_TEMPLATE_PATH`)과 같은 디렉토리의 `monitoring_degraded_template.html`을 읽어온다
+        2. 인덱스별 실패 정보를 테이블 행으로 변환하여 `{failure_rows}` 플레이스홀더에 삽입
+        3. 심각도와 무관하게 `send_alert_infos_to_admin`을 통해 모든 수신자에게 발송
+
+        # Arguments
+        * `failures` - 평가에 실패한 인덱스 정보 배열
+        * `total_cnt` - 이번 주기에 평가 대상이었던 전체 인덱스 수
+
+        # Returns
+        * `anyhow::Result<()>` - 발송 성공 여부
+    "#]
+    async fn send_email_degraded_alert(
+        &self,
+        failures: &[EvaluationFailure],
+        total_cnt: usize,
+    ) -> anyhow::Result<()> {
+        let template_path: PathBuf =
+            Path::new(&*HTML_TEMPLATE_PATH).with_file_name(DEGRADED_TEMPLATE_FILE_NAME);
+        let template_content: String = fs::read_to_string(&template_path)?;
+
+        let failure_rows: String = failures
+            .iter()
+            .map(|failure| {
+                format!(
+                    r#"<tr>
+                        <td style="{}">{}</td>
+                        <td style="{}">{}</td>
+                    </tr>"#,
+                    Self::TABLE_CELL_STYLE,
+                    failure.index_name(),
+                    Self::TABLE_CELL_STYLE_RED,
+                    failure.error()
+                )
+            })
+            .collect();
+
+        let cluster_names: String = Self::join_cluster_names(
+            failures
+                .iter()
+                .map(|failure| failure.cluster_name().as_str()),
+        );
+
+        let html_content: String = template_content
+            .replace("{cluster_name}", &cluster_names)
+            .replace(
+                "{alert_time}",
+                &chrono::Utc::now()
+                    .format("%Y-%m-%d %H:%M:%S UTC")
+                    .to_string(),
+            )
+            .replace("{failed_cnt}", &failures.len().to_string())
+            .replace("{total_cnt}", &total_cnt.to_string())
+            .replace("{failure_rows}", &failure_rows);
+
+        self.send_alert_infos_to_admin("[Elasticsearch] Index Monitoring Degraded", &html_content)
+            .await
+    }
+
     #[doc = r#"
         인덱스 문서 개수 알람을 이메일로 발송하는 함수.
 
-        1. 이메일 제목과 HTML 템플릿을 생성하여 알람 내용을 구성
+        1. 수신자별 최소 심각도(`min_severity`, 기본 Warning)에 따라 수신자 그룹과 알람 대상을 나눈다
         2. `generate_index_alert_html`을 통해 인덱스 알람 정보를 HTML 형식으로 변환
         3. SQL Server의 stored procedure를 통해 내부 이메일 시스템(imailer)으로 발송
         4. 각 수신자별로 개별적으로 이메일 발송하며, 실패 시에도 다른 수신자에게는 계속 발송
@@ -268,16 +685,33 @@ impl NotificationServiceImpl {
         &self,
         log_index_results: &[LogIndexResult],
     ) -> anyhow::Result<()> {
-        let elastic_config: &'static ElasticServerConfig = get_mon_elastic_config_info();
-
         let email_subject: String =
             String::from("[Elasticsearch] Index Document Count Change Detected");
 
-        let html_content: String =
-            self.generate_index_alert_html(log_index_results, elastic_config)?;
+        /* 수신자별 최소 심각도에 따라 수신자 그룹을 나누어 발송 */
+        for min_severity in [AlertSeverity::Warning, AlertSeverity::Critical] {
+            let receivers: Vec<&ReceiverEmail> = self
+                .receiver_email_list()
+                .emails
+                .iter()
+                .filter(|receiver| receiver.resolve_min_severity() == min_severity)
+                .collect();
+
+            let target_results: Vec<LogIndexResult> = log_index_results
+                .iter()
+                .filter(|log_result| *log_result.severity() >= min_severity)
+                .cloned()
+                .collect();
+
+            if receivers.is_empty() || target_results.is_empty() {
+                continue;
+            }
 
-        self.send_alert_infos_to_admin(&email_subject, &html_content)
-            .await?;
+            let html_content: String = self.generate_index_alert_html(&target_results)?;
+
+            self.send_alert_infos_to_receivers(&email_subject, &html_content, &receivers)
+                .await?;
+        }
 
         Ok(())
     }
@@ -300,8 +734,33 @@ impl NotificationServiceImpl {
         email_subject: &str,
         html_content: &str,
     ) -> anyhow::Result<()> {
-        let receiver_email_list: &Vec<ReceiverEmail> = &self.receiver_email_list().emails;
+        let receiver_email_list: Vec<&ReceiverEmail> =
+            self.receiver_email_list().emails.iter().collect();
+
+        self.send_alert_infos_to_receivers(email_subject, html_content, &receiver_email_list)
+            .await
+    }
+
+    #[doc = r#"
+        지정한 수신자들에게 알람 정보를 이메일로 발송하는 함수.
+
+        SQL Server stored procedure를 통한 imailer 방식으로 발송하며,
+        특정 수신자에 대한 발송 실패는 로깅만 하고 다른 수신자에게는 계속 발송한다.
+
+        # Arguments
+        * `email_subject` - 이메일 제목
+        * `html_content` - HTML 형식의 이메일 본문
+        * `receiver_email_list` - 발송 대상 수신자 목록
 
+        # Returns
+        * `anyhow::Result<()>` - 발송 성공 여부
+    "#]
+    async fn send_alert_infos_to_receivers(
+        &self,
+        email_subject: &str,
+        html_content: &str,
+        receiver_email_list: &[&ReceiverEmail],
+    ) -> anyhow::Result<()> {
         /* SMTP 버전 -> 온라인망 사용용*/
         // self.send_message_to_receivers_smtp(email_subject, html_content, receiver_email_list)
         //     .await?;
@@ -339,14 +798,13 @@ impl NotificationServiceImpl {
         1. HTML 템플릿 파일(`HTML_TEMPLATE_PATH`)을 읽어온다
         2. `generate_alert_rows`를 통해 인덱스별 알람 데이터를 테이블 행으로 변환
         3. 템플릿 내의 플레이스홀더를 실제 데이터로 교체:
-           - `{cluster_name}`: Elasticsearch 클러스터명
+           - `{cluster_name}`: 알람 대상 인덱스들의 클러스터명
            - `{alert_time}`: 현재 시각 (UTC)
            - `{alert_rows}`: 알람 데이터 테이블 행들
         4. 완성된 HTML 문자열을 반환
 
         # Arguments
         * `log_index_results` - 알람 대상 인덱스 정보 배열
-        * `elastic_config` - Elasticsearch 설정 정보
 
         # Returns
         * `String` - 완성된 HTML 이메일 템플릿
@@ -355,7 +813,6 @@ impl NotificationServiceImpl {
     fn generate_index_alert_html(
         &self,
         log_index_results: &[LogIndexResult],
-        elastic_config: &ElasticServerConfig,
     ) -> anyhow::Result<String> {
         /* HTML 템플릿 파일 읽기 */
         let template_content: String = fs::read_to_string(&*HTML_TEMPLATE_PATH)?;
@@ -363,9 +820,15 @@ impl NotificationServiceImpl {
         /* 알람 행들 생성 */
         let alert_rows: String = self.generate_alert_rows(log_index_results)?;
 
+        let cluster_names: String = Self::join_cluster_names(
+            log_index_results
+                .iter()
+                .map(|log_result| log_result.cluster_name().as_str()),
+        );
+
         /* 템플릿의 플레이스홀더 교체 */
         let html_content: String = template_content
-            .replace("{cluster_name}", elastic_config.elastic_cluster_name())
+            .replace("{cluster_name}", &cluster_names)
             .replace(
                 "{alert_time}",
                 &chrono::Utc::now()
@@ -383,8 +846,11 @@ impl NotificationServiceImpl {
         1. 각 `LogIndexResult`를 순회하면서 HTML 테이블 행 생성
         2. 각 행에는 다음 정보가 포함됨:
            - 인덱스명: `log_result.index_name()`
+           - 심각도: `log_result.severity()` (Critical 은 빨간색으로 표시)
            - 현재 문서 개수: `log_result.cur_cnt()`
            - 변동률: `log_result.fluctuation_val()`
+           - 변동 방향: `log_result.direction()` (Drop/Growth)
+           - 위반 규칙: `log_result.alert_rules()`
            - 히스토리 정보: `generate_history_table_html`을 통해 생성된 상세 히스토리
         3. 각 행은 CSS 스타일이 인라인으로 적용된 `<tr>` 태그로 구성
         4. 모든 행을 연결하여 하나의 문자열로 반환
@@ -405,18 +871,35 @@ impl NotificationServiceImpl {
                     r#"<tr>
                         <td style="{}">{}</td>
                         <td style="{}">{}</td>
+                        <td style="{}">{} {}</td>
+                        <td style="{}">{}</td>
                         <td style="{}">{}%</td>
+                        <td style="{}">{} {}</td>
+                        <td style="{}">{}</td>
+                        <td style="{}">{}</td>
                         <td style="{}">{}</td>
 
                     </tr>"#,
                     Self::TABLE_CELL_STYLE,
                     log_result.index_name(),
                     Self::TABLE_CELL_STYLE,
+                    log_result.cluster_name(),
+                    Self::severity_cell_style(*log_result.severity()),
+                    log_result.severity().emoji(),
+                    log_result.severity().as_str(),
+                    Self::TABLE_CELL_STYLE,
                     log_result.cur_cnt(),
                     Self::TABLE_CELL_STYLE_RED,
                     log_result.fluctuation_val(),
+                    Self::TABLE_CELL_STYLE_RED,
+                    log_result.direction().emoji(),
+                    log_result.direction().as_str(),
+                    Self::TABLE_CELL_STYLE_RED,
+                    join_alert_rules(log_result.alert_rules()),
+                    Self::TABLE_CELL_STYLE,
+                    Self::breakdown_cell_html(log_result.breakdown_changes()),
                     Self::TABLE_CELL_STYLE,
-                    self.generate_history_table_html(alert_formats)
+                    self.generate_history_cell_html(log_result, alert_formats)
                 ));
             }
         }
@@ -424,6 +907,48 @@ impl NotificationServiceImpl {
         Ok(rows)
     }
 
+    #[doc = r#"
+        알람 상태에 따라 히스토리 컬럼에 들어갈 HTML을 생성하는 함수.
+
+        1. 새로 발생한 알람(`Firing`)은 `generate_history_table_html`로 전체 샘플 이력을 표시
+        2. 이미 알림이 발송된 장애(`StillFiring`)는 장애 시작 시각과 억제된 알람 수만 요약하여 표시
+
+        # Arguments
+        * `log_result` - 알람 대상 인덱스 정보
+        * `alert_indexes` - 알람 인덱스 정보 배열
+
+        # Returns
+        * `String` - 히스토리 컬럼 HTML
+    "#]
+    fn generate_history_cell_html(
+        &self,
+        log_result: &LogIndexResult,
+        alert_indexes: &[AlertIndex],
+    ) -> String {
+        match log_result.alert_status() {
+            AlertStatus::StillFiring => format!(
+                r#"<div style="color: #555; font-size: 14px; line-height: 1.5;">{} since {} ({} alerts suppressed)</div>"#,
+                log_result.alert_status().as_str(),
+                log_result.incident_started_at().as_deref().unwrap_or("-"),
+                log_result.suppressed_cnt()
+            ),
+            AlertStatus::Firing | AlertStatus::Resolved => match log_result.pair_sample() {
+                Some(pair_sample) => format!(
+                    r#"<div style="color: #555; font-size: 14px; line-height: 1.5;"><div>{}:{} -> {}</div><div>{}:{} -> {}</div><div>diff {} ({}%)</div></div>"#,
+                    pair_sample.primary_cluster(),
+                    pair_sample.primary_index(),
+                    pair_sample.primary_cnt(),
+                    pair_sample.replica_cluster(),
+                    pair_sample.replica_index(),
+                    pair_sample.replica_cnt(),
+                    pair_sample.diff_cnt(),
+                    pair_sample.diff_percent()
+                ),
+                None => self.generate_history_table_html(alert_indexes),
+            },
+        }
+    }
+
     #[doc = r#"
         알람 인덱스 히스토리 정보를 HTML div 요소들로 변환하는 함수.
 
@@ -558,6 +1083,86 @@ impl NotificationService for NotificationServiceImpl {
         Ok(())
     }
 
+    #[doc = r#"
+        장애가 해소된 인덱스의 복구 알림을 텔레그램과 이메일로 동시에 발송하는 함수.
+
+        `send_index_alert_message`와 동일하게 각 채널의 실패는 로깅만 하고
+        다른 채널의 발송에는 영향을 주지 않는다.
+
+        # Arguments
+        * `recoveries` - 장애가 해소된 인덱스 정보 배열
+
+        # Returns
+        * `Result<(), anyhow::Error>` - 항상 성공을 반환 (개별 실패는 로깅만)
+    "#]
+    async fn send_recovery_message(
+        &self,
+        recoveries: &[IncidentRecovery],
+    ) -> Result<(), anyhow::Error> {
+        let telegram = async {
+            if let Err(e) = self.send_telegram_recovery_alert(recoveries).await {
+                error!(
+                    "[NotificationServiceImpl->send_recovery_message][telegram] {:?}",
+                    e
+                );
+            }
+        };
+
+        let mail = async {
+            if let Err(e) = self.send_email_recovery_alert(recoveries).await {
+                error!(
+                    "[NotificationServiceImpl->send_recovery_message][imailer] {:?}",
+                    e
+                );
+            }
+        };
+
+        tokio::join!(telegram, mail);
+
+        Ok(())
+    }
+
+    #[doc = r#"
+        평가에 실패한 인덱스가 있을 때 모니터링 저하 알림을 텔레그램과 이메일로 동시에 발송하는 함수.
+
+        인덱스 알람과 별개의 알림으로, 조회 오류 등으로 이상 탐지를 수행하지 못한
+        인덱스가 있음을 알린다. 각 채널의 실패는 로깅만 한다.
+
+        # Arguments
+        * `failures` - 평가에 실패한 인덱스 정보 배열
+        * `total_cnt` - 이번 주기에 평가 대상이었던 전체 인덱스 수
+
+        # Returns
+        * `Result<(), anyhow::Error>` - 항상 성공을 반환 (개별 실패는 로깅만)
+    "#]
+    async fn send_monitoring_degraded_message(
+        &self,
+        failures: &[EvaluationFailure],
+        total_cnt: usize,
+    ) -> Result<(), anyhow::Error> {
+        let telegram = async {
+            if let Err(e) = self.send_telegram_degraded_alert(failures, total_cnt).await {
+                error!(
+                    "[NotificationServiceImpl->send_monitoring_degraded_message][telegram] {:?}",
+                    e
+                );
+            }
+        };
+
+        let mail = async {
+            if let Err(e) = self.send_email_degraded_alert(failures, total_cnt).await {
+                error!(
+                    "[NotificationServiceImpl->send_monitoring_degraded_message][imailer] {:?}",
+                    e
+                );
+            }
+        };
+
+        tokio::join!(telegram, mail);
+
+        Ok(())
+    }
+
     #[doc = r#"
         일일 리포트 정보를 이메일로 발송하는 함수.
 
@@ -1,25 +1,469 @@
 use crate::common::*;
 
 use crate::dto::alarm::alarm_report_infos::AlarmReportInfos;
-use crate::traits::{repository_traits::es_repository::*, service_traits::query_service::*};
+use crate::traits::{
+    repository_traits::{
+        alarm_history_repository::*, es_repository::*, spool_repository::*,
+        time_series_repository::*,
+    },
+    service_traits::query_service::*,
+};
 
-use crate::repository::es_repository_impl::*;
+use crate::repository::{es_repository_impl::*, spool_repository_impl::*};
 
-use crate::utils_modules::{time_utils::*, traits::*};
+use crate::utils_modules::{io_utils::*, time_utils::*, traits::*};
 
 use crate::dto::alarm::alarm_log_history_index::*;
-use crate::model::index::{alert_index::*, alert_index_format::*, index_config::*};
-
-use crate::dto::{index_count_agg_result::*, index_name_count::*, log_index_result::*};
-
-use crate::enums::sort_order::*;
+use crate::model::configs::{compaction_config::*, index_bootstrap_config::*, total_config::*};
+use crate::model::index::{
+    alert_index::*, breakdown_bucket::*, index_config::*, monitor_rollup::*,
+};
+
+use crate::dto::{
+    index_count_agg_result::*, index_pair_sample::*, index_stats::*, ingest_rate_bucket::*,
+    log_index_result::*, pair_consistency_info::*, spool_entry::*,
+};
+
+use crate::enums::{alert_rule::*, fluctuation_direction::*, sort_order::*};
+
+/* 한 번에 조회할 수 있는 최대 샘플 수 (ES max_result_window) */
+const MAX_SAMPLE_SIZE: usize = 10000;
+/* 색인 정체 기간 계산 시 문서 수가 마지막으로 바뀐 샘플을 찾는 최대 과거 범위(초) */
+const STALL_LOOKBACK_SEC: i64 = 7 * 86400;
+/* 스풀 재전송 시 한 번의 `_bulk` 요청에 담는 최대 문서 수 */
+const SPOOL_REPLAY_BULK_SIZE: usize = 500;
+/* 모니터링(문서 수 샘플) 인덱스 기본 보관 기간(일) */
+const DEFAULT_MONITOR_RETENTION_DAYS: u32 = 90;
+/* 알람 이력 인덱스 기본 보관 기간(일) */
+const DEFAULT_ALARM_RETENTION_DAYS: u32 = 365;
+/* 롤업 문서 생성 시 composite 집계 한 페이지의 버킷 수 / 한 번의 `_bulk` 요청에 담는 문서 수 */
+const ROLLUP_PAGE_SIZE: usize = 1000;
+/* 인덱스 템플릿 기본 우선순위 - 인덱스명 접두어가 긴(구체적인) 템플릿이 우선하도록 접두어 길이를 더함 */
+const BASE_TEMPLATE_PRIORITY: usize = 200;
 
 #[derive(Debug, new)]
 pub struct QueryServiceImpl {
     es_conn: Arc<EsRepositoryImpl>,
+    /* 모니터링 샘플/페어 샘플 저장/조회 - storage.backend 설정에 따라 모니터링 클러스터 또는 로컬 파일 */
+    ts_repo: Arc<dyn TimeSeriesRepository>,
+    /* 알람 이력 저장/조회 - storage.backend 설정에 따라 모니터링 클러스터 또는 로컬 파일 */
+    alarm_repo: Arc<dyn AlarmHistoryRepository>,
 }
 
 impl QueryServiceImpl {
+    #[doc = r#"
+        문자열 필드 매핑 - `keyword` 로 색인하되 `.keyword` 하위 필드를 함께 둔다.
+
+        템플릿 적용 이전(dynamic mapping)에 생성된 일자별 인덱스는 `text` + `.keyword` 로 매핑되어 있으므로,
+        조회 쿼리는 신규/기존 인덱스 모두에서 동작하는 `{field}.keyword` 를 그대로 사용한다.
+    "#]
+    fn keyword_mapping() -> Value {
+        json!({
+            "type": "keyword",
+            "fields": { "keyword": { "type": "keyword" } }
+        })
+    }
+
+    #[doc = "모니터링 인덱스(AlertIndex / IndexPairSample 문서) 매핑"]
+    fn monitor_index_mappings() -> Value {
+        json!({
+            "dynamic_templates": [
+                { "strings_as_keyword": { "match_mapping_type": "string", "mapping": Self::keyword_mapping() } }
+            ],
+            "properties": {
+                "index_name": Self::keyword_mapping(),
+                "target_name": Self::keyword_mapping(),
+                "cluster_name": Self::keyword_mapping(),
+                "cnt": { "type": "long" },
+                "timestamp": { "type": "date", "format": "strict_date_time_no_millis||strict_date_optional_time" },
+                "breakdown": {
+                    "properties": {
+                        "key": Self::keyword_mapping(),
+                        "cnt": { "type": "long" }
+                    }
+                },
+                "freshness_lag_sec": { "type": "long" },
+                "ingest_cnt": { "type": "long" },
+                "index_stats": {
+                    "properties": {
+                        "store_size_bytes": { "type": "long" },
+                        "docs_deleted": { "type": "long" },
+                        "indexing_total": { "type": "long" },
+                        "delete_total": { "type": "long" },
+                        "segment_cnt": { "type": "long" }
+                    }
+                },
+                "source_row_cnt": { "type": "long" },
+                "suppressed": { "type": "boolean" },
+                "pair_name": Self::keyword_mapping(),
+                "primary_cluster": Self::keyword_mapping(),
+                "primary_index": Self::keyword_mapping(),
+                "replica_cluster": Self::keyword_mapping(),
+                "replica_index": Self::keyword_mapping(),
+                "primary_cnt": { "type": "long" },
+                "replica_cnt": { "type": "long" },
+                "diff_cnt": { "type": "long" },
+                "diff_percent": { "type": "double" },
+                "diverged": { "type": "boolean" }
+            }
+        })
+    }
+
+    #[doc = "롤업 인덱스(MonitorRollup / 옮겨 온 IndexPairSample 문서) 매핑"]
+    fn rollup_index_mappings() -> Value {
+        json!({
+            "properties": {
+                "index_name": Self::keyword_mapping(),
+                "target_name": Self::keyword_mapping(),
+                "cluster_name": Self::keyword_mapping(),
+                "timestamp": { "type": "date", "format": "strict_date_time_no_millis||strict_date_optional_time" },
+                "cnt": { "type": "long" },
+                "first_cnt": { "type": "long" },
+                "last_cnt": { "type": "long" },
+                "min_cnt": { "type": "long" },
+                "max_cnt": { "type": "long" },
+                "sample_cnt": { "type": "long" },
+                "index_stats": {
+                    "properties": {
+                        "store_size_bytes": { "type": "long" },
+                        "docs_deleted": { "type": "long" },
+                        "indexing_total": { "type": "long" },
+                        "delete_total": { "type": "long" },
+                        "segment_cnt": { "type": "long" }
+                    }
+                },
+                "pair_name": Self::keyword_mapping(),
+                "primary_cluster": Self::keyword_mapping(),
+                "primary_index": Self::keyword_mapping(),
+                "replica_cluster": Self::keyword_mapping(),
+                "replica_index": Self::keyword_mapping(),
+                "primary_cnt": { "type": "long" },
+                "replica_cnt": { "type": "long" },
+                "diff_cnt": { "type": "long" },
+                "diff_percent": { "type": "double" },
+                "diverged": { "type": "boolean" }
+            }
+        })
+    }
+
+    #[doc = "알람 이력 인덱스(AlarmLogHistoryIndex 문서) 매핑"]
+    fn alarm_index_mappings() -> Value {
+        json!({
+            "dynamic_templates": [
+                { "strings_as_keyword": { "match_mapping_type": "string", "mapping": Self::keyword_mapping() } }
+            ],
+            "properties": {
+                "index_name": Self::keyword_mapping(),
+                "cluster_name": Self::keyword_mapping(),
+                "index_cnt": { "type": "long" },
+                "fluctuation_val": { "type": "double" },
+                "timestamp": { "type": "date", "format": "strict_date_time_no_millis||strict_date_optional_time" },
+                "alert_rules": Self::keyword_mapping(),
+                "severity": Self::keyword_mapping(),
+                "alert_status": Self::keyword_mapping(),
+                "incident_started_at": { "type": "date", "format": "strict_date_time_no_millis||strict_date_optional_time" },
+                "last_notified_at": { "type": "date", "format": "strict_date_time_no_millis||strict_date_optional_time" },
+                "notified": { "type": "boolean" },
+                "suppressed_cnt": { "type": "long" },
+                "notified_rules": Self::keyword_mapping(),
+                "start_cnt": { "type": "long" },
+                "peak_fluctuation_val": { "type": "double" },
+                "resolved_at": { "type": "date", "format": "strict_date_time_no_millis||strict_date_optional_time" },
+                "incident_duration_sec": { "type": "long" },
+                "suppressed": { "type": "boolean" }
+            }
+        })
+    }
+
+    #[doc = r#"
+        인덱스 계열(접두어) 하나에 대한 ILM 정책과 composable 인덱스 템플릿을 생성(또는 갱신)하는 함수.
+
+        1. `{접두어}-policy` ILM 정책 - 일자별 인덱스이므로 rollover 없이 생성 후 `retention_days` 가 지나면 삭제
+        2. `{접두어}-template` 인덱스 템플릿 - `{index_prefix}*` 패턴에 명시적 매핑과 ILM 정책을 적용
+           - 모니터링/알람 인덱스 접두어가 서로 겹쳐도 더 구체적인(긴) 접두어의 템플릿이 우선 적용됨
+
+        기존 인덱스에는 영향이 없고, 이후 새로 생성되는 일자별 인덱스부터 적용된다.
+
+        # Arguments
+        * `index_prefix` - 인덱스명 접두어 (예: monitor_index_name)
+        * `mappings` - 인덱스 매핑
+        * `retention_days` - 보관 기간(일)
+    "#]
+    async fn put_index_family_template(
+        &self,
+        index_prefix: &str,
+        mappings: Value,
+        retention_days: u32,
+    ) -> anyhow::Result<()> {
+        let family_name: &str = index_prefix.trim_end_matches(['_', '-', '.']);
+        let policy_name: String = format!("{}-policy", family_name);
+        let template_name: String = format!("{}-template", family_name);
+
+        let policy_body: Value = json!({
+            "policy": {
+                "phases": {
+                    "hot": { "min_age": "0ms", "actions": {} },
+                    "delete": {
+                        "min_age": format!("{}d", retention_days),
+                        "actions": { "delete": {} }
+                    }
+                }
+            }
+        });
+
+        self.es_conn
+            .put_ilm_policy(&policy_name, &policy_body)
+            .await?;
+
+        let template_body: Value = json!({
+            "index_patterns": [format!("{}*", index_prefix)],
+            "priority": BASE_TEMPLATE_PRIORITY + index_prefix.len(),
+            "template": {
+                "settings": {
+                    "index.lifecycle.name": policy_name
+                },
+                "mappings": mappings
+            },
+            "_meta": {
+                "description": "index_count_tracking managed template"
+            }
+        });
+
+        self.es_conn
+            .put_index_template(&template_name, &template_body)
+            .await?;
+
+        info!(
+            "[QueryServiceImpl->put_index_family_template] {} / {} applied to '{}*' (retention {}d)",
+            template_name, policy_name, index_prefix, retention_days
+        );
+
+        Ok(())
+    }
+
+    #[doc = r#"
+        문서 수가 실제로 변하지 않은 기간(초)을 계산하는 함수.
+
+        1. 기준시각 이전 `STALL_LOOKBACK_SEC` 범위에서 문서 수가 현재 값과 다른 가장 최근 샘플을 조회
+        2. 그 이후 현재 값이 처음 관측된 샘플 시각부터 기준시각까지를 정체 기간으로 계산
+        3. 조회 범위 안에서 값이 한 번도 바뀌지 않았다면 범위 내 최초 샘플 시각을 사용 (최소 기간)
+
+        # Arguments
+        * `mon_index_name` - 모니터링 인덱스명(패턴)
+        * `index_name` - 대상 인덱스명
+        * `cur_cnt` - 현재 문서 수
+        * `cur_timestamp_utc` - 기준 시각 (UTC)
+
+        # Returns
+        * `i64` - 정체 기간(초), 샘플이 없으면 0
+    "#]
+    async fn calc_stall_sec(
+        &self,
+        mon_index_name: &str,
+        index_name: &str,
+        cur_cnt: usize,
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> anyhow::Result<i64> {
+        let lookback_start_utc: DateTime<Utc> =
+            calc_time_window(cur_timestamp_utc, STALL_LOOKBACK_SEC);
+
+        let flat_since: Option<AlertIndex> = match self
+            .ts_repo
+            .read_last_changed_sample(
+                mon_index_name,
+                index_name,
+                cur_cnt,
+                lookback_start_utc,
+                cur_timestamp_utc,
+            )
+            .await?
+        {
+            Some(changed_sample) => self
+                .ts_repo
+                .read_samples(
+                    mon_index_name,
+                    index_name,
+                    convert_utc_from_str(changed_sample.timestamp())?,
+                    cur_timestamp_utc,
+                    SortOrder::Asc,
+                    2,
+                )
+                .await?
+                .into_iter()
+                .find(|sample| *sample.cnt() == cur_cnt),
+            None => self
+                .ts_repo
+                .read_first_last_samples(
+                    mon_index_name,
+                    index_name,
+                    lookback_start_utc,
+                    cur_timestamp_utc,
+                )
+                .await?
+                .map(|(first, _)| first),
+        };
+
+        match flat_since {
+            Some(sample) => {
+                Ok((cur_timestamp_utc - convert_utc_from_str(sample.timestamp())?).num_seconds())
+            }
+            None => Ok(0),
+        }
+    }
+
+    #[doc = r#"
+        일자별 모니터링 인덱스 하나를 시계열(인덱스)별 1시간 단위 롤업 문서로 집계하는 함수.
+
+        1. `index_name` terms + `timestamp` 1시간 date_histogram composite 집계를 `after_key` 로 페이지 단위 조회
+        2. 버킷마다 최소/최대(min/max), 처음/마지막(top_metrics) 문서 수와 마지막 `_stats` 값을 구함
+        3. 각 버킷을 `MonitorRollup` 으로 변환
+
+        페어 샘플(`pair_name`)처럼 `index_name` 이 없는 문서는 롤업 대상이 아니다.
+
+        # Arguments
+        * `daily_index_name` - 일자별 모니터링 인덱스명
+
+        # Returns
+        * `Vec<MonitorRollup>` - 시간 단위 롤업 문서 목록
+    "#]
+    async fn fetch_hourly_rollups(
+        &self,
+        daily_index_name: &str,
+    ) -> anyhow::Result<Vec<MonitorRollup>> {
+        let mut rollups: Vec<MonitorRollup> = Vec::new();
+        let mut after_key: Option<Value> = None;
+
+        loop {
+            let mut composite: Value = json!({
+                "size": ROLLUP_PAGE_SIZE,
+                "sources": [
+                    { "index_name": { "terms": { "field": "index_name.keyword" } } },
+                    { "hour": { "date_histogram": { "field": "timestamp", "fixed_interval": "1h" } } }
+                ]
+            });
+
+            if let Some(after_key) = after_key.take() {
+                composite["after"] = after_key;
+            }
+
+            let search_query: Value = json!({
+                "size": 0,
+                "query": { "exists": { "field": "index_name" } },
+                "aggs": {
+                    "hourly": {
+                        "composite": composite,
+                        "aggs": {
+                            "min_cnt": { "min": { "field": "cnt" } },
+                            "max_cnt": { "max": { "field": "cnt" } },
+                            "first": {
+                                "top_metrics": {
+                                    "metrics": [{ "field": "cnt" }],
+                                    "sort": { "timestamp": "asc" }
+                                }
+                            },
+                            "last": {
+                                "top_metrics": {
+                                    "metrics": [
+                                        { "field": "cnt" },
+                                        { "field": "index_stats.store_size_bytes" },
+                                        { "field": "index_stats.docs_deleted" },
+                                        { "field": "index_stats.indexing_total" },
+                                        { "field": "index_stats.delete_total" },
+                                        { "field": "index_stats.segment_cnt" }
+                                    ],
+                                    "sort": { "timestamp": "desc" }
+                                }
+                            },
+                            "target_name": { "terms": { "field": "target_name.keyword", "size": 1 } },
+                            "cluster_name": { "terms": { "field": "cluster_name.keyword", "size": 1 } }
+                        }
+                    }
+                }
+            });
+
+            let response_body: Value = self
+                .es_conn
+                .get_search_query(&search_query, daily_index_name)
+                .await?;
+
+            let page: Vec<MonitorRollup> =
+                self.get_aggregation_result_vec(&response_body, "hourly")?;
+            let page_len: usize = page.len();
+            rollups.extend(page);
+
+            match response_body["aggregations"]["hourly"].get("after_key") {
+                Some(next_after_key) if page_len == ROLLUP_PAGE_SIZE => {
+                    after_key = Some(next_after_key.clone());
+                }
+                _ => break,
+            }
+        }
+
+        Ok(rollups)
+    }
+
+    #[doc = r#"
+        일자별 모니터링 인덱스 하나의 페어 샘플(`pair_name` 이 있는 문서)을 모두 조회하는 함수.
+
+        페어 샘플은 페어 수 x 검증 주기만큼만 쌓이므로 롤업하지 않고 그대로 롤업 인덱스로 옮긴다.
+        `timestamp`, `pair_name` 순으로 정렬해 `search_after` 로 `ROLLUP_PAGE_SIZE` 건씩 조회한다.
+
+        # Arguments
+        * `daily_index_name` - 일자별 모니터링 인덱스명
+
+        # Returns
+        * `Vec<IndexPairSample>` - 페어 샘플 목록 (timestamp 오름차순)
+    "#]
+    async fn fetch_pair_samples(
+        &self,
+        daily_index_name: &str,
+    ) -> anyhow::Result<Vec<IndexPairSample>> {
+        let mut pair_samples: Vec<IndexPairSample> = Vec::new();
+        let mut search_after: Option<Value> = None;
+
+        loop {
+            let mut search_query: Value = json!({
+                "size": ROLLUP_PAGE_SIZE,
+                "query": { "exists": { "field": "pair_name" } },
+                "sort": [
+                    { "timestamp": { "order": "asc" } },
+                    { "pair_name.keyword": { "order": "asc" } }
+                ]
+            });
+
+            if let Some(search_after) = search_after.take() {
+                search_query["search_after"] = search_after;
+            }
+
+            let response_body: Value = self
+                .es_conn
+                .get_search_query(&search_query, daily_index_name)
+                .await?;
+
+            let hits: &Vec<Value> = response_body["hits"]["hits"].as_array().ok_or_else(|| {
+                anyhow!("[QueryServiceImpl->fetch_pair_samples] Missing 'hits.hits'")
+            })?;
+
+            for hit in hits {
+                pair_samples.push(serde_json::from_value(hit["_source"].clone()).map_err(|e| {
+                    anyhow!(
+                        "[QueryServiceImpl->fetch_pair_samples] Failed to deserialize source: {:?}",
+                        e
+                    )
+                })?);
+            }
+
+            match hits.last() {
+                Some(last_hit) if hits.len() == ROLLUP_PAGE_SIZE => {
+                    search_after = Some(last_hit["sort"].clone());
+                }
+                _ => break,
+            }
+        }
+
+        Ok(pair_samples)
+    }
+
     #[doc = r#"
         Elasticsearch 집계 응답을 파싱하여 벡터 형태의 구조화된 객체로 변환하는 제네릭 함수.
 
@@ -46,8 +490,6 @@ impl QueryServiceImpl {
     where
         T: FromAggBucket,
     {
-        info!("response_body: {:?}", response_body);
-
         let buckets: &Value = response_body
             .get("aggregations")
             .and_then(|agg| agg.get(agg_name))
@@ -75,6 +517,7 @@ impl QueryServiceImpl {
         Ok(results)
     }
 
+    #[allow(dead_code)]
     fn get_aggregation_metric_value<T>(
         &self,
         response_body: &Value,
@@ -119,74 +562,6 @@ impl QueryServiceImpl {
         ))
     }
 
-    #[doc = r#"
-        Elasticsearch 검색 응답을 파싱하여 벡터 형태의 구조화된 객체로 변환하는 제네릭 함수.
-
-        1. ES 응답의 `hits.hits` 배열에서 각 검색 결과를 추출
-        2. 각 히트의 `_id`와 `_source`를 분리하여 파싱
-        3. `_source`를 지정된 타입 `S`로 역직렬화
-        4. `FromSearchHit` 트레이트를 통해 최종 타입 `T`로 변환
-        5. 모든 결과를 벡터로 수집하여 반환
-
-        # Type Parameters
-        * `T` - 최종 반환할 객체 타입 (`FromSearchHit` 트레이트 구현 필요)
-        * `S` - ES `_source` 필드의 역직렬화 타입 (`DeserializeOwned` 구현 필요)
-
-        # Arguments
-        * `response_body` - Elasticsearch 검색 응답 JSON
-
-        # Returns
-        * `Vec<T>` - 변환된 객체들의 벡터
-        * `anyhow::Error` - 응답 파싱 실패, 필수 필드 누락, 역직렬화 실패 시
-    "#]
-    fn get_query_result_vec<T, S>(&self, response_body: &Value) -> Result<Vec<T>, anyhow::Error>
-    where
-        S: DeserializeOwned,
-        T: FromSearchHit<S>,
-    {
-        let hits: &Value = response_body
-            .get("hits")
-            .and_then(|h| h.get("hits"))
-            .ok_or_else(|| {
-                anyhow!("[QueryServiceImpl->get_query_result_vec] Missing 'hits.hits' field")
-            })?;
-
-        let arr: &Vec<Value> = hits.as_array().ok_or_else(|| {
-            anyhow!("[QueryServiceImpl->get_query_result_vec] 'hits.hits' is not an array")
-        })?;
-
-        /* ID + source 역직렬화 → T 로 변환 */
-        let results: Vec<T> = arr
-            .iter()
-            .map(|hit| {
-                /* 1) doc_id */
-                let id: String = hit
-                    .get("_id")
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| {
-                        anyhow!("[QueryServiceImpl->get_query_result_vec] Missing or invalid '_id'")
-                    })?
-                    .to_string();
-
-                /* 2) source 역직렬화 */
-                let src_val: &Value = hit.get("_source").ok_or_else(|| {
-                    anyhow!("[QueryServiceImpl->get_query_result_vec] Missing '_source'")
-                })?;
-
-                let source: S = serde_json::from_value(src_val.to_owned()).map_err(|e| {
-                    anyhow!(
-                        "[QueryServiceImpl->get_query_result_vec] Failed to deserialize source: {}",
-                        e
-                    )
-                })?;
-
-                /* 3) 트레이트 메서드로 T 생성 */
-                Ok::<T, anyhow::Error>(T::from_search_hit(id, source))
-            })
-            .collect::<Result<_, _>>()?;
-        Ok(results)
-    }
-
     #[doc = r#"
         Elasticsearch 검색 응답에서 첫 번째 결과만을 파싱하여 단일 구조화된 객체로 변환하는 제네릭 함수.
 
@@ -247,427 +622,988 @@ impl QueryServiceImpl {
             )
         })?;
 
-        Ok(T::from_search_hit(id, source))
+        Ok(T::from_search_hit(id, source))
+    }
+
+    #[doc = r#"
+        인덱스 하나의 수집 쿼리(`_msearch` 의 개별 body)를 생성하는 함수.
+
+        1. `query_filter`가 있으면 해당 query DSL로, 없으면 `match_all` 쿼리로 검색 범위 지정
+        2. `track_total_hits: true`로 정확한 문서 수 계산
+        3. `breakdown_field`가 있으면 `by_breakdown` terms 집계 추가
+        4. `timestamp_field`가 있으면
+           - `latest_timestamp` max 집계 추가 (데이터 신선도)
+           - 직전 2구간으로 한정한 `ingest_window` filter 집계 아래에 `ingest_histogram` date_histogram 추가 (색인 속도)
+             `min_doc_count: 0`, `extended_bounds`로 문서가 없는 구간도 0건으로 반환
+
+        # Arguments
+        * `index_config` - 수집 대상 인덱스 설정
+        * `cur_utc_time` - 수집 기준 시각 (UTC)
+
+        # Returns
+        * `Value` - 검색 쿼리 body
+    "#]
+    fn build_collection_query(
+        &self,
+        index_config: &IndexConfig,
+        cur_utc_time: DateTime<Utc>,
+    ) -> Value {
+        let mut aggs: serde_json::Map<String, Value> = serde_json::Map::new();
+
+        if let Some(breakdown_field) = index_config.breakdown_field() {
+            aggs.insert(
+                "by_breakdown".to_string(),
+                json!({
+                    "terms": {
+                        "field": breakdown_field,
+                        "size": index_config.resolve_breakdown_size()
+                    }
+                }),
+            );
+        }
+
+        if let Some(timestamp_field) = index_config.timestamp_field() {
+            let interval_sec: i64 = index_config.resolve_ingest_interval_sec();
+            /* Two intervals are requested so that at least one bucket is complete. */
+            let start_millis: i64 =
+                calc_time_window(cur_utc_time, interval_sec * 2).timestamp_millis();
+            let end_millis: i64 = cur_utc_time.timestamp_millis();
+
+            aggs.insert(
+                "latest_timestamp".to_string(),
+                json!({ "max": { "field": timestamp_field } }),
+            );
+
+            aggs.insert(
+                "ingest_window".to_string(),
+                json!({
+                    "filter": {
+                        "range": {
+                            timestamp_field: {
+                                "gte": start_millis,
+                                "lt": end_millis,
+                                "format": "epoch_millis"
+                            }
+                        }
+                    },
+                    "aggs": {
+                        "ingest_histogram": {
+                            "date_histogram": {
+                                "field": timestamp_field,
+                                "fixed_interval": format!("{}s", interval_sec),
+                                "min_doc_count": 0,
+                                "extended_bounds": {
+                                    "min": start_millis,
+                                    "max": end_millis - 1
+                                }
+                            }
+                        }
+                    }
+                }),
+            );
+        }
+
+        json!({
+            "size": 0,                     /* 문서 본문은 받지 않음 */
+            "track_total_hits": true,      /* 정확한 총건수 계산 */
+            "query": index_config.query_filter().clone().unwrap_or_else(|| json!({ "match_all": {} })),
+            "aggs": aggs
+        })
+    }
+
+    #[doc = r#"
+        `_msearch` 의 개별 응답을 `AlertIndex` 로 변환하는 함수.
+
+        1. 개별 응답에 `error` 가 있으면 에러 반환
+        2. `hits.total.value` 를 문서 수로 사용
+        3. `by_breakdown` 집계 -> 버킷별 문서 수
+        4. `latest_timestamp` 집계 -> 현재 시각과의 차이(신선도 지연, 초)
+        5. `ingest_window.ingest_histogram` 집계 -> 마지막으로 완료된 구간의 신규 문서 수
+
+        # Arguments
+        * `index_config` - 수집 대상 인덱스 설정
+        * `response` - 해당 인덱스의 `_msearch` 개별 응답
+        * `cur_utc_time` - 수집 기준 시각 (UTC)
+
+        # Returns
+        * `AlertIndex` - 수집된 샘플
+        * `anyhow::Error` - 개별 응답 에러 또는 파싱 실패 시
+    "#]
+    fn parse_collection_response(
+        &self,
+        index_config: &IndexConfig,
+        response: &Value,
+        cur_utc_time: DateTime<Utc>,
+    ) -> anyhow::Result<AlertIndex> {
+        let index_name: &str = index_config.index_name();
+
+        if let Some(error) = response.get("error") {
+            return Err(anyhow!(
+                "[QueryServiceImpl->parse_collection_response] {}: {:?}",
+                index_name,
+                error
+            ));
+        }
+
+        let doc_cnt: usize = response["hits"]["total"]["value"]
+            .as_i64()
+            .ok_or_else(|| {
+                anyhow!(
+                    "[QueryServiceImpl->parse_collection_response] {}: invalid hits.total.value in search response",
+                    index_name
+                )
+            })?
+            .try_into()?;
+
+        let mut alert_index: AlertIndex = AlertIndex::new(
+            index_name.to_string(),
+            doc_cnt,
+            convert_date_to_str(cur_utc_time, Utc),
+        );
+        alert_index.set_target_name(index_config.target_name().clone());
+        alert_index.set_cluster_name(Some(index_config.resolve_cluster_name().to_string()));
+
+        if index_config.breakdown_field().is_some() {
+            let buckets: Vec<BreakdownBucket> =
+                self.get_aggregation_result_vec(response, "by_breakdown")?;
+            alert_index.set_breakdown(Some(buckets));
+        }
+
+        if index_config.timestamp_field().is_some() {
+            let aggregations: &Value = &response["aggregations"];
+
+            /* Data freshness -> how far the newest document lags behind now. */
+            if let Some(latest_millis) = aggregations["latest_timestamp"]["value"].as_f64()
+                && let Some(latest_timestamp) =
+                    DateTime::from_timestamp_millis(latest_millis as i64)
+            {
+                alert_index
+                    .set_freshness_lag_sec(Some((cur_utc_time - latest_timestamp).num_seconds()));
+            }
+
+            /* Ingestion rate -> documents created during the last complete interval. */
+            let interval_millis: i64 = index_config.resolve_ingest_interval_sec() * 1000;
+            let cur_millis: i64 = cur_utc_time.timestamp_millis();
+
+            let ingest_buckets: Vec<IngestRateBucket> =
+                aggregations["ingest_window"]["ingest_histogram"]["buckets"]
+                    .as_array()
+                    .map(|buckets| {
+                        buckets
+                            .iter()
+                            .map(IngestRateBucket::from_agg_bucket)
+                            .collect::<anyhow::Result<Vec<IngestRateBucket>>>()
+                    })
+                    .transpose()?
+                    .unwrap_or_default();
+
+            alert_index.set_ingest_cnt(
+                ingest_buckets
+                    .iter()
+                    .rev()
+                    .find(|bucket| bucket.key_millis() + interval_millis <= cur_millis)
+                    .map(|bucket| *bucket.cnt()),
+            );
+        }
+
+        Ok(alert_index)
+    }
+
+    #[doc = r#"
+        여러 대상의 `_stats` 를 한 번의 요청으로 조회하여 대상명별 `IndexStats` 로 반환하는 함수.
+
+        1. 모든 대상을 하나의 `_stats` 요청(level=indices)으로 조회
+        2. 대상명과 같은 이름의 인덱스가 있으면 해당 인덱스의 primaries 통계를 사용
+        3. 글롭 패턴이면 일치하는 인덱스들의 통계를 합산
+        4. 그 외(별칭/데이터 스트림)이거나 일괄 조회가 실패하면 대상별로 개별 조회
+
+        # Arguments
+        * `targets` - `_stats` 를 조회할 대상(인덱스/패턴/별칭) 목록
+
+        # Returns
+        * `HashMap<String, IndexStats>` - 대상명별 통계 (조회 실패한 대상은 제외, 에러는 로그로만 처리)
+    "#]
+    async fn fetch_index_stats_map(&self, targets: &[&str]) -> HashMap<String, IndexStats> {
+        let mut index_stats_map: HashMap<String, IndexStats> = HashMap::new();
+
+        if targets.is_empty() {
+            return index_stats_map;
+        }
+
+        let indices_stats: Value = match self.es_conn.get_indices_stats(targets).await {
+            Ok(response_body) => response_body["indices"].clone(),
+            Err(e) => {
+                error!("[QueryServiceImpl->fetch_index_stats_map] {:?}", e);
+                Value::Null
+            }
+        };
+
+        for target in targets {
+            let bulk_stats: Option<IndexStats> = match (&indices_stats, glob::Pattern::new(target))
+            {
+                (Value::Object(indices), _) if indices.contains_key(*target) => {
+                    Some(IndexStats::from_primaries(&indices[*target]["primaries"]))
+                }
+                (Value::Object(indices), Ok(pattern)) if target.contains('*') => Some(
+                    indices
+                        .iter()
+                        .filter(|(index_name, _)| pattern.matches(index_name))
+                        .fold(IndexStats::default(), |mut sum, (_, index)| {
+                            sum.accumulate(&IndexStats::from_primaries(&index["primaries"]));
+                            sum
+                        }),
+                ),
+                _ => None,
+            };
+
+            let index_stats: Option<IndexStats> = match bulk_stats {
+                Some(index_stats) => Some(index_stats),
+                None => match self.es_conn.get_index_stats(target).await {
+                    Ok(response_body) => IndexStats::from_stats_response(&response_body)
+                        .map_err(|e| error!("[QueryServiceImpl->fetch_index_stats_map] {:?}", e))
+                        .ok(),
+                    Err(e) => {
+                        error!("[QueryServiceImpl->fetch_index_stats_map] {:?}", e);
+                        None
+                    }
+                },
+            };
+
+            if let Some(index_stats) = index_stats {
+                index_stats_map.insert(target.to_string(), index_stats);
+            }
+        }
+
+        index_stats_map
+    }
+}
+
+#[async_trait]
+impl QueryService for QueryServiceImpl {
+    #[doc = r#"
+        모니터링 대상 인덱스 전체의 샘플(`AlertIndex`)을 한 번의 왕복으로 수집하는 함수.
+
+        1. 인덱스별 수집 쿼리(`build_collection_query`)를 하나의 `_msearch` 요청으로 묶어 조회
+           - 문서 수(`hits.total`), breakdown terms 집계, 최신 timestamp, 색인 속도 histogram 을 한 쿼리로 처리
+        2. 원본 인덱스 시계열의 `_stats` 를 한 번의 요청으로 조회 (`fetch_index_stats_map`)
+        3. `_msearch` 응답 순서대로 인덱스별 `AlertIndex` 를 생성
+           - 개별 응답에 에러가 있으면 해당 인덱스만 에러 로그를 남기고 제외
+
+        인덱스 수와 무관하게 대상 클러스터 요청 수가 일정하므로 수집 시간이 인덱스 수에 비례해 늘어나지 않는다.
+
+        # Arguments
+        * `index_configs` - 수집 대상 인덱스 설정 목록 (패턴/필터가 시계열 단위로 펼쳐진 목록)
+        * `cur_utc_time` - 수집 기준 시각 (UTC)
+
+        # Returns
+        * `Vec<AlertIndex>` - 수집에 성공한 인덱스의 샘플 목록
+        * `anyhow::Error` - `_msearch` 요청 자체가 실패한 경우
+    "#]
+    async fn collect_index_samples(
+        &self,
+        index_configs: &[IndexConfig],
+        cur_utc_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<AlertIndex>> {
+        if index_configs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let es_queries: Vec<(String, Value)> = index_configs
+            .iter()
+            .map(|index_config| {
+                (
+                    index_config.count_target().to_string(),
+                    self.build_collection_query(index_config, cur_utc_time),
+                )
+            })
+            .collect();
+
+        let response_body: Value = self.es_conn.get_msearch_query(&es_queries).await?;
+
+        let responses: &Vec<Value> = response_body["responses"].as_array().ok_or_else(|| {
+            anyhow!("[QueryServiceImpl->collect_index_samples] 'responses' is not an array")
+        })?;
+
+        /* Filter series share the source index, so only the base series collects `_stats`. */
+        let stats_targets: Vec<&str> = index_configs
+            .iter()
+            .filter(|index_config| !index_config.is_filter_series())
+            .map(|index_config| index_config.count_target())
+            .collect();
+
+        let index_stats_map: HashMap<String, IndexStats> =
+            self.fetch_index_stats_map(&stats_targets).await;
+
+        let mut alert_indexes: Vec<AlertIndex> = Vec::with_capacity(index_configs.len());
+
+        for (index_config, response) in index_configs.iter().zip(responses) {
+            let mut alert_index: AlertIndex =
+                match self.parse_collection_response(index_config, response, cur_utc_time) {
+                    Ok(alert_index) => alert_index,
+                    Err(e) => {
+                        error!("[QueryServiceImpl->collect_index_samples] {:?}", e);
+                        continue;
+                    }
+                };
+
+            if !index_config.is_filter_series() {
+                alert_index
+                    .set_index_stats(index_stats_map.get(index_config.count_target()).cloned());
+            }
+
+            alert_indexes.push(alert_index);
+        }
+
+        Ok(alert_indexes)
+    }
+
+    #[doc = r#"
+        지정된 Elasticsearch 인덱스의 전체 문서 개수를 정확하게 조회하여 반환하는 함수.
+
+        원본/복제 클러스터 정합성 검증처럼 집계 없이 문서 수만 필요한 경우에 사용한다.
+
+        1. `size: 0`, `track_total_hits: true`로 `match_all` 검색 수행
+        2. 응답의 `hits.total.value`에서 문서 개수를 usize로 변환
+
+        # Arguments
+        * `index_name` - 문서 개수를 조회할 Elasticsearch 인덱스명(패턴/별칭)
+
+        # Returns
+        * `usize` - 인덱스의 총 문서 개수
+        * `anyhow::Error` - ES 조회 실패, 응답 파싱 실패, 타입 변환 실패 시
+    "#]
+    async fn get_index_doc_count(&self, index_name: &str) -> anyhow::Result<usize> {
+        let query: Value = json!({
+            "size": 0,                     /* 문서 본문은 받지 않음 */
+            "track_total_hits": true,      /* 정확한 총건수 계산 */
+            "query": { "match_all": {} }
+        });
+
+        let resp: Value = self.es_conn.get_search_query(&query, index_name).await?;
+
+        let value: usize = resp["hits"]["total"]["value"]
+            .as_i64()
+            .ok_or_else(|| {
+                anyhow!(
+                    "[QueryServiceImpl->get_index_doc_count] {}: invalid hits.total.value in search response",
+                    index_name
+                )
+            })?
+            .try_into()?;
+
+        Ok(value)
+    }
+
+    #[doc = r#"
+        글롭 패턴/별칭/데이터 스트림에 해당하는 실제 인덱스 목록을 조회하는 함수.
+
+        1. `_resolve/index/{index_pattern}` API 호출
+        2. 응답의 `indices[].name` - 패턴에 직접 일치하는 인덱스
+        3. 응답의 `aliases[].indices` - 별칭이 가리키는 인덱스
+        4. 응답의 `data_streams[].backing_indices` - 데이터 스트림의 backing 인덱스
+        5. 중복을 제거하고 이름순으로 정렬하여 반환
+
+        # Arguments
+        * `index_pattern` - 인덱스명, 글롭 패턴, 별칭 또는 데이터 스트림명
+
+        # Returns
+        * `Vec<String>` - 실제 인덱스명 목록
+        * `anyhow::Error` - ES 조회 실패 시
+    "#]
+    async fn resolve_concrete_indices(&self, index_pattern: &str) -> anyhow::Result<Vec<String>> {
+        let resp: Value = self.es_conn.get_resolve_index(index_pattern).await?;
+
+        let mut concrete_indices: Vec<String> = Vec::new();
+
+        let names_of = |key: &str, field: &str| -> Vec<String> {
+            resp[key]
+                .as_array()
+                .map(|entries| {
+                    entries
+                        .iter()
+                        .flat_map(|entry| match &entry[field] {
+                            Value::String(name) => vec![name.to_string()],
+                            Value::Array(names) => names
+                                .iter()
+                                .filter_map(|name| name.as_str().map(str::to_string))
+                                .collect(),
+                            _ => Vec::new(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        concrete_indices.extend(names_of("indices", "name"));
+        concrete_indices.extend(names_of("aliases", "indices"));
+        concrete_indices.extend(names_of("data_streams", "backing_indices"));
+
+        concrete_indices.sort();
+        concrete_indices.dedup();
+
+        Ok(concrete_indices)
+    }
+
+    #[doc = r#"
+        expand 모드로 수집된 대상(패턴/별칭)의 시계열(실제 인덱스)명 목록을 조회하는 함수.
+
+        1. 시간 범위 내에서 `target_name.keyword`가 대상명과 일치하는 샘플만 필터링
+        2. `index_name.keyword` terms 집계로 시계열명을 추출
+        3. 리포트 생성 시 expand 대상을 실제 인덱스별로 펼치는 데 사용
+
+        # Arguments
+        * `mon_index_name` - 모니터링 데이터가 저장된 인덱스명(패턴)
+        * `target_name` - 설정 파일에 정의된 대상(패턴/별칭)명
+        * `start_time` - 조회 시작 시간 (UTC)
+        * `end_time` - 조회 종료 시간 (UTC)
+
+        # Returns
+        * `Vec<String>` - 시계열명 목록
+        * `anyhow::Error` - ES 조회 실패 또는 파싱 실패 시
+    "#]
+    async fn get_expanded_series_names(
+        &self,
+        mon_index_name: &str,
+        target_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<String>> {
+        self.ts_repo
+            .read_series_names(mon_index_name, target_name, start_time, end_time)
+            .await
+    }
+
+    #[doc = r#"
+        AlertIndex 목록을 지정된 Elasticsearch 인덱스에 한 번의 `_bulk` 요청으로 색인(저장)하는 함수.
+
+        1. 각 `AlertIndex` 구조체를 JSON 형태로 직렬화
+        2. Elasticsearch의 `_bulk` API를 통해 지정된 인덱스에 모든 문서를 한 번에 저장
+        3. 색인 실패(일부 문서 실패 포함) 시 에러 로그를 기록하고 로컬 스풀에 보관 (이후 재전송)
+        4. 스풀에 보관된 경우 상위 호출자에게 성공으로 반환
+
+        # Arguments
+        * `index_name` - 문서를 저장할 Elasticsearch 인덱스명
+        * `alert_indexes` - 색인할 AlertIndex 목록
+
+        # Returns
+        * `()` - 색인 성공 또는 스풀 보관 시
+        * `anyhow::Error` - 직렬화 실패 또는 스풀 기록 실패 시
+    "#]
+    async fn post_log_indices(
+        &self,
+        index_name: &str,
+        alert_indexes: &[AlertIndex],
+    ) -> anyhow::Result<()> {
+        if alert_indexes.is_empty() {
+            return Ok(());
+        }
+
+        self.ts_repo.write_samples(index_name, alert_indexes).await
+    }
+
+    #[doc = "원본/복제 클러스터 정합성 검증 결과(페어 샘플)를 시계열 저장소에 기록하는 함수."]
+    async fn post_pair_samples(
+        &self,
+        index_name: &str,
+        pair_samples: &[IndexPairSample],
+    ) -> anyhow::Result<()> {
+        if pair_samples.is_empty() {
+            return Ok(());
+        }
+
+        self.ts_repo
+            .write_pair_samples(index_name, pair_samples)
+            .await
+    }
+
+    #[doc = r#"
+        리포트 기간 동안의 페어별 복제 정합성 요약을 시계열 저장소에서 조회하는 함수.
+
+        # Arguments
+        * `mon_index_name` - 모니터링 인덱스명(패턴)
+        * `start_time` - 조회 시작 시각 (UTC)
+        * `end_time` - 조회 종료 시각 (UTC)
+
+        # Returns
+        * `Vec<PairConsistencyInfo>` - 페어명 오름차순 정합성 요약
+    "#]
+    async fn get_pair_consistency_infos(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<PairConsistencyInfo>> {
+        self.ts_repo
+            .read_pair_consistency_infos(mon_index_name, start_time, end_time)
+            .await
+    }
+
+    #[doc = "AlarmLogHistoryIndex 구조체를 알람 이력 저장소에 기록하는 함수."]
+    async fn post_alarm_history_index(
+        &self,
+        index_name: &str,
+        alarm_history_index: AlarmLogHistoryIndex,
+    ) -> anyhow::Result<()> {
+        self.alarm_repo
+            .write_alarm_history(index_name, &alarm_history_index)
+            .await
     }
 
     #[doc = r#"
-        지정된 시간 범위와 인덱스에 대해 Elasticsearch 집계 쿼리를 실행하여 단일 집계 값을 조회하는 헬퍼 함수.
+        원본 보관 기간(`compaction.raw_retention_days`)이 지난 일자별 모니터링 인덱스를 롤업으로 압축하는 함수.
 
-        1. 시간 범위(`timestamp` 필드) 및 인덱스명(`index_name.keyword`)으로 필터링된 bool 쿼리 생성
-        2. 지정된 집계 타입(`agg_body`)으로 집계 수행 (예: max, min, avg 등)
-        3. 집계 결과에서 `value` 필드를 f64 타입으로 추출
-        4. NaN이나 무한대 값 방어를 통해 유효한 값만 반환
-        5. 결과가 없거나 유효하지 않으면 None 반환
+        1. `{mon_index_name}YYYYMMDD` 형태의 일자별 인덱스 중 보관 기간 이전 일자만 선택
+        2. 인덱스마다 시계열별 1시간 단위 롤업 문서를 만들어 `{rollup_index_name}YYYYMM` 에 색인
+           - 문서 ID 가 `{index_name}|{timestamp}` 이므로 중간에 실패해 다시 압축해도 중복되지 않음
+        3. 페어 샘플은 롤업하지 않고 같은 롤업 인덱스에 그대로 옮김 (문서 ID `{pair_name}|{timestamp}`)
+           - 롤업 조회는 `index_name` 으로, 페어 조회는 `pair_name` 으로 필터링하므로 서로 섞이지 않음
+        4. 롤업/페어 샘플 색인이 모두 성공한 경우에만 원본 일자별 인덱스를 삭제
 
         # Arguments
-        * `mon_index_name` - 모니터링 데이터가 저장된 Elasticsearch 인덱스명
-        * `index_name` - 필터링할 대상 인덱스명 (term 쿼리용)
-        * `gte` - 시간 범위 시작점 (greater than or equal, ISO 8601 포맷)
-        * `lte` - 시간 범위 종료점 (less than or equal, ISO 8601 포맷)
-        * `agg_name` - 집계 쿼리의 이름 (응답에서 참조용)
-        * `agg_body` - 집계 쿼리 본문 (JSON 형태의 집계 정의)
+        * `mon_index_name` - 모니터링 인덱스 접두어
 
         # Returns
-        * `Option<f64>` - 집계 결과 값 (유효한 경우), None (결과 없음 또는 무효한 값)
-        * `anyhow::Error` - ES 조회 실패 시
+        * `usize` - 압축(삭제)한 일자별 인덱스 수
+        * `anyhow::Error` - 조회/색인/삭제 실패 시 (이미 압축한 인덱스는 유지)
     "#]
-    async fn fetch_agg_value_f64(
-        &self,
-        mon_index_name: &str,
-        index_name: &str,
-        gte: DateTime<Utc>,
-        lte: DateTime<Utc>,
-        agg_name: &str,
-        agg_body: Value,
-    ) -> anyhow::Result<Option<f64>> {
-        let query: Value = json!({
-            "size": 0,
-            "query": {
-                "bool": {
-                    "filter": [
-                        {
-                            "range": {
-                                "timestamp": { "gte": convert_date_to_str(gte, Utc), "lte": convert_date_to_str(lte, Utc) }
-                            }
-                        },
-                        {
-                            "term": {
-                                "index_name.keyword": index_name
-                            }
-                        }
-                    ]
-                }
+    async fn compact_monitor_indices(&self, mon_index_name: &str) -> anyhow::Result<usize> {
+        let compaction_config: &CompactionConfig = get_compaction_config_info();
+
+        let Some(raw_cutoff_time) = compaction_config.raw_cutoff_time(Utc::now()) else {
+            return Ok(0);
+        };
+
+        let rollup_index_name: String = compaction_config.resolve_rollup_index_name(mon_index_name);
+
+        /* 롤업 인덱스가 모니터링 인덱스 패턴에 포함되면 원본과 롤업이 이중으로 집계됨 */
+        if rollup_index_name.starts_with(mon_index_name) {
+            return Err(anyhow!(
+                "[QueryServiceImpl->compact_monitor_indices] rollup_index_name '{}' must not start with monitor_index_name '{}'",
+                rollup_index_name,
+                mon_index_name
+            ));
+        }
 
-            },
-            "aggs": {
-                agg_name: agg_body
+        let daily_indices: Vec<String> = self
+            .resolve_concrete_indices(&format!("{}*", mon_index_name))
+            .await?;
+
+        let mut compacted_cnt: usize = 0;
+
+        for daily_index_name in daily_indices {
+            let Some(index_date) =
+                daily_index_name
+                    .strip_prefix(mon_index_name)
+                    .and_then(|date_suffix| {
+                        chrono::NaiveDate::parse_from_str(date_suffix, "%Y%m%d").ok()
+                    })
+            else {
+                continue;
+            };
+
+            if index_date >= raw_cutoff_time.date_naive() {
+                continue;
             }
-        });
 
-        let resp: Value = self
-            .es_conn
-            .get_search_query(&query, mon_index_name)
-            .await?;
-        let v: Option<f64> = resp["aggregations"][agg_name]["value"].as_f64();
+            let rollups: Vec<MonitorRollup> = self.fetch_hourly_rollups(&daily_index_name).await?;
 
-        /* NaN/무한대 방어 */
-        let v: Option<f64> = v.and_then(|x| if x.is_finite() { Some(x) } else { None });
-        Ok(v)
-    }
+            let pair_samples: Vec<IndexPairSample> =
+                self.fetch_pair_samples(&daily_index_name).await?;
 
-    #[doc = r#"
-        지정된 시간 범위와 인덱스에 대해 cnt 필드의 최대/최소 값을 병렬로 조회한다.
+            let mut documents: Vec<(String, Value)> = rollups
+                .iter()
+                .map(|rollup| Ok((rollup.doc_id(), convert_json_from_struct(rollup)?)))
+                .collect::<anyhow::Result<Vec<(String, Value)>>>()?;
 
-        1. `fetch_agg_value_f64`를 통해 max 집계와 min 집계를 각각 수행
-        2. Elasticsearch의 집계 기능을 사용하여 지정된 시간 범위 내에서:
-           - `cnt` 필드의 최대값을 조회
-           - `cnt` 필드의 최소값을 조회
-        3. 각 값이 null이거나 유효하지 않은 경우 0.0으로 기본값 설정
+            for pair_sample in &pair_samples {
+                documents.push((pair_sample.doc_id(), convert_json_from_struct(pair_sample)?));
+            }
 
-        # Arguments
-        * `mon_index_name` - 모니터링 데이터가 저장된 인덱스명
-        * `index_name` - 조회 대상 인덱스명 (필터링용)
-        * `gte` - 시작 시간 (greater than or equal)
-        * `lte` - 종료 시간 (less than or equal)
+            let save_rollup_index: String =
+                format!("{}{}", rollup_index_name, index_date.format("%Y%m"));
 
-        # Returns
-        * `(f64, f64)` - (최소값, 최대값) 튜플
-        * `anyhow::Error` - ES 조회 실패 시
-    "#]
-    async fn fetch_min_max_values(
-        &self,
-        mon_index_name: &str,
-        index_name: &str,
-        gte: DateTime<Utc>,
-        lte: DateTime<Utc>,
-    ) -> anyhow::Result<(f64, f64)> {
-        let max_val: f64 = self
-            .fetch_agg_value_f64(
-                mon_index_name,
-                index_name,
-                gte,
-                lte,
-                "max_value_in_range",
-                json!({ "max": { "field": "cnt" } }),
-            )
-            .await?
-            .unwrap_or(0.0);
+            for chunk in documents.chunks(ROLLUP_PAGE_SIZE) {
+                self.es_conn
+                    .post_bulk_query_with_ids(chunk, &save_rollup_index)
+                    .await?;
+            }
 
-        let min_val: f64 = self
-            .fetch_agg_value_f64(
-                mon_index_name,
-                index_name,
-                gte,
-                lte,
-                "min_value_in_range",
-                json!({ "min": { "field": "cnt" } }),
-            )
-            .await?
-            .unwrap_or(0.0);
+            self.es_conn.delete_index(&daily_index_name).await?;
+
+            info!(
+                "[QueryServiceImpl->compact_monitor_indices] {} -> {} ({} hourly rollups, {} pair samples)",
+                daily_index_name,
+                save_rollup_index,
+                rollups.len(),
+                pair_samples.len()
+            );
+
+            compacted_cnt += 1;
+        }
 
-        Ok((min_val, max_val))
+        Ok(compacted_cnt)
     }
 
     #[doc = r#"
-        최소값과 최대값을 바탕으로 변동률(%)을 계산하는 헬퍼 함수.
-
-        변동률 = ((최대값 - 최소값) / 최소값) × 100
+        모니터링/알람 이력 인덱스의 템플릿(명시적 매핑)과 ILM 정책을 생성하는 함수 - 프로그램 시작 시 1회 호출.
 
-        1. 최소값이 0보다 큰 경우: 정상적인 변동률 계산 수행  
-        2. 최소값이 0 이하인 경우: 0.0 반환 (0으로 나누기 방지)  
-        3. 결과는 백분율로 표현됨 (예: 50.0 = 50%)
+        1. 모니터링 인덱스(`monitor_index_name` + `YYYYMMDD`) - 샘플/페어 샘플 매핑, 보관 기간 `monitor_retention_days`
+        2. 알람 이력 인덱스(`alarm_log_index`) - 알람 이력 매핑, 보관 기간 `alarm_retention_days`
 
         # Arguments
-        * `min_val` - 최소값
-        * `max_val` - 최대값
+        * `mon_index_name` - 모니터링 인덱스 접두어
+        * `alarm_index_name` - 알람 이력 인덱스 접두어
+        * `bootstrap_config` - 보관 기간 설정
 
         # Returns
-        * `f64` - 변동률 (백분율)
+        * `anyhow::Error` - 정책/템플릿 생성 실패 시
     "#]
-    fn calculate_fluctuation(val1: f64, val2: f64) -> f64 {
-        let diff: f64 = (val1 - val2).abs();
-
-        let avg: f64 = (val1.abs() + val2.abs()) / 2.0;
-
-        if avg > 0.0 { (diff / avg) * 100.0 } else { 0.0 }
-    }
-
-    async fn fetch_index_cnt_infos<'a>(
+    async fn bootstrap_index_templates(
         &self,
         mon_index_name: &str,
-        index_name: &str,
-        prev_timestamp_utc: DateTime<Utc>,
-        cur_timestamp_utc: DateTime<Utc>,
-        size: usize,
-        sorts: Option<Vec<SortSpec<'a>>>,
-    ) -> anyhow::Result<Vec<AlertIndexFormat>> {
-        let sort_json: Vec<Value> = sorts
-            .unwrap_or_default()
-            .iter()
-            .map(|spec| spec.to_es_json())
-            .collect();
-
-        let search_query: Value = json!({
-            "query": {
-                "bool": {
-                    "filter": [
-                        {
-                            "range": {
-                                "timestamp": {
-                                    "gte": convert_date_to_str(prev_timestamp_utc, Utc),
-                                    "lte": convert_date_to_str(cur_timestamp_utc, Utc)
-                                }
-                            }
-                        },
-                        {
-                            "term": {
-                                "index_name.keyword": index_name
-                            }
-                        }
-                    ]
-                }
-            },
-            "sort": sort_json,
-            "size": size
-        });
+        alarm_index_name: &str,
+        bootstrap_config: &IndexBootstrapConfig,
+    ) -> anyhow::Result<()> {
+        let compaction_config: &CompactionConfig = get_compaction_config_info();
+
+        let mut monitor_retention_days: u32 = bootstrap_config
+            .monitor_retention_days()
+            .unwrap_or(DEFAULT_MONITOR_RETENTION_DAYS);
+
+        if let Some(raw_retention_days) = *compaction_config.raw_retention_days() {
+            /* ILM 이 압축 전에 원본 인덱스를 삭제하지 않도록 원본 보관 기간보다 길게 유지 */
+            if monitor_retention_days <= raw_retention_days {
+                warn!(
+                    "[QueryServiceImpl->bootstrap_index_templates] monitor_retention_days({}) <= compaction.raw_retention_days({}), raised to {}",
+                    monitor_retention_days,
+                    raw_retention_days,
+                    raw_retention_days + 1
+                );
+                monitor_retention_days = raw_retention_days + 1;
+            }
 
-        let response_body: Value = self
-            .es_conn
-            .get_search_query(&search_query, mon_index_name)
+            self.put_index_family_template(
+                &compaction_config.resolve_rollup_index_name(mon_index_name),
+                Self::rollup_index_mappings(),
+                compaction_config.resolve_rollup_retention_days(),
+            )
             .await?;
+        }
 
-        self.get_query_result_vec::<AlertIndexFormat, AlertIndex>(&response_body)
+        self.put_index_family_template(
+            mon_index_name,
+            Self::monitor_index_mappings(),
+            monitor_retention_days,
+        )
+        .await?;
+
+        self.put_index_family_template(
+            alarm_index_name,
+            Self::alarm_index_mappings(),
+            bootstrap_config
+                .alarm_retention_days()
+                .unwrap_or(DEFAULT_ALARM_RETENTION_DAYS),
+        )
+        .await
     }
 
-    async fn get_all_indicies_count_by_time<'a>(
-        &self,
-        mon_index_name: &str,
-        start_time: DateTime<Utc>,
-        end_time: DateTime<Utc>,
-        sorts: Option<Vec<SortSpec<'a>>>,
-    ) -> anyhow::Result<Vec<IndexCountAggResult>> {
-        let sort_json: Vec<Value> = sorts
-            .unwrap_or_default()
-            .iter()
-            .map(|spec| spec.to_es_json())
-            .collect();
+    #[doc = r#"
+        로컬 스풀에 보관된 문서를 모니터링 클러스터에 재전송하는 함수.
 
-        let search_query: Value = json!({
-            "size": 1,
-            "track_total_hits": false,
-            "query": {
-                "range": {
-                    "timestamp": {
-                        "gte": convert_date_to_str(start_time, Utc),
-                        "lte": convert_date_to_str(end_time, Utc)
-                    }
-                }
-            },
-            "aggs": {
-                "by_index": {
-                    "terms": {
-                        "field": "index_name.keyword",
-                        "size": 1000
-                    },
-                    "aggs": {
-                        "est_cnt": {
-                            "top_metrics": {
-                                "sort": sort_json,
-                                "metrics": [
-                                    { "field": "cnt" },
-                                    { "field": "timestamp" }
-                                ]
-                            }
-                        }
-                    }
-                }
+        1. 재전송 대상 문서를 스풀에서 가져옴 (이전 재전송에서 남은 문서 우선)
+        2. 같은 인덱스의 연속된 문서를 `SPOOL_REPLAY_BULK_SIZE` 단위의 `_bulk` 요청으로 색인
+           - 문서는 수집 당시의 timestamp 와 일자별 인덱스명을 그대로 사용
+           - 문서 ID 가 있는 문서는 같은 ID 로 색인하므로 이미 색인된 문서도 중복되지 않음
+        3. 색인이 실패하면 아직 전송하지 못한 문서만 스풀에 남기고 다음 재전송 시 이어서 처리
+
+        # Returns
+        * `usize` - 이번에 재전송한 문서 수
+        * `anyhow::Error` - 스풀 파일 접근 실패 또는 색인 실패 시
+    "#]
+    async fn replay_spooled_documents(&self) -> anyhow::Result<usize> {
+        let spool_repo: Arc<SpoolRepositoryImpl> = get_spool_repo();
+        let spool_entries: Vec<SpoolEntry> = spool_repo.take_replay_entries()?;
+
+        let mut replayed_cnt: usize = 0;
+
+        while replayed_cnt < spool_entries.len() {
+            let first_entry: &SpoolEntry = &spool_entries[replayed_cnt];
+            let index_name: &str = first_entry.index_name();
+            let with_ids: bool = first_entry.doc_id().is_some();
+
+            let batch: Vec<&SpoolEntry> = spool_entries[replayed_cnt..]
+                .iter()
+                .take(SPOOL_REPLAY_BULK_SIZE)
+                .take_while(|entry| {
+                    entry.index_name() == index_name && entry.doc_id().is_some() == with_ids
+                })
+                .collect();
+
+            let bulk_res: anyhow::Result<()> = if with_ids {
+                let documents: Vec<(String, Value)> = batch
+                    .iter()
+                    .map(|entry| {
+                        (
+                            entry.doc_id().clone().unwrap_or_default(),
+                            entry.document().clone(),
+                        )
+                    })
+                    .collect();
+
+                self.es_conn
+                    .post_bulk_query_with_ids(&documents, index_name)
+                    .await
+            } else {
+                let documents: Vec<Value> =
+                    batch.iter().map(|entry| entry.document().clone()).collect();
+
+                self.es_conn.post_bulk_query(&documents, index_name).await
+            };
+
+            if let Err(e) = bulk_res {
+                spool_repo.complete_replay(&spool_entries[replayed_cnt..])?;
+                return Err(anyhow!(
+                    "[QueryServiceImpl->replay_spooled_documents] Replayed {} of {} documents: {:?}",
+                    replayed_cnt,
+                    spool_entries.len(),
+                    e
+                ));
             }
-        });
 
-        let response_body: Value = self
-            .es_conn
-            .get_search_query(&search_query, mon_index_name)
-            .await?;
+            replayed_cnt += batch.len();
+        }
 
-        /* get_aggregation_result_vec를 사용하여 집계 결과 파싱 */
-        let results: Vec<IndexCountAggResult> =
-            self.get_aggregation_result_vec(&response_body, "by_index")?;
+        spool_repo.complete_replay(&[])?;
 
-        Ok(results)
+        Ok(replayed_cnt)
     }
-}
 
-#[async_trait]
-impl QueryService for QueryServiceImpl {
     #[doc = r#"
-        지정된 Elasticsearch 인덱스의 전체 문서 개수를 정확하게 조회하여 반환하는 함수.
+        지정된 시간 범위 내에 저장된 특정 인덱스의 샘플(`AlertIndex`)들을 시간순으로 조회하는 함수.
 
-        1. `match_all` 쿼리로 모든 문서를 대상으로 검색 수행
-        2. `size: 0`으로 설정하여 문서 본문은 받지 않고 메타데이터만 조회
-        3. `track_total_hits: true`로 설정하여 정확한 총 문서 수 계산 활성화
-        4. 응답의 `hits.total.value`에서 문서 개수를 usize로 변환
-        5. 큰 인덱스에서도 정확한 카운트를 보장
+        1. `timestamp` 범위 및 `index_name.keyword`로 필터링
+        2. `timestamp` 내림차순(최신 샘플부터)으로 `MAX_SAMPLE_SIZE`건씩 조회
+           - 한 페이지가 가득 차면 가장 오래된 샘플 직전(1초 전)까지로 구간을 줄여 다음 페이지를 조회
+           - 샘플 주기가 짧아 구간 내 샘플이 `MAX_SAMPLE_SIZE`를 넘어도 최신 샘플이 누락되지 않음
+        3. 시간순(오름차순)으로 뒤집어 반환
+        4. timestamp는 UTC 문자열 그대로 반환 (리포트용 조회와 달리 로컬 시간으로 변환하지 않음)
+
+        이상 탐지기(`Detector`)가 판단에 필요한 과거 이력을 가져올 때 사용된다.
 
         # Arguments
-        * `index_name` - 문서 개수를 조회할 Elasticsearch 인덱스명
+        * `mon_index_name` - 모니터링 데이터가 저장된 인덱스명(패턴)
+        * `index_name` - 조회 대상 인덱스명
+        * `start_timestamp` - 조회 시작 시간 (UTC)
+        * `end_timestamp` - 조회 종료 시간 (UTC)
 
         # Returns
-        * `usize` - 인덱스의 총 문서 개수
-        * `anyhow::Error` - ES 조회 실패, 응답 파싱 실패, 타입 변환 실패 시
+        * `Vec<AlertIndex>` - 시간순으로 정렬된 샘플 목록
+        * `anyhow::Error` - ES 조회 실패 또는 파싱 실패 시
     "#]
-    async fn get_index_doc_count(&self, index_name: &str) -> anyhow::Result<usize> {
-        let query: Value = json!({
-            "size": 0,                     /* 문서 본문은 받지 않음 */
-            "track_total_hits": true,      /* 정확한 총건수 계산 */
-            "query": { "match_all": {} }
-        });
+    async fn get_index_samples(
+        &self,
+        mon_index_name: &str,
+        index_name: &str,
+        start_timestamp: DateTime<Utc>,
+        end_timestamp: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<AlertIndex>> {
+        let mut samples: Vec<AlertIndex> = Vec::new();
+        let mut page_end_timestamp: DateTime<Utc> = end_timestamp;
 
-        let resp: Value = self.es_conn.get_search_query(&query, index_name).await?;
+        loop {
+            let page: Vec<AlertIndex> = self
+                .ts_repo
+                .read_samples(
+                    mon_index_name,
+                    index_name,
+                    start_timestamp,
+                    page_end_timestamp,
+                    SortOrder::Desc,
+                    MAX_SAMPLE_SIZE,
+                )
+                .await?;
 
-        let hits_total: &Value = &resp["hits"]["total"];
+            let page_len: usize = page.len();
+            let oldest_timestamp: Option<DateTime<Utc>> = page
+                .last()
+                .map(|sample| convert_utc_from_str(sample.timestamp()))
+                .transpose()?;
 
-        let value: usize = hits_total["value"]
-            .as_i64()
-            .ok_or_else(|| anyhow::anyhow!("[QueryServiceImpl->get_index_doc_count] invalid hits.total.value in search response"))?
-            .try_into()?;
+            samples.extend(page);
 
-        Ok(value)
+            match oldest_timestamp {
+                Some(oldest_timestamp)
+                    if page_len == MAX_SAMPLE_SIZE && oldest_timestamp > start_timestamp =>
+                {
+                    page_end_timestamp = oldest_timestamp - chrono::Duration::seconds(1);
+                }
+                _ => break,
+            }
+        }
+
+        samples.reverse();
+
+        Ok(samples)
     }
 
     #[doc = r#"
-        AlertIndex 구조체를 지정된 Elasticsearch 인덱스에 문서로 색인(저장)하는 함수.
+        지정된 시간 범위 내에 저장된 특정 인덱스의 최초/최종 샘플(`AlertIndex`)을 조회하는 함수.
+
+        1. `timestamp` 오름차순 정렬로 1건 조회하여 최초 샘플 획득
+        2. `timestamp` 내림차순 정렬로 1건 조회하여 최종 샘플 획득
+        3. 둘 중 하나라도 없으면 `None` 반환
 
-        1. `AlertIndex` 구조체를 JSON 형태로 직렬화
-        2. Elasticsearch의 인덱싱 API를 통해 지정된 인덱스에 문서 저장
-        3. 색인 실패 시 에러 로그를 기록하되 함수 실행은 계속 진행
-        4. 비동기 처리를 통해 ES와의 네트워크 통신 최적화
-        5. 에러가 발생해도 상위 호출자에게 성공으로 반환 (로그만 기록)
+        구간 시작/끝의 버킷별 문서 수(`breakdown`)를 비교할 때 사용된다.
 
         # Arguments
-        * `index_name` - 문서를 저장할 Elasticsearch 인덱스명
-        * `alert_index` - 색인할 AlertIndex 구조체 참조
+        * `mon_index_name` - 모니터링 데이터가 저장된 인덱스명(패턴)
+        * `index_name` - 조회 대상 인덱스명
+        * `start_timestamp` - 조회 시작 시간 (UTC)
+        * `end_timestamp` - 조회 종료 시간 (UTC)
 
         # Returns
-        * `()` - 항상 성공 반환 (에러는 로그로만 처리)
-        * `anyhow::Error` - 실제로는 반환되지 않음 (내부에서 처리)
+        * `Option<(AlertIndex, AlertIndex)>` - (최초 샘플, 최종 샘플)
+        * `anyhow::Error` - ES 조회 실패 또는 파싱 실패 시
     "#]
-    async fn post_log_index(
-        &self,
-        index_name: &str,
-        alert_index: &AlertIndex,
-    ) -> anyhow::Result<()> {
-        self.es_conn
-            .post_query_struct(alert_index, index_name)
-            .await
-            .unwrap_or_else(|e| {
-                error!("[QueryServiceImpl->post_log_index] {:?}", e);
-            });
-
-        Ok(())
-    }
-
-    #[doc = "AlarmLogHistoryIndex 구조체를 지정된 Elasticsearch 인덱스에 문서로 색인(저장)하는 함수."]
-    async fn post_alarm_history_index(
+    async fn get_boundary_samples(
         &self,
+        mon_index_name: &str,
         index_name: &str,
-        alarm_history_index: AlarmLogHistoryIndex,
-    ) -> anyhow::Result<()> {
-        self.es_conn
-            .post_query_struct(&alarm_history_index, index_name)
+        start_timestamp: DateTime<Utc>,
+        end_timestamp: DateTime<Utc>,
+    ) -> anyhow::Result<Option<(AlertIndex, AlertIndex)>> {
+        self.ts_repo
+            .read_first_last_samples(mon_index_name, index_name, start_timestamp, end_timestamp)
             .await
-            .unwrap_or_else(|e| {
-                error!("[QueryServiceImpl->post_alarm_history_index] {:?}", e);
-            });
-
-        Ok(())
     }
 
     #[doc = r#"
-        주어진 인덱스 설정(`IndexConfig`)과 기준 시각(`cur_timestamp_utc`)을 바탕으로
-        이전 agg_term_sec 동안의 문서 수(`cnt`) 변동을 계산한다.
+        주어진 인덱스 설정(`IndexConfig`)의 기대 활동 주기(`expected_activity_sec`) 동안
+        문서 수(`cnt`)가 전혀 변하지 않았는지(색인 정체) 검사한다.
 
-        1. `calc_time_window`를 통해 (기준시각 - agg_term_sec) ~ 기준시각 범위를 산출
-        2. 해당 구간에서 `cnt` 필드의 최대/최소 값을 Elasticsearch 집계(`max`, `min`)로 조회
-        3. 최소값을 기준으로 변화율(%)을 계산
-        4. 변화율이 허용치(`allowable_fluctuation_range`) 이상이면,
-        - 구간 내 데이터를 추가 조회하여 `AlertIndexFormat`으로 변환
-        - 이를 포함한 `LogIndexResult`를 반환
-        5. 변화율이 허용치 미만이면 `alert_info`가 None인 `LogIndexResult` 반환
+        1. (기준시각 - expected_activity_sec) ~ 기준시각 구간의 최초/최종 샘플을 조회
+           - 구간 내 샘플이 없으면 판단할 수 없으므로 정상으로 처리
+        2. 같은 구간의 최대/최소 cnt 값을 조회
+        3. 구간 시작 이전(기준시각 - 2 * expected_activity_sec ~ 구간 시작)의 마지막 샘플을 조회
+           - 구간 이전 샘플이 없으면 정체 기간을 증명할 수 없으므로 정상으로 처리
+        4. 구간 내 최소값 = 최대값 = 구간 이전 마지막 값이면 정체로 판단하여
+           `AlertRule::Stalled` 규칙이 담긴 `LogIndexResult` 반환
+           - 정체 기간(`stall_sec`)은 문서 수가 마지막으로 바뀐 이후 경과 시간 (`calc_stall_sec`)
+
+        날짜별 모니터링 인덱스를 넘나드는 구간을 조회해야 하므로 `mon_index_name`에는
+        `monitor_index_name*` 형태의 패턴을 전달한다.
 
         # Arguments
-        * `mon_index_name` - 모니터링 정보를 가지고 있는 인덱스 이름
-        * `index_config` - 모니터링 대상 인덱스 설정 (허용변동범위, 집계주기 포함)
-        * `cur_timestamp_utc` - 기준 시각 (UTC, "%Y-%m-%dT%H:%M:%SZ" 포맷)
+        * `mon_index_name` - 모니터링 정보를 가지고 있는 인덱스 이름(패턴)
+        * `index_config` - 모니터링 대상 인덱스 설정 (기대 활동 주기 포함)
+        * `cur_timestamp_utc` - 기준 시각 (UTC)
 
         # Returns
-        * `LogIndexResult` - 인덱스명, 정상여부, (조건 충족 시) AlertIndexFormat 포함
+        * `LogIndexResult` - 인덱스명, 정체여부, (정체 시) 구간 내 AlertIndex 목록 포함
         * `anyhow::Error` - ES 조회 실패 또는 파싱 실패 시
     "#]
-    async fn get_alert_infos_from_log_index(
+    async fn get_stall_infos_from_log_index(
         &self,
         mon_index_name: &str,
         index_config: &IndexConfig,
         cur_timestamp_utc: DateTime<Utc>,
     ) -> anyhow::Result<LogIndexResult> {
         let index_name: &str = index_config.index_name();
-        let allowable: f64 = *index_config.allowable_fluctuation_range();
-        let agg_term: i64 = *index_config.agg_term_sec(); /* The specific period for which monitoring will be conducted. */
 
-        let prev_timestamp_utc: DateTime<Utc> = calc_time_window(cur_timestamp_utc, agg_term);
+        let mut result: LogIndexResult = LogIndexResult::new(
+            index_name.to_string(),
+            false,
+            None,
+            0.0,
+            0,
+            FluctuationDirection::Flat,
+            Vec::new(),
+            None,
+            None,
+            None,
+        );
+
+        let expected_activity_sec: i64 = match index_config.expected_activity_sec() {
+            Some(expected_activity_sec) if *expected_activity_sec > 0 => *expected_activity_sec,
+            _ => return Ok(result),
+        };
 
-        /* `Maximum` and `Minimum` values of index documents within a specific period  */
-        let (min_val, max_val) = self
-            .fetch_min_max_values(
+        let window_start_utc: DateTime<Utc> =
+            calc_time_window(cur_timestamp_utc, expected_activity_sec);
+
+        /* 1. Samples within the expected activity window. */
+        let last_cnt: f64 = match self
+            .ts_repo
+            .read_first_last_samples(
                 mon_index_name,
                 index_name,
-                prev_timestamp_utc,
+                window_start_utc,
                 cur_timestamp_utc,
             )
-            .await?;
+            .await?
+        {
+            Some((_, last_sample)) => *last_sample.cnt() as f64,
+            None => return Ok(result),
+        };
 
-        let flunct_val: f64 = Self::calculate_fluctuation(min_val, max_val);
-        let flunct_val_one_decimal: f64 = (flunct_val * 100.0).round() / 100.0;
+        /* 2. `Maximum` and `Minimum` values within the window. */
+        let (min_val, max_val) = self
+            .ts_repo
+            .read_min_max_cnt(
+                mon_index_name,
+                index_name,
+                window_start_utc,
+                cur_timestamp_utc,
+            )
+            .await?
+            .unwrap_or((0.0, 0.0));
 
-        let mut result: LogIndexResult = LogIndexResult::new(
-            index_name.to_string(),
-            false,
-            None,
-            flunct_val_one_decimal,
-            0,
-        );
+        /* 3. The last sample before the window -> proves that the count has been flat for the entire window. */
+        let before_window: Option<AlertIndex> = self
+            .ts_repo
+            .read_samples(
+                mon_index_name,
+                index_name,
+                calc_time_window(window_start_utc, expected_activity_sec),
+                window_start_utc,
+                SortOrder::Desc,
+                1,
+            )
+            .await?
+            .into_iter()
+            .next();
 
-        if flunct_val >= allowable {
-            let sorts: Vec<SortSpec<'_>> = vec![SortSpec {
-                field: "timestamp",
-                order: SortOrder::Desc,
-            }];
+        let before_window_cnt: f64 = match before_window {
+            Some(sample) => *sample.cnt() as f64,
+            None => return Ok(result),
+        };
 
-            let alert_index_formats: Vec<AlertIndexFormat> = self
-                .fetch_index_cnt_infos(
+        if min_val == max_val && max_val == before_window_cnt {
+            let alert_indexes: Vec<AlertIndex> = self
+                .ts_repo
+                .read_samples(
                     mon_index_name,
                     index_name,
-                    prev_timestamp_utc,
+                    window_start_utc,
                     cur_timestamp_utc,
+                    SortOrder::Desc,
                     100,
-                    Some(sorts),
                 )
                 .await?;
 
-            let cur_index_cnt: usize = alert_index_formats
-                .first()
-                .map(|format| *format.alert_index().cnt())
-                .unwrap_or_else(|| {
-                    error!("[QueryServiceImpl->get_alert_infos_from_log_index] No alert index formats found");
-                    0
-                });
-
-            let alert_indexes: Vec<AlertIndex> = alert_index_formats
-                .into_iter()
-                .map(|format| format.alert_index)
-                .collect();
-
             result.set_alert_yn(true);
-            result.set_cur_cnt(cur_index_cnt);
+            result.set_cur_cnt(last_cnt as usize);
+            result.set_alert_rules(vec![AlertRule::Stalled]);
+            result.set_stall_sec(Some(
+                self.calc_stall_sec(
+                    mon_index_name,
+                    index_name,
+                    last_cnt as usize,
+                    cur_timestamp_utc,
+                )
+                .await?
+                .max(expected_activity_sec),
+            ));
             result.set_alert_index_format(Some(alert_indexes));
         }
 
@@ -681,31 +1617,15 @@ impl QueryService for QueryServiceImpl {
         start_timestamp: DateTime<Utc>,
         end_timestamp: DateTime<Utc>,
     ) -> anyhow::Result<Vec<AlertIndex>> {
-        let sorts: Vec<SortSpec<'_>> = vec![
-            SortSpec {
-                field: "timestamp",
-                order: SortOrder::Asc,
-            },
-            /* 필요 시 보조 정렬도 추가 가능 */
-            // SortSpec { field: "_score", order: SortOrder::Desc },
-        ];
-
-        let alert_index_formats: Vec<AlertIndexFormat> = self
-            .fetch_index_cnt_infos(
-                mon_index_name,
-                index_name,
-                start_timestamp,
-                end_timestamp,
-                10000,
-                Some(sorts),
-            )
+        /* 리포트 기간이 길어 샘플이 MAX_SAMPLE_SIZE 를 넘어도 잘리지 않도록 페이지 단위로 모두 조회 */
+        let alert_indexes: Vec<AlertIndex> = self
+            .get_index_samples(mon_index_name, index_name, start_timestamp, end_timestamp)
             .await?;
 
         /***** !![important] Converts UTC time to Korean time and saves it. !! ******/
-        let report_indexes: Vec<AlertIndex> = alert_index_formats
+        let report_indexes: Vec<AlertIndex> = alert_indexes
             .into_iter()
-            .map(|x| {
-                let mut alert_index: AlertIndex = x.alert_index;
+            .map(|mut alert_index| {
                 let utc_to_local: String = calc_struct_to_strkor(alert_index.timestamp())
                     .unwrap_or(alert_index.timestamp().to_string());
                 alert_index.set_timestamp(utc_to_local);
@@ -717,6 +1637,31 @@ impl QueryService for QueryServiceImpl {
         Ok(report_indexes)
     }
 
+    #[doc = r#"
+        지정된 시간 범위 내에서 인덱스별로 가장 최근에 기록된 알람 이력을 알람 이력 저장소에서 조회하는 함수.
+
+        프로그램 재시작 시 인덱스별 알람 진행 상태를 복원하는 데 사용
+
+        # Arguments
+        * `alarm_index_name` - 알람 이력이 저장된 인덱스명
+        * `start_time` - 조회 시작 시간 (UTC)
+        * `end_time` - 조회 종료 시간 (UTC)
+
+        # Returns
+        * `Vec<AlarmLogHistoryIndex>` - 인덱스별 최신 알람 이력 목록
+        * `anyhow::Error` - 저장소 조회 실패 또는 파싱 실패 시
+    "#]
+    async fn get_latest_alarm_histories(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<AlarmLogHistoryIndex>> {
+        self.alarm_repo
+            .read_latest_alarm_histories(alarm_index_name, start_time, end_time)
+            .await
+    }
+
     #[doc = r#"
         지정된 시간 범위 내에서 모든 인덱스의 최초 시점 카운트 정보를 조회하는 함수.
 
@@ -741,16 +1686,9 @@ impl QueryService for QueryServiceImpl {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
     ) -> anyhow::Result<Vec<IndexCountAggResult>> {
-        let sorts: Vec<SortSpec<'_>> = vec![SortSpec {
-            field: "timestamp",
-            order: SortOrder::Asc,
-        }];
-
-        let results: Vec<IndexCountAggResult> = self
-            .get_all_indicies_count_by_time(mon_index_name, start_time, end_time, Some(sorts))
-            .await?;
-
-        Ok(results)
+        self.ts_repo
+            .read_boundary_cnts(mon_index_name, start_time, end_time, SortOrder::Asc)
+            .await
     }
 
     async fn get_end_time_all_indicies_count(
@@ -759,16 +1697,9 @@ impl QueryService for QueryServiceImpl {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
     ) -> anyhow::Result<Vec<IndexCountAggResult>> {
-        let sorts: Vec<SortSpec<'_>> = vec![SortSpec {
-            field: "timestamp",
-            order: SortOrder::Desc,
-        }];
-
-        let results: Vec<IndexCountAggResult> = self
-            .get_all_indicies_count_by_time(mon_index_name, start_time, end_time, Some(sorts))
-            .await?;
-
-        Ok(results)
+        self.ts_repo
+            .read_boundary_cnts(mon_index_name, start_time, end_time, SortOrder::Desc)
+            .await
     }
 
     #[doc = "Function that aggregation the document count per index and returns the total number of distinct indices."]
@@ -778,44 +1709,9 @@ impl QueryService for QueryServiceImpl {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
     ) -> anyhow::Result<AlarmReportInfos> {
-        let search_query: Value = json!({
-        "size": 0,
-        "track_total_hits": false,
-        "query": {
-            "range": {
-                "timestamp": {
-                    "gte": convert_date_to_str(start_time, Utc),
-                    "lte": convert_date_to_str(end_time, Utc)
-                }
-            }
-        },
-        "aggs": {
-            "by_index_name": {
-                "terms": {
-                        "field": "index_name.keyword",
-                        "size": 10000
-                    }
-                },
-                "distinct_index_name_count": {
-                    "cardinality": {
-                        "field": "index_name.keyword"
-                    }
-                }
-            }
-        });
-
-        let response_body: Value = self
-            .es_conn
-            .get_search_query(&search_query, alarm_index_name)
-            .await?;
-
-        let buckets: Vec<IndexNameCount> =
-            self.get_aggregation_result_vec(&response_body, "by_index_name")?;
-
-        let distinct_count_u64: u64 =
-            self.get_aggregation_metric_value::<u64>(&response_body, "distinct_index_name_count")?;
-
-        Ok(AlarmReportInfos::new(buckets, distinct_count_u64))
+        self.alarm_repo
+            .read_alarm_index_counts(alarm_index_name, start_time, end_time)
+            .await
     }
 
     // #[doc = "Function that returns the most recent index tracking information."]
@@ -858,42 +1754,16 @@ impl QueryService for QueryServiceImpl {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
     ) -> anyhow::Result<(f64, f64)> {
-        let search_query: Value = json!({
-            "size": 1,
-            "track_total_hits": false,
-            "query": {
-                "bool": {
-                    "must": [
-                        {
-                            "range": {
-                                "timestamp": {
-                                    "gte": convert_date_to_str(start_time, Utc),
-                                    "lte": convert_date_to_str(end_time, Utc)
-                                }
-                            }
-                        },
-                        {
-                            "term": {
-                                "index_name.keyword": index_name
-                            }
-                        }
-                    ]
-                }
-            },
-            "aggs": {
-                "cnt_max": { "max": { "field": "cnt" } },
-                "cnt_min": { "min": { "field": "cnt" } }
-            }
-        });
-
-        let response_body: Value = self
-            .es_conn
-            .get_search_query(&search_query, mon_index_name)
-            .await?;
-
-        let cnt_max: f64 = self.get_aggregation_metric_value::<f64>(&response_body, "cnt_max")?;
-        let cnt_min: f64 = self.get_aggregation_metric_value::<f64>(&response_body, "cnt_min")?;
-        
-        Ok((cnt_min, cnt_max))
+        self.ts_repo
+            .read_min_max_cnt(mon_index_name, index_name, start_time, end_time)
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "[QueryServiceImpl->fetch_max_min_doc_count_value] No samples for '{}' between {} and {}",
+                    index_name,
+                    start_time,
+                    end_time
+                )
+            })
     }
 }
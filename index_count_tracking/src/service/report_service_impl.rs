@@ -4,19 +4,22 @@ use crate::model::index::{index_config::*, index_list_config::*};
 use crate::traits::service_traits::{
     chart_service::*, notification_service::*, query_service::*, report_service::*,
 };
-use crate::utils_modules::time_utils::*;
+use crate::utils_modules::{stats_utils::*, time_utils::*};
 
 use crate::model::{configs::total_config::*, index::alert_index::*, report::report_config::*};
 
 use crate::dto::{
     alarm::{
         alarm_image_info::*, alarm_index_detail_info::*, alarm_index_diff_detail_infos::*,
-        alarm_report_infos::*,
+        alarm_report_infos::*, daily_report_contents::*,
     },
+    breakdown_change::*,
     index_count_agg_result::*,
+    index_pair_sample::*,
+    pair_consistency_info::*,
 };
 
-use crate::enums::report_type::*;
+use crate::enums::{report_type::*, target_mode::*};
 
 #[derive(Debug, new)]
 pub struct ReportServiceImpl<Q: QueryService, C: ChartService, N: NotificationService> {
@@ -31,6 +34,59 @@ where
     C: ChartService,
     N: NotificationService,
 {
+    #[doc = "Function that expands `expand` mode targets and named filters into the monitor series recorded during the report period."]
+    async fn expand_report_targets(
+        &self,
+        target_index_info_list: &IndexListConfig,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> IndexListConfig {
+        let mut report_configs: Vec<IndexConfig> = Vec::new();
+
+        for index_config in target_index_info_list.index() {
+            if *index_config.target_mode() == TargetMode::Sum {
+                report_configs.extend(
+                    index_config
+                        .expand_filters()
+                        .iter()
+                        .map(|series_config| series_config.with_cluster_series()),
+                );
+                continue;
+            }
+
+            /* Series of other clusters are recorded under `{cluster}:{target}`. */
+            let series_config: IndexConfig = index_config.with_cluster_series();
+
+            match self
+                .query_service
+                .get_expanded_series_names(
+                    mon_index_name,
+                    series_config.index_name(),
+                    start_time,
+                    end_time,
+                )
+                .await
+            {
+                /* The recorded series already include the named filter series. */
+                Ok(series_names) => report_configs.extend(
+                    series_names
+                        .iter()
+                        .map(|series_name| series_config.expand_to(series_name).as_single_series()),
+                ),
+                Err(e) => {
+                    error!(
+                        "[ReportServiceImpl->expand_report_targets] Failed to expand {}: {:?}",
+                        index_config.index_name(),
+                        e
+                    );
+                }
+            }
+        }
+
+        IndexListConfig::new(report_configs)
+    }
+
     #[doc = "Function that organizes index change information into a report format and then sends a notification to the administrator."]
     async fn report_index_cnt_task(
         &self,
@@ -50,6 +106,16 @@ where
         let mon_index_alias: &str = &format!("{}*", mon_index_name);
         let alarm_index_alias: &str = &format!("{}*", alarm_index_name);
 
+        /* Patterns/aliases in `expand` mode are reported per concrete index. */
+        let target_index_info_list: &IndexListConfig = &self
+            .expand_report_targets(
+                target_index_info_list,
+                mon_index_alias,
+                prev_hour_utc_time,
+                utc_from_local,
+            )
+            .await;
+
         for index in target_index_info_list.index() {
             /* Gecerate Report graph ,*/
             let graph_path: PathBuf = match self
@@ -75,6 +141,30 @@ where
                 AlarmImageInfo::new(index.index_name().to_string(), graph_path);
 
             alarm_image_infos.push(alarm_image_info);
+
+            /* Ingestion-rate graph for indices with a timestamp field */
+            if index.timestamp_field().is_some() {
+                match self
+                    .generate_ingest_rate_graph(
+                        mon_index_alias,
+                        index.index_name(),
+                        local_time,
+                        prev_local_time,
+                        utc_from_local,
+                        prev_hour_utc_time,
+                    )
+                    .await
+                {
+                    Ok(Some(ingest_graph_path)) => {
+                        alarm_image_infos.push(AlarmImageInfo::new(
+                            format!("{} (ingest rate)", index.index_name()),
+                            ingest_graph_path,
+                        ));
+                    }
+                    Ok(None) => (),
+                    Err(e) => error!("{:?}", e),
+                }
+            }
         }
 
         /* Generate Report HTML */
@@ -109,6 +199,24 @@ where
             .get_index_name_aggregations(alarm_index_alias, prev_hour_utc_time, utc_from_local)
             .await?;
 
+        /* Replication consistency of each primary/replica pair during the report period. */
+        let pair_consistency_infos: Vec<PairConsistencyInfo> = self
+            .query_service
+            .get_pair_consistency_infos(mon_index_alias, prev_hour_utc_time, utc_from_local)
+            .await?;
+
+        /* Pair alarms are recorded under the pair name. */
+        let pair_alarm_cnts: HashMap<String, u64> = alarm_report_infos
+            .buckets()
+            .iter()
+            .filter(|bucket| {
+                pair_consistency_infos
+                    .iter()
+                    .any(|pair_info| pair_info.latest().pair_name() == &bucket.name)
+            })
+            .map(|bucket| (bucket.name.clone(), bucket.count))
+            .collect();
+
         /* Number of alarm occurrence indices. */
         let alaram_index_cnt: u64 = alarm_report_infos.distinct_count_u64;
 
@@ -116,13 +224,23 @@ where
         let total_alarm_cnt: u64 = alarm_report_infos.buckets().iter().map(|x| x.count).sum();
 
         /* Detailed information by index */
-        let alarm_index_details: Vec<AlarmIndexDetailInfo> = self.generate_alarm_index_details(
+        let mut alarm_index_details: Vec<AlarmIndexDetailInfo> = self.generate_alarm_index_details(
             target_index_info_list.index(),
             alarm_report_infos,
             start_time_all_index_info,
             end_time_all_index_info,
         );
 
+        /* Size growth and the buckets of the breakdown field that contributed most to the change */
+        self.fill_boundary_sample_details(
+            &mut alarm_index_details,
+            target_index_info_list.index(),
+            mon_index_alias,
+            prev_hour_utc_time,
+            utc_from_local,
+        )
+        .await;
+
         /* Detailed information by index - Maximum change information */
         let alarm_index_diff_detilas: Vec<AlarmIndexDiffDetailInfo> = self
             .generate_alram_index_diff_details(
@@ -133,18 +251,24 @@ where
             )
             .await?;
 
-        let html_content: String = self.generate_daily_report_html(
-            target_index_info_list,
-            prev_local_time,
-            local_time,
-            report_type,
+        let report_contents: DailyReportContents = DailyReportContents {
             start_time_all_index_cnt,
             end_time_all_index_cnt,
             total_difference,
             alaram_index_cnt,
             total_alarm_cnt,
             alarm_index_details,
-            alarm_index_diff_detilas,
+            alarm_index_diff_details: alarm_index_diff_detilas,
+            pair_consistency_infos,
+            pair_alarm_cnts,
+        };
+
+        let html_content: String = self.generate_daily_report_html(
+            target_index_info_list,
+            prev_local_time,
+            local_time,
+            report_type,
+            &report_contents,
         )?;
 
         /* Send the report via email. */
@@ -202,6 +326,59 @@ where
         Ok(output_path)
     }
 
+    #[doc = "Function that draws the number of newly ingested documents per interval recorded in the monitor index. Returns `None` if no sample has an ingestion rate."]
+    async fn generate_ingest_rate_graph(
+        &self,
+        mon_index_name: &str,
+        index_name: &str,
+        local_time: DateTime<Local>,
+        prev_local_time: DateTime<Local>,
+        utc_time: DateTime<Utc>,
+        prev_utc_time: DateTime<Utc>,
+    ) -> anyhow::Result<Option<PathBuf>> {
+        let hour: i64 = (utc_time - prev_utc_time).num_hours();
+
+        let index_cnt_history: Vec<AlertIndex> = self
+            .query_service
+            .get_report_infos_from_log_index(mon_index_name, index_name, prev_utc_time, utc_time)
+            .await?;
+
+        let mut x_label: Vec<String> = Vec::new();
+        let mut y_label: Vec<i64> = Vec::new();
+
+        for index_info in index_cnt_history {
+            if let Some(ingest_cnt) = index_info.ingest_cnt {
+                x_label.push(index_info.timestamp);
+                y_label.push(ingest_cnt as i64);
+            }
+        }
+
+        if x_label.is_empty() {
+            return Ok(None);
+        }
+
+        let output_path: PathBuf =
+            PathBuf::from(format!("./pics/{}_ingest_chart_{}.png", hour, index_name));
+
+        self.chart_service
+            .generate_line_chart(
+                &format!(
+                    "[{} ~ {}] {} - ingest rate",
+                    convert_date_to_str(prev_local_time, Local),
+                    convert_date_to_str(local_time, Local),
+                    index_name
+                ),
+                x_label,
+                y_label,
+                &output_path,
+                "timestamp",
+                "new documents / interval",
+            )
+            .await?;
+
+        Ok(Some(output_path))
+    }
+
     fn calc_start_end_index_cnt(
         &self,
         start_time_all_index_cnt: &[IndexCountAggResult],
@@ -271,7 +448,7 @@ where
                 }
             };
 
-            let alarm_index_detail: AlarmIndexDetailInfo = AlarmIndexDetailInfo::new(
+            let mut alarm_index_detail: AlarmIndexDetailInfo = AlarmIndexDetailInfo::new(
                 index_name.to_string(),
                 filtered_start_cnt,
                 filtered_end_cnt,
@@ -279,6 +456,7 @@ where
                 difference_percent,
                 filtered_alarm_cnt,
             );
+            alarm_index_detail.cluster_name = index.resolve_cluster_name().to_string();
 
             alarm_index_details.push(alarm_index_detail);
         } //for
@@ -286,6 +464,65 @@ where
         alarm_index_details
     }
 
+    #[doc = "Function that fills in the store size growth and the buckets of `breakdown_field` that changed the most, using the first and last samples of the report period."]
+    async fn fill_boundary_sample_details(
+        &self,
+        alarm_index_details: &mut [AlarmIndexDetailInfo],
+        index_list: &[IndexConfig],
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) {
+        for alarm_index_detail in alarm_index_details.iter_mut() {
+            let has_breakdown: bool = index_list.iter().any(|index| {
+                index.index_name() == alarm_index_detail.index_name()
+                    && index.breakdown_field().is_some()
+            });
+
+            let boundary_samples: Option<(AlertIndex, AlertIndex)> = match self
+                .query_service
+                .get_boundary_samples(
+                    mon_index_name,
+                    alarm_index_detail.index_name(),
+                    start_time,
+                    end_time,
+                )
+                .await
+            {
+                Ok(boundary_samples) => boundary_samples,
+                Err(e) => {
+                    error!("[ReportServiceImpl->fill_boundary_sample_details] {:?}", e);
+                    continue;
+                }
+            };
+
+            let Some((first, last)) = boundary_samples else {
+                continue;
+            };
+
+            alarm_index_detail.start_store_size = first
+                .index_stats()
+                .as_ref()
+                .map(|stats| *stats.store_size_bytes());
+            alarm_index_detail.end_store_size = last
+                .index_stats()
+                .as_ref()
+                .map(|stats| *stats.store_size_bytes());
+            alarm_index_detail.end_source_row_cnt = *last.source_row_cnt();
+
+            if has_breakdown
+                && let (Some(first_buckets), Some(last_buckets)) =
+                    (first.breakdown(), last.breakdown())
+            {
+                alarm_index_detail.top_buckets = BreakdownChange::top_changes(
+                    first_buckets,
+                    last_buckets,
+                    TOP_BREAKDOWN_CHANGE_CNT,
+                );
+            }
+        }
+    }
+
     async fn generate_alram_index_diff_details(
         &self,
         index_list: &Vec<IndexConfig>,
@@ -313,13 +550,14 @@ where
             let diff_cnt: f64 = (max_value - min_value).abs();
             let diff_per: f64 = (diff_cnt / divisor ) * 100.0;
 
-            let alarm_index_diff_info: AlarmIndexDiffDetailInfo = AlarmIndexDiffDetailInfo::new(
+            let mut alarm_index_diff_info: AlarmIndexDiffDetailInfo = AlarmIndexDiffDetailInfo::new(
                 index_name.to_string(),
                 max_value as u64,
                 min_value as u64,
                 diff_cnt as u64,
                 diff_per,
             );
+            alarm_index_diff_info.cluster_name = index.resolve_cluster_name().to_string();
 
             alarm_index_diff_details.push(alarm_index_diff_info);
         }
@@ -334,13 +572,7 @@ where
         start_local_time: DateTime<Local>,
         end_local_time: DateTime<Local>,
         report_type: ReportType,
-        start_time_all_index_cnt: usize,
-        end_time_all_index_cnt: usize,
-        total_difference: usize,
-        alaram_index_cnt: u64,
-        total_alarm_cnt: u64,
-        alarm_index_details: Vec<AlarmIndexDetailInfo>,
-        alarm_index_diff_details: Vec<AlarmIndexDiffDetailInfo>,
+        report_contents: &DailyReportContents,
     ) -> anyhow::Result<String> {
         /* HTML 템플릿 파일 읽기 */
         let template_content: String =
@@ -378,32 +610,49 @@ where
             )
             .replace(
                 "{{TOTAL_DOCS_START}}",
-                &start_time_all_index_cnt.to_formatted_string(&Locale::en),
+                &report_contents
+                    .start_time_all_index_cnt
+                    .to_formatted_string(&Locale::en),
             )
             .replace(
                 "{{TOTAL_DOCS_END}}",
-                &end_time_all_index_cnt.to_formatted_string(&Locale::en),
+                &report_contents
+                    .end_time_all_index_cnt
+                    .to_formatted_string(&Locale::en),
             )
             .replace(
                 "{{TOTAL_CHANGE}}",
-                &total_difference.to_formatted_string(&Locale::en),
+                &report_contents
+                    .total_difference
+                    .to_formatted_string(&Locale::en),
             )
             .replace("{{CHANGE_STYLE}}", "")
             .replace(
                 "{{INDICES_WITH_ALERTS}}",
-                &alaram_index_cnt.to_formatted_string(&Locale::en),
+                &report_contents
+                    .alaram_index_cnt
+                    .to_formatted_string(&Locale::en),
             )
             .replace(
                 "{{TOTAL_ALERTS}}",
-                &total_alarm_cnt.to_formatted_string(&Locale::en),
+                &report_contents
+                    .total_alarm_cnt
+                    .to_formatted_string(&Locale::en),
             )
             .replace(
                 "{{INDEX_ROWS}}",
-                &self.generate_index_detail_rows(&alarm_index_details),
+                &self.generate_index_detail_rows(report_contents.alarm_index_details()),
             )
             .replace(
                 "{{INDEX_DIFF_ROWS}}",
-                &self.generate_index_diff_detail_rows(&alarm_index_diff_details),
+                &self.generate_index_diff_detail_rows(report_contents.alarm_index_diff_details()),
+            )
+            .replace(
+                "{{PAIR_ROWS}}",
+                &self.generate_pair_consistency_rows(
+                    report_contents.pair_consistency_infos(),
+                    report_contents.pair_alarm_cnts(),
+                ),
             );
 
         Ok(html_content)
@@ -411,7 +660,7 @@ where
 
     #[doc = "인덱스별 상세 정보 테이블 행 생성"]
     fn generate_index_detail_rows(&self, alarm_index_details: &[AlarmIndexDetailInfo]) -> String {
-        self.generate_table_rows(alarm_index_details, |alarm_index| {
+        self.generate_cluster_section_rows(alarm_index_details, 10, AlarmIndexDetailInfo::cluster_name, |alarm_index| {
 
             format!(
                 r#"<tr>
@@ -421,22 +670,82 @@ where
                     <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: #fff;">{}</td>
                     <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: #fff;">{}</td>
                     <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: #fff;">{}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: #fff;">{}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: #fff;">{}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: #fff;">{}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: #fff;">{}</td>
                 </tr>"#,
                 alarm_index.index_name(),
                 alarm_index.start_index_cnt.to_formatted_string(&Locale::en),
                 alarm_index.end_index_cnt.to_formatted_string(&Locale::en),
                 alarm_index.difference.to_formatted_string(&Locale::en),
                 format!("{:.2}%", alarm_index.difference_percent),
+                alarm_index
+                    .end_source_row_cnt
+                    .map(|source_row_cnt| source_row_cnt.to_formatted_string(&Locale::en))
+                    .unwrap_or_else(|| String::from("-")),
+                Self::format_source_drift(alarm_index.end_source_row_cnt, alarm_index.end_index_cnt),
+                Self::format_store_size_growth(
+                    alarm_index.start_store_size,
+                    alarm_index.end_store_size
+                ),
                 alarm_index.alarm_cnt.to_formatted_string(&Locale::en),
+                if alarm_index.top_buckets.is_empty() {
+                    String::from("-")
+                } else {
+                    join_breakdown_changes(&alarm_index.top_buckets, "<br>")
+                },
             )
         })
     }
 
+    #[doc = "테이블 대비 차이 컬럼 - `인덱스 문서 수 - 테이블 행 수 (차이율)` 형태, 원본 행 수가 없으면 '-'"]
+    fn format_source_drift(source_row_cnt: Option<usize>, index_cnt: usize) -> String {
+        let Some(source_row_cnt) = source_row_cnt else {
+            return String::from("-");
+        };
+
+        let diff_cnt: usize = source_row_cnt.abs_diff(index_cnt);
+        let diff_percent: f64 = if source_row_cnt == 0 {
+            0.0
+        } else {
+            diff_cnt as f64 / source_row_cnt as f64 * 100.0
+        };
+
+        format!(
+            "{}{} ({:.2}%)",
+            if index_cnt >= source_row_cnt {
+                "+"
+            } else {
+                "-"
+            },
+            diff_cnt.to_formatted_string(&Locale::en),
+            diff_percent
+        )
+    }
+
+    #[doc = "저장 용량 변동 컬럼 - `시작 용량 → 종료 용량 (증감)` 형태, 수집된 값이 없으면 '-'"]
+    fn format_store_size_growth(
+        start_store_size: Option<u64>,
+        end_store_size: Option<u64>,
+    ) -> String {
+        match (start_store_size, end_store_size) {
+            (Some(start_size), Some(end_size)) => format!(
+                "{} → {} ({}{})",
+                format_bytes(start_size),
+                format_bytes(end_size),
+                if end_size >= start_size { "+" } else { "-" },
+                format_bytes(start_size.abs_diff(end_size))
+            ),
+            _ => String::from("-"),
+        }
+    }
+
     fn generate_index_diff_detail_rows(
         &self,
         alarm_index_diff_details: &[AlarmIndexDiffDetailInfo],
     ) -> String {
-        self.generate_table_rows(alarm_index_diff_details, |alarm_diff_info| {
+        self.generate_cluster_section_rows(alarm_index_diff_details, 5, AlarmIndexDiffDetailInfo::cluster_name, |alarm_diff_info| {
             format!(
                 r#"<tr>
                     <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: #fff;">{}</td>
@@ -454,12 +763,97 @@ where
         })
     }
 
-    #[doc = "Helper function for creating common table rows"]
-    fn generate_table_rows<T, F>(&self, data: &[T], row_formatter: F) -> String
+    #[doc = "복제 정합성 테이블 행 생성 - 페어가 없으면 '-' 한 행"]
+    fn generate_pair_consistency_rows(
+        &self,
+        pair_consistency_infos: &[PairConsistencyInfo],
+        pair_alarm_cnts: &HashMap<String, u64>,
+    ) -> String {
+        if pair_consistency_infos.is_empty() {
+            return String::from(
+                r#"<tr>
+                    <td colspan="8" style="border: 1px solid #ddd; padding: 12px; text-align: center; background-color: #fff;">-</td>
+                </tr>"#,
+            );
+        }
+
+        pair_consistency_infos
+            .iter()
+            .map(|pair_info| {
+                let latest: &IndexPairSample = pair_info.latest();
+                let row_color: &str = if *latest.diverged() { "#fdecea" } else { "#fff" };
+
+                format!(
+                    r#"<tr>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: {0};">{1}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: {0};">{2}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: {0};">{3}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: {0};">{4}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: {0};">{5:.2}%</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: {0};">{6}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: {0};">{7} / {8}</td>
+                    <td style="border: 1px solid #ddd; padding: 12px; text-align: left; background-color: {0};">{9}</td>
+                </tr>"#,
+                    row_color,
+                    latest.pair_name(),
+                    latest.primary_cnt().to_formatted_string(&Locale::en),
+                    latest.replica_cnt().to_formatted_string(&Locale::en),
+                    latest.diff_cnt().to_formatted_string(&Locale::en),
+                    latest.diff_percent(),
+                    pair_info.max_diff_cnt().to_formatted_string(&Locale::en),
+                    pair_info.diverged_cnt(),
+                    pair_info.sample_cnt(),
+                    pair_alarm_cnts
+                        .get(latest.pair_name())
+                        .copied()
+                        .unwrap_or(0)
+                        .to_formatted_string(&Locale::en)
+                )
+            })
+            .collect::<String>()
+    }
+
+    #[doc = "Helper function for creating table rows grouped into one section per cluster (in order of first appearance)"]
+    fn generate_cluster_section_rows<T, K, F>(
+        &self,
+        data: &[T],
+        colspan: usize,
+        cluster_of: K,
+        row_formatter: F,
+    ) -> String
     where
+        K: Fn(&T) -> &String,
         F: Fn(&T) -> String,
     {
-        data.iter().map(row_formatter).collect::<String>()
+        let mut cluster_names: Vec<&String> = Vec::new();
+
+        for item in data {
+            if !cluster_names.contains(&cluster_of(item)) {
+                cluster_names.push(cluster_of(item));
+            }
+        }
+
+        cluster_names
+            .into_iter()
+            .map(|cluster_name| {
+                let cluster_rows: Vec<&T> = data
+                    .iter()
+                    .filter(|item| cluster_of(item) == cluster_name)
+                    .collect();
+
+                format!(
+                    r#"<tr>
+                    <td colspan="{}" style="border: 1px solid #ddd; padding: 10px 12px; text-align: left; background-color: #e9ecef; font-weight: bold;">🖥️ {} ({}개)</td>
+                </tr>"#,
+                    colspan,
+                    cluster_name,
+                    cluster_rows.len()
+                ) + &cluster_rows
+                    .into_iter()
+                    .map(&row_formatter)
+                    .collect::<String>()
+            })
+            .collect::<String>()
     }
 }
 
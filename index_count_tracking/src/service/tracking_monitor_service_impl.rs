@@ -1,91 +1,1413 @@
 use crate::common::*;
 
+use crate::traits::repository_traits::sqlserver_repository::*;
 use crate::traits::service_traits::{
     notification_service::*, query_service::*, tracking_monitor_service::*,
 };
 
+use crate::repository::sqlserver_repository_impl::*;
+
+use crate::model::alarm::{alert_state::*, silence_config::*};
 use crate::model::configs::total_config::*;
-use crate::model::index::{alert_index::*, index_list_config::*};
+use crate::model::index::{
+    alert_index::*, breakdown_bucket::*, index_config::*, index_list_config::*,
+    index_pair_config::*, index_schedule::*, reconcile_config::*,
+};
+
+use crate::enums::{
+    alert_rule::*, alert_severity::*, alert_status::*, fluctuation_direction::*,
+    storage_backend::*, target_mode::*,
+};
 
 use crate::dto::alarm::alarm_log_history_index::*;
-use crate::dto::log_index_result::*;
+use crate::dto::{
+    breakdown_change::*, comparison_window::*, evaluation_failure::*, incident_recovery::*,
+    index_pair_sample::*, index_stats::*, log_index_result::*,
+};
+
+use crate::utils_modules::{io_utils::*, time_utils::*};
 
-use crate::utils_modules::time_utils::*;
+use crate::env_configuration::env_config::*;
+
+use crate::detector::{detector_common::*, detector_factory::*};
+use crate::traits::detector_traits::detector::*;
 
 #[derive(Debug, new)]
 pub struct TrackingServiceImpl<Q: QueryService, N: NotificationService> {
-    target_query_service: Q,
+    /* Query service per target cluster (keyed by `elastic_cluster_name`). */
+    target_query_services: HashMap<String, Q>,
     mon_query_service: Q,
     notification_service: Arc<N>,
+    /* Alert state per index (deduplication / cooldown / re-notification). */
+    #[new(default)]
+    alert_states: Mutex<HashMap<String, AlertState>>,
+    /* Latest evaluation failure per index - indices run on different cadences, so failures are kept until the index is evaluated again. */
+    #[new(default)]
+    evaluation_failures: Mutex<HashMap<String, EvaluationFailure>>,
+    /* Divergence state per primary/replica pair (keyed by pair name). */
+    #[new(default)]
+    pair_states: Mutex<HashMap<String, AlertState>>,
+    /* Indices whose evaluation failed at the last degraded notification and when it was sent. */
+    #[new(default)]
+    degraded_state: Mutex<Option<(Vec<String>, DateTime<Utc>)>>,
 }
 
+/* How far back the alarm history is read to restore alert states on startup. */
+const ALERT_STATE_RESTORE_SEC: i64 = 86400;
+
+/* Number of indices evaluated at once when `system.eval_concurrency` is not set. */
+const DEFAULT_EVAL_CONCURRENCY: usize = 8;
+
+/* How often expired daily monitoring indices are compacted into hourly rollups. */
+const COMPACTION_TICK_SEC: u64 = 3600;
+
+/* How often index patterns/aliases are re-resolved into concrete indices by the scheduler. */
+const TARGET_RESOLVE_TICK_SEC: i64 = 300;
+
 impl<Q, N> TrackingServiceImpl<Q, N>
 where
     Q: QueryService + Sync + Send,
     N: NotificationService + Sync + Send,
 {
-    #[doc = "Function that indexes the number if index documents"]
+    #[doc = "Function that returns the query service of the target cluster the index belongs to."]
+    fn get_target_query_service(&self, index_config: &IndexConfig) -> anyhow::Result<&Q> {
+        let cluster_name: &str = index_config.resolve_cluster_name();
+
+        self.target_query_services.get(cluster_name).ok_or_else(|| {
+            anyhow!(
+                "[TrackingServiceImpl->get_target_query_service] Unknown cluster '{}' for {}",
+                cluster_name,
+                index_config.index_name()
+            )
+        })
+    }
+
+    #[doc = "Function that expands `expand` mode targets (glob patterns / aliases / data streams) and named filters into one config per monitor series."]
+    async fn resolve_target_index_list(&self, index_list: &IndexListConfig) -> IndexListConfig {
+        let mut resolved_configs: Vec<IndexConfig> = Vec::new();
+
+        for index_config in index_list.index() {
+            let target_query_service: &Q = match self.get_target_query_service(index_config) {
+                Ok(target_query_service) => target_query_service,
+                Err(e) => {
+                    error!("[TrackingServiceImpl->resolve_target_index_list] {:?}", e);
+                    continue;
+                }
+            };
+
+            if *index_config.target_mode() == TargetMode::Sum {
+                resolved_configs.extend(
+                    index_config
+                        .expand_filters()
+                        .iter()
+                        .map(|series_config| series_config.with_cluster_series()),
+                );
+                continue;
+            }
+
+            match target_query_service
+                .resolve_concrete_indices(index_config.index_name())
+                .await
+            {
+                Ok(concrete_indices) => {
+                    resolved_configs.extend(concrete_indices.iter().flat_map(|concrete_index| {
+                        index_config
+                            .expand_to(concrete_index)
+                            .expand_filters()
+                            .iter()
+                            .map(|series_config| series_config.with_cluster_series())
+                            .collect::<Vec<IndexConfig>>()
+                    }))
+                }
+                Err(e) => {
+                    error!(
+                        "[TrackingServiceImpl->resolve_target_index_list] Failed to resolve {}: {:?}",
+                        index_config.index_name(),
+                        e
+                    );
+                }
+            }
+        }
+
+        IndexListConfig::new(resolved_configs)
+    }
+
+    #[doc = "Function that indexes the number if index documents and returns the collected count per index."]
     async fn save_index_cnt_infos(
         &self,
         index_list: &IndexListConfig,
         mon_index_name: &str,
-    ) -> anyhow::Result<()> {
+        silence_list: &SilenceListConfig,
+    ) -> anyhow::Result<HashMap<String, usize>> {
+        let mut index_cnt_map: HashMap<String, usize> = HashMap::new();
         let cur_utc_time: DateTime<Utc> = Utc::now();
-        let save_mon_index: &str = &format!("{}{}", mon_index_name, convert_data_to_str_index(cur_utc_time, Utc));
+        let save_mon_index: &str = &format!(
+            "{}{}",
+            mon_index_name,
+            convert_data_to_str_index(cur_utc_time, Utc)
+        );
+
+        /* Indices are grouped by cluster so that each cluster is still sampled with a constant number of round trips. */
+        let mut cluster_configs: HashMap<&str, Vec<IndexConfig>> = HashMap::new();
 
         for index_config in index_list.index() {
-            let index_name: &str = index_config.index_name();
+            cluster_configs
+                .entry(index_config.resolve_cluster_name())
+                .or_default()
+                .push(index_config.clone());
+        }
 
-            /* Number of indexed documents. */
-            let doc_cnt: usize = match self
-                .target_query_service
-                .get_index_doc_count(index_config.index_name())
-                .await
-            {
-                Ok(doc_cnt) => doc_cnt,
+        let collect_results: Vec<anyhow::Result<Vec<AlertIndex>>> =
+            join_all(
+                cluster_configs
+                    .iter()
+                    .map(|(cluster_name, index_configs)| async move {
+                        let target_query_service: &Q =
+                    self.target_query_services.get(*cluster_name).ok_or_else(|| {
+                        anyhow!(
+                            "[TrackingServiceImpl->save_index_cnt_infos] Unknown cluster '{}'",
+                            cluster_name
+                        )
+                    })?;
+
+                        target_query_service
+                            .collect_index_samples(index_configs, cur_utc_time)
+                            .await
+                    }),
+            )
+            .await;
+
+        let mut alert_indexes: Vec<AlertIndex> = Vec::new();
+        let mut failed_cluster_cnt: usize = 0;
+
+        /* A failing cluster must not stop the other clusters from being sampled. */
+        for collect_result in collect_results {
+            match collect_result {
+                Ok(cluster_alert_indexes) => alert_indexes.extend(cluster_alert_indexes),
                 Err(e) => {
-                    error!("[TrackingServiceImpl->save_index_cnt_infos]{:?}", e);
-                    continue;
+                    error!("[TrackingServiceImpl->save_index_cnt_infos] {:?}", e);
+                    failed_cluster_cnt += 1;
                 }
-            };
+            }
+        }
+
+        if failed_cluster_cnt > 0 && failed_cluster_cnt == cluster_configs.len() {
+            return Err(anyhow!(
+                "[TrackingServiceImpl->save_index_cnt_infos] Failed to collect samples from every target cluster"
+            ));
+        }
+
+        self.fill_source_row_cnts(index_list, &mut alert_indexes)
+            .await;
 
-            let alert_index: AlertIndex = AlertIndex::new(
-                index_name.to_string(),
-                doc_cnt,
-                convert_date_to_str(cur_utc_time, Utc),
+        for alert_index in alert_indexes.iter_mut() {
+            let silenced: bool = silence_list.is_silenced(
+                alert_index.index_name(),
+                convert_local_from_utc(cur_utc_time),
             );
+            alert_index.set_suppressed(silenced);
 
-            self.mon_query_service
-                .post_log_index(save_mon_index, &alert_index)
+            index_cnt_map.insert(alert_index.index_name().to_string(), *alert_index.cnt());
+        }
+
+        /* All samples are written with a single bulk request. */
+        self.mon_query_service
+            .post_log_indices(save_mon_index, &alert_indexes)
+            .await?;
+
+        Ok(index_cnt_map)
+    }
+
+    #[doc = "Function that queries the source (SQL Server) row count of every index with `reconcile` configured and records it on the sample of the same cycle."]
+    async fn fill_source_row_cnts(
+        &self,
+        index_list: &IndexListConfig,
+        alert_indexes: &mut [AlertIndex],
+    ) {
+        let reconcile_targets: Vec<(&str, &ReconcileConfig)> = index_list
+            .index()
+            .iter()
+            .filter_map(|index_config| {
+                index_config
+                    .reconcile()
+                    .as_ref()
+                    .map(|reconcile| (index_config.index_name().as_str(), reconcile))
+            })
+            .collect();
+
+        if reconcile_targets.is_empty() {
+            return;
+        }
+
+        let sql_conn: Arc<SqlServerRepositoryImpl> = get_sqlserver_repo();
+
+        let row_cnt_results: Vec<anyhow::Result<usize>> = join_all(
+            reconcile_targets
+                .iter()
+                .map(|(_, reconcile)| sql_conn.get_row_count(reconcile.count_query())),
+        )
+        .await;
+
+        /* A failed source query leaves the sample without a row count (no drift alert in that cycle). */
+        for ((index_name, _), row_cnt_result) in reconcile_targets.into_iter().zip(row_cnt_results)
+        {
+            match row_cnt_result {
+                Ok(source_row_cnt) => {
+                    if let Some(alert_index) = alert_indexes
+                        .iter_mut()
+                        .find(|alert_index| alert_index.index_name() == index_name)
+                    {
+                        alert_index.set_source_row_cnt(Some(source_row_cnt));
+                    }
+                }
+                Err(e) => error!(
+                    "[TrackingServiceImpl->fill_source_row_cnts] Failed to count source rows of '{}': {:?}",
+                    index_name, e
+                ),
+            }
+        }
+    }
+
+    #[doc = "Function that checks whether the index count of the latest sample drifts from the source (SQL Server) row count by more than the configured tolerance."]
+    fn check_source_drift(
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+    ) -> Option<LogIndexResult> {
+        let reconcile: &ReconcileConfig = index_config.reconcile().as_ref()?;
+        let latest_sample: &AlertIndex = samples.last()?;
+        let source_row_cnt: usize = (*latest_sample.source_row_cnt())?;
+
+        let diff_cnt: usize = source_row_cnt.abs_diff(*latest_sample.cnt());
+        let diff_percent: f64 = match source_row_cnt {
+            0 if diff_cnt == 0 => 0.0,
+            0 => 100.0,
+            _ => round_fluctuation(diff_cnt as f64 / source_row_cnt as f64 * 100.0),
+        };
+
+        if !reconcile.is_drifted(diff_cnt, diff_percent) {
+            return None;
+        }
+
+        let mut result: LogIndexResult = LogIndexResult::new(
+            index_config.index_name().to_string(),
+            true,
+            Some(vec![latest_sample.clone()]),
+            diff_percent,
+            *latest_sample.cnt(),
+            if *latest_sample.cnt() < source_row_cnt {
+                FluctuationDirection::Drop
+            } else {
+                FluctuationDirection::Growth
+            },
+            vec![AlertRule::SourceDrift],
+            None,
+            None,
+            None,
+        );
+        result.set_source_row_cnt(Some(source_row_cnt));
+
+        Some(result)
+    }
+
+    #[doc = "Function that compares the breakdown of the first and last samples in the window and returns the buckets that changed the most."]
+    fn calc_breakdown_changes(samples: &[AlertIndex]) -> Vec<BreakdownChange> {
+        let mut breakdowns = samples
+            .iter()
+            .filter_map(|sample| sample.breakdown().as_ref());
+
+        let first: Option<&Vec<BreakdownBucket>> = breakdowns.next();
+        let last: Option<&Vec<BreakdownBucket>> = breakdowns.next_back();
+
+        match (first, last) {
+            (Some(first), Some(last)) => {
+                BreakdownChange::top_changes(first, last, TOP_BREAKDOWN_CHANGE_CNT)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    #[doc = "Function that checks whether the newest document of the latest sample lags behind now by more than the configured limit."]
+    fn check_freshness_lag(
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+    ) -> Option<LogIndexResult> {
+        let max_freshness_lag_sec: i64 = (*index_config.max_freshness_lag_sec())?;
+        let latest_sample: &AlertIndex = samples.last()?;
+        let freshness_lag_sec: i64 = (*latest_sample.freshness_lag_sec())?;
+
+        if freshness_lag_sec <= max_freshness_lag_sec {
+            return None;
+        }
+
+        let mut result: LogIndexResult = LogIndexResult::new(
+            index_config.index_name().to_string(),
+            true,
+            Some(vec![latest_sample.clone()]),
+            0.0,
+            *latest_sample.cnt(),
+            FluctuationDirection::Flat,
+            vec![AlertRule::FreshnessLag],
+            None,
+            None,
+            None,
+        );
+        result.set_freshness_lag_sec(Some(freshness_lag_sec));
+
+        Some(result)
+    }
+
+    #[doc = "Function that checks whether the ingestion rate of the latest sample is outside the configured min/max range."]
+    fn check_ingest_rate(
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+    ) -> Option<LogIndexResult> {
+        let latest_sample: &AlertIndex = samples.last()?;
+        let ingest_cnt: usize = (*latest_sample.ingest_cnt())?;
+
+        let mut alert_rules: Vec<AlertRule> = Vec::new();
+
+        if index_config
+            .min_ingest_rate()
+            .is_some_and(|min_ingest_rate| ingest_cnt < min_ingest_rate)
+        {
+            alert_rules.push(AlertRule::IngestRateFloor);
+        }
+
+        if index_config
+            .max_ingest_rate()
+            .is_some_and(|max_ingest_rate| ingest_cnt > max_ingest_rate)
+        {
+            alert_rules.push(AlertRule::IngestRateCeiling);
+        }
+
+        if alert_rules.is_empty() {
+            return None;
+        }
+
+        let mut result: LogIndexResult = LogIndexResult::new(
+            index_config.index_name().to_string(),
+            true,
+            Some(vec![latest_sample.clone()]),
+            0.0,
+            *latest_sample.cnt(),
+            FluctuationDirection::Flat,
+            alert_rules,
+            None,
+            None,
+            None,
+        );
+        result.set_ingest_cnt(Some(ingest_cnt));
+
+        Some(result)
+    }
+
+    #[doc = "Function that checks the `_stats` metrics of the latest sample (and the delete count within `agg_term_sec`) against the configured limits."]
+    fn check_index_stats(
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> Option<LogIndexResult> {
+        let window_start_utc: DateTime<Utc> =
+            calc_time_window(cur_timestamp_utc, *index_config.agg_term_sec());
+        let window_stats: Vec<(&AlertIndex, &IndexStats)> =
+            window_samples(samples, window_start_utc)
+                .into_iter()
+                .filter_map(|sample| sample.index_stats().as_ref().map(|stats| (sample, stats)))
+                .collect();
+
+        let (latest_sample, latest_stats) = *window_stats.last()?;
+        let (_, first_stats) = *window_stats.first()?;
+
+        let exceeds = |limit: &Option<u64>, value: u64| limit.is_some_and(|limit| value > limit);
+
+        let mut alert_rules: Vec<AlertRule> = Vec::new();
+
+        if exceeds(
+            index_config.max_store_size_bytes(),
+            *latest_stats.store_size_bytes(),
+        ) {
+            alert_rules.push(AlertRule::StoreSizeCeiling);
+        }
+
+        if exceeds(
+            index_config.max_docs_deleted(),
+            *latest_stats.docs_deleted(),
+        ) {
+            alert_rules.push(AlertRule::DeletedDocsCeiling);
+        }
+
+        /* Totals are reset when shards are relocated, so a decrease is not treated as a delete storm. */
+        let delete_count: u64 = latest_stats
+            .delete_total()
+            .saturating_sub(*first_stats.delete_total());
+
+        if exceeds(index_config.max_delete_count(), delete_count) {
+            alert_rules.push(AlertRule::DeleteCountCeiling);
+        }
+
+        if exceeds(index_config.max_segment_cnt(), *latest_stats.segment_cnt()) {
+            alert_rules.push(AlertRule::SegmentCountCeiling);
+        }
+
+        if alert_rules.is_empty() {
+            return None;
+        }
+
+        let mut result: LogIndexResult = LogIndexResult::new(
+            index_config.index_name().to_string(),
+            true,
+            Some(vec![latest_sample.clone()]),
+            0.0,
+            *latest_sample.cnt(),
+            FluctuationDirection::Flat,
+            alert_rules,
+            None,
+            None,
+            None,
+        );
+        result.set_index_stats(Some(latest_stats.clone()));
+
+        Some(result)
+    }
+
+    #[doc = "Function that evaluates a single index and returns the abnormal results for it, i.e. the index informations whose number of documents fluctuated beyond a threshold (or did not change at all) within a specific period."]
+    async fn evaluate_index(
+        &self,
+        mon_index_alias: &str,
+        index_config: &IndexConfig,
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<LogIndexResult>> {
+        let mut log_index_results: Vec<LogIndexResult> = Vec::new();
+
+        /* Detector configured for each index. */
+        let detector: Box<dyn Detector> = build_detector(*index_config.detector());
+
+        let samples: Vec<AlertIndex> = self
+            .mon_query_service
+            .get_index_samples(
+                mon_index_alias,
+                index_config.index_name(),
+                calc_time_window(cur_timestamp_utc, detector.lookback_sec(index_config)),
+                cur_timestamp_utc,
+            )
+            .await?;
+
+        /* Past windows to compare against (e.g. the same window 1 day / 7 days ago). */
+        let mut comparisons: Vec<ComparisonWindow> = Vec::new();
+
+        for offset_sec in detector.comparison_offsets_sec(index_config) {
+            let comparison_end_utc: DateTime<Utc> = calc_time_window(cur_timestamp_utc, offset_sec);
+
+            let comparison_samples: Vec<AlertIndex> = self
+                .mon_query_service
+                .get_index_samples(
+                    mon_index_alias,
+                    index_config.index_name(),
+                    calc_time_window(comparison_end_utc, detector.lookback_sec(index_config)),
+                    comparison_end_utc,
+                )
                 .await?;
+
+            comparisons.push(ComparisonWindow::new(offset_sec, comparison_samples));
         }
 
-        Ok(())
+        let mut log_index_res: LogIndexResult =
+            detector.detect(index_config, &samples, &comparisons, cur_timestamp_utc);
+
+        if log_index_res.alert_yn && index_config.breakdown_field().is_some() {
+            log_index_res.breakdown_changes = Self::calc_breakdown_changes(&samples);
+        }
+
+        if log_index_res.alert_yn {
+            log_index_results.push(log_index_res);
+        }
+
+        /* Data freshness detection */
+        if let Some(freshness_index_res) = Self::check_freshness_lag(index_config, &samples) {
+            log_index_results.push(freshness_index_res);
+        }
+
+        /* Ingestion-rate detection */
+        if let Some(ingest_index_res) = Self::check_ingest_rate(index_config, &samples) {
+            log_index_results.push(ingest_index_res);
+        }
+
+        /* `_stats` metrics detection */
+        if let Some(stats_index_res) =
+            Self::check_index_stats(index_config, &samples, cur_timestamp_utc)
+        {
+            log_index_results.push(stats_index_res);
+        }
+
+        /* Source-of-truth reconciliation */
+        if let Some(drift_index_res) = Self::check_source_drift(index_config, &samples) {
+            log_index_results.push(drift_index_res);
+        }
+
+        /* Stalled-ingestion detection */
+        if index_config.expected_activity_sec().is_some() {
+            let stall_index_res: LogIndexResult = self
+                .mon_query_service
+                .get_stall_infos_from_log_index(mon_index_alias, index_config, cur_timestamp_utc)
+                .await?;
+
+            if stall_index_res.alert_yn {
+                log_index_results.push(stall_index_res);
+            }
+        }
+
+        for log_index_res in log_index_results.iter_mut() {
+            log_index_res.set_cluster_name(index_config.resolve_cluster_name().to_string());
+        }
+
+        Ok(log_index_results)
     }
 
-    #[doc = "Function that detects and returns index informations whose number of documents fluctuated beyond a threshold within a specific period."]
+    #[doc = "Function that evaluates all indices concurrently (bounded by `system.eval_concurrency`) and returns the abnormal results together with the indices whose evaluation failed."]
     async fn detect_abnormal_index_changes(
         &self,
         mon_index_name: &str,
         target_index_info_list: &IndexListConfig,
         cur_timestamp_utc: DateTime<Utc>,
-    ) -> anyhow::Result<Vec<LogIndexResult>> {
+    ) -> (Vec<LogIndexResult>, Vec<EvaluationFailure>) {
         let mut log_index_results: Vec<LogIndexResult> = Vec::new();
+        let mut evaluation_failures: Vec<EvaluationFailure> = Vec::new();
+
+        /* The lookback period may span multiple daily monitoring indices. */
+        let mon_index_alias: &str = &format!("{}*", mon_index_name);
+
+        let eval_concurrency: usize = get_system_config_info()
+            .eval_concurrency()
+            .filter(|eval_concurrency| *eval_concurrency > 0)
+            .unwrap_or(DEFAULT_EVAL_CONCURRENCY);
+
+        /* `buffered` keeps the configuration order while evaluating up to `eval_concurrency` indices at once. */
+        let evaluation_futures: Vec<_> = target_index_info_list
+            .index()
+            .iter()
+            .map(|index_config| async move {
+                (
+                    index_config,
+                    self.evaluate_index(mon_index_alias, index_config, cur_timestamp_utc)
+                        .await,
+                )
+            })
+            .collect();
+
+        let mut evaluations = futures::stream::iter(evaluation_futures).buffered(eval_concurrency);
+
+        while let Some((index_config, evaluation)) = evaluations.next().await {
+            match evaluation {
+                Ok(results) => log_index_results.extend(results),
+                Err(e) => {
+                    error!(
+                        "[TrackingServiceImpl->detect_abnormal_index_changes] {}: {:?}",
+                        index_config.index_name(),
+                        e
+                    );
+                    evaluation_failures.push(EvaluationFailure::new(
+                        index_config.index_name().to_string(),
+                        index_config.resolve_cluster_name().to_string(),
+                        format!("{:#}", e),
+                    ));
+                }
+            }
+        }
+
+        (log_index_results, evaluation_failures)
+    }
+
+    #[doc = "Function that restores the alert state of each index from the latest alarm history."]
+    async fn restore_alert_states(
+        &self,
+        target_index_info_list: &IndexListConfig,
+    ) -> anyhow::Result<()> {
+        let cur_utc_time: DateTime<Utc> = Utc::now();
+        let alarm_index_alias: &str = &format!("{}*", get_alarm_log_index_info().index_name());
+
+        let latest_histories: Vec<AlarmLogHistoryIndex> = self
+            .mon_query_service
+            .get_latest_alarm_histories(
+                alarm_index_alias,
+                calc_time_window(cur_utc_time, ALERT_STATE_RESTORE_SEC),
+                cur_utc_time,
+            )
+            .await?;
+
+        let mut alert_states = self
+            .alert_states
+            .lock()
+            .map_err(|e| anyhow!("[TrackingServiceImpl->restore_alert_states] {:?}", e))?;
+
+        for history in latest_histories {
+            /* The incident is already closed. */
+            if history.alert_status == AlertStatus::Resolved {
+                continue;
+            }
+
+            let Some(index_config) = target_index_info_list
+                .index()
+                .iter()
+                .find(|index_config| index_config.index_name() == &history.index_name)
+            else {
+                continue;
+            };
+
+            let last_fired_at: DateTime<Utc> = convert_utc_from_str(&history.timestamp)?;
+
+            /* Incidents that already ended before the cooldown elapsed are not restored. */
+            if (cur_utc_time - last_fired_at).num_seconds() > index_config.resolve_cooldown_sec() {
+                continue;
+            }
 
-        let save_mon_index: &str = &format!("{}{}", mon_index_name, convert_data_to_str_index(cur_timestamp_utc, Utc));
+            let incident_started_at: DateTime<Utc> = match &history.incident_started_at {
+                Some(started_at) => convert_utc_from_str(started_at)?,
+                None => last_fired_at,
+            };
+
+            let last_notified_at: Option<DateTime<Utc>> = match &history.last_notified_at {
+                Some(notified_at) => Some(convert_utc_from_str(notified_at)?),
+                None => None,
+            };
+
+            let mut alert_state: AlertState = AlertState::new(
+                incident_started_at,
+                last_fired_at,
+                last_notified_at,
+                None,
+                history.suppressed_cnt,
+            );
+            alert_state.set_peak_fluctuation(
+                history
+                    .peak_fluctuation_val
+                    .unwrap_or(history.fluctuation_val),
+            );
+            alert_state.set_start_cnt(history.start_cnt.unwrap_or(history.index_cnt));
+            alert_state.set_last_cnt(history.index_cnt);
+            alert_state.set_severity(history.severity);
+            /* Histories written before `notified_rules` existed: the announced rules are the recorded ones. */
+            alert_state.set_notified_rules(
+                if history.notified_rules.is_empty() && last_notified_at.is_some() {
+                    history.alert_rules.clone()
+                } else {
+                    history.notified_rules.clone()
+                },
+            );
+
+            alert_states.insert(history.index_name.clone(), alert_state);
+        }
+
+        info!("Restored alert states for {} indices", alert_states.len());
+
+        Ok(())
+    }
+
+    #[doc = "Function that loads the silences (maintenance windows) - no silences if the file does not exist."]
+    fn load_silence_list(&self) -> SilenceListConfig {
+        let silence_list_path: &Path = SILENCE_LIST_PATH.as_path();
+
+        if !silence_list_path.exists() {
+            return SilenceListConfig::default();
+        }
+
+        match read_toml_from_file::<SilenceListConfig>(&silence_list_path.to_string_lossy()) {
+            Ok(silence_list) => silence_list,
+            Err(e) => {
+                error!(
+                    "[TrackingServiceImpl->load_silence_list] Failed to read {:?}: {:?}",
+                    silence_list_path, e
+                );
+                SilenceListConfig::default()
+            }
+        }
+    }
+
+    #[doc = "Function that applies the alert state of each index to the detection results, decides whether to notify (deduplication / cooldown / re-notification / silences) and returns the incidents that have just been resolved."]
+    fn apply_alert_states(
+        &self,
+        target_index_info_list: &IndexListConfig,
+        log_index_results: &mut [LogIndexResult],
+        index_cnt_map: &HashMap<String, usize>,
+        silence_list: &SilenceListConfig,
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<IncidentRecovery>> {
+        let mut recoveries: Vec<IncidentRecovery> = Vec::new();
+        let cur_timestamp_local: DateTime<Local> = convert_local_from_utc(cur_timestamp_utc);
+
+        let mut alert_states = self
+            .alert_states
+            .lock()
+            .map_err(|e| anyhow!("[TrackingServiceImpl->apply_alert_states] {:?}", e))?;
 
         for index_config in target_index_info_list.index() {
-            let log_index_res: LogIndexResult = self
-                .mon_query_service
-                .get_alert_infos_from_log_index(save_mon_index, index_config, cur_timestamp_utc)
+            let index_name: &str = index_config.index_name();
+            let active_silence: Option<&SilenceConfig> =
+                silence_list.find_active_silence(index_name, cur_timestamp_local);
+            let silenced: bool = active_silence.is_some();
+
+            if let Some(silence) = active_silence
+                && log_index_results
+                    .iter()
+                    .any(|log_index_res| log_index_res.index_name() == index_name)
+            {
+                info!(
+                    "Alert for {} suppressed by silence '{}' ({})",
+                    index_name,
+                    silence.index_pattern(),
+                    silence.reason().as_deref().unwrap_or("no reason")
+                );
+            }
+            let mut firing: bool = false;
+
+            for log_index_res in log_index_results
+                .iter_mut()
+                .filter(|log_index_res| log_index_res.index_name() == index_name)
+            {
+                firing = true;
+                self.update_alert_state(
+                    index_config,
+                    &mut alert_states,
+                    log_index_res,
+                    silenced,
+                    cur_timestamp_utc,
+                );
+            }
+
+            /* The index is back within its thresholds: the incident ends. */
+            if !firing
+                && let Some(alert_state) = alert_states.get_mut(index_name)
+                && alert_state.is_firing()
+            {
+                alert_state.set_resolved_at(Some(cur_timestamp_utc));
+
+                let end_cnt: usize = index_cnt_map
+                    .get(index_name)
+                    .copied()
+                    .unwrap_or(*alert_state.last_cnt());
+
+                let mut recovery: IncidentRecovery = IncidentRecovery::new(
+                    index_name.to_string(),
+                    convert_date_to_str(*alert_state.incident_started_at(), Utc),
+                    convert_date_to_str(cur_timestamp_utc, Utc),
+                    (cur_timestamp_utc - *alert_state.incident_started_at()).num_seconds(),
+                    *alert_state.peak_fluctuation(),
+                    *alert_state.start_cnt(),
+                    end_cnt,
+                );
+                recovery.set_cluster_name(index_config.resolve_cluster_name().to_string());
+                recovery.set_severity(*alert_state.severity());
+                /* Incidents that were never announced do not need a recovery notification either. */
+                recovery.set_suppressed(silenced || alert_state.last_notified_at().is_none());
+
+                recoveries.push(recovery);
+            }
+        }
+
+        Ok(recoveries)
+    }
+
+    #[doc = "Function that updates the alert state of an abnormal index and marks whether the result should be notified. During a silence the incident is tracked but never notified."]
+    fn update_alert_state(
+        &self,
+        index_config: &IndexConfig,
+        alert_states: &mut HashMap<String, AlertState>,
+        log_index_res: &mut LogIndexResult,
+        silenced: bool,
+        cur_timestamp_utc: DateTime<Utc>,
+    ) {
+        let index_name: &str = index_config.index_name();
+        log_index_res.set_suppressed(silenced);
+
+        /* A recurrence within the cooldown period is treated as the same incident. */
+        let ongoing_state: Option<&mut AlertState> =
+            alert_states.get_mut(index_name).filter(|alert_state| {
+                alert_state.resolved_at().is_none_or(|resolved_at| {
+                    (cur_timestamp_utc - resolved_at).num_seconds()
+                        <= index_config.resolve_cooldown_sec()
+                })
+            });
+
+        match ongoing_state {
+            Some(alert_state) => {
+                /* A recovery was already announced, the severity escalated or another rule started firing -> notify immediately. */
+                let renotify_yn: bool = alert_state.resolved_at().is_some()
+                    || log_index_res.severity() > alert_state.severity()
+                    || alert_state.has_new_rules(log_index_res.alert_rules())
+                    || alert_state.last_notified_at().is_none_or(|notified_at| {
+                        (cur_timestamp_utc - notified_at).num_seconds()
+                            >= index_config.resolve_renotify_sec()
+                    });
+
+                alert_state.set_last_fired_at(cur_timestamp_utc);
+                alert_state.set_resolved_at(None);
+                alert_state.set_last_cnt(*log_index_res.cur_cnt());
+                alert_state.set_peak_fluctuation(
+                    alert_state
+                        .peak_fluctuation()
+                        .max(*log_index_res.fluctuation_val()),
+                );
+
+                log_index_res.set_alert_status(AlertStatus::StillFiring);
+                log_index_res.set_incident_started_at(Some(convert_date_to_str(
+                    *alert_state.incident_started_at(),
+                    Utc,
+                )));
+                log_index_res.set_suppressed_cnt(*alert_state.suppressed_cnt());
+
+                if renotify_yn && !silenced {
+                    log_index_res.set_notify_yn(true);
+                    alert_state.set_severity(*log_index_res.severity());
+                    alert_state.set_last_notified_at(Some(cur_timestamp_utc));
+                    alert_state.set_suppressed_cnt(0);
+                    alert_state.add_notified_rules(log_index_res.alert_rules());
+                } else {
+                    alert_state.set_suppressed_cnt(alert_state.suppressed_cnt() + 1);
+                }
+            }
+            None => {
+                /* An incident that starts during a silence is notified once the silence ends. */
+                let mut alert_state: AlertState = AlertState::new(
+                    cur_timestamp_utc,
+                    cur_timestamp_utc,
+                    (!silenced).then_some(cur_timestamp_utc),
+                    None,
+                    silenced as u64,
+                );
+                alert_state.set_peak_fluctuation(*log_index_res.fluctuation_val());
+                alert_state.set_start_cnt(*log_index_res.cur_cnt());
+                alert_state.set_last_cnt(*log_index_res.cur_cnt());
+                alert_state.set_severity(*log_index_res.severity());
+
+                if !silenced {
+                    alert_state.add_notified_rules(log_index_res.alert_rules());
+                }
+
+                alert_states.insert(index_name.to_string(), alert_state);
+
+                log_index_res.set_alert_status(AlertStatus::Firing);
+                log_index_res
+                    .set_incident_started_at(Some(convert_date_to_str(cur_timestamp_utc, Utc)));
+                log_index_res.set_notify_yn(!silenced);
+            }
+        }
+    }
+
+    #[doc = "Function that counts both sides of a primary/replica pair at (almost) the same instant and compares them."]
+    async fn count_index_pair(
+        &self,
+        index_pair: &IndexPairConfig,
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> anyhow::Result<IndexPairSample> {
+        let primary_cluster: &str = index_pair.resolve_primary_cluster();
+        let replica_cluster: &str = index_pair.replica_cluster();
+
+        let (primary_query_service, replica_query_service): (&Q, &Q) = match (
+            self.target_query_services.get(primary_cluster),
+            self.target_query_services.get(replica_cluster),
+        ) {
+            (Some(primary), Some(replica)) => (primary, replica),
+            _ => {
+                return Err(anyhow!(
+                    "[TrackingServiceImpl->count_index_pair] Unknown cluster in pair '{}'",
+                    index_pair.pair_name()
+                ));
+            }
+        };
+
+        let (primary_cnt, replica_cnt): (usize, usize) = tokio::try_join!(
+            primary_query_service.get_index_doc_count(index_pair.index_name()),
+            replica_query_service.get_index_doc_count(index_pair.resolve_replica_index_name())
+        )?;
+
+        let diff_cnt: usize = primary_cnt.abs_diff(replica_cnt);
+        let diff_percent: f64 = match primary_cnt {
+            0 if diff_cnt == 0 => 0.0,
+            0 => 100.0,
+            _ => round_fluctuation(diff_cnt as f64 / primary_cnt as f64 * 100.0),
+        };
+
+        Ok(IndexPairSample::new(
+            index_pair.pair_name(),
+            primary_cluster.to_string(),
+            index_pair.index_name().to_string(),
+            replica_cluster.to_string(),
+            index_pair.resolve_replica_index_name().to_string(),
+            primary_cnt,
+            replica_cnt,
+            diff_cnt,
+            diff_percent,
+            index_pair.is_diverged(diff_cnt, diff_percent),
+            convert_date_to_str(cur_timestamp_utc, Utc),
+        ))
+    }
+
+    #[doc = "Function that applies the divergence state of each pair and returns the alerts (past the grace period) and the divergences that have just been resolved."]
+    fn apply_pair_states(
+        &self,
+        index_pairs: &[IndexPairConfig],
+        pair_samples: &[IndexPairSample],
+        silence_list: &SilenceListConfig,
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> anyhow::Result<(Vec<LogIndexResult>, Vec<IncidentRecovery>)> {
+        let mut pair_alerts: Vec<LogIndexResult> = Vec::new();
+        let mut recoveries: Vec<IncidentRecovery> = Vec::new();
+
+        let renotify_sec: i64 = get_system_config_info()
+            .alert_renotify_min()
+            .unwrap_or(DEFAULT_ALERT_RENOTIFY_MIN)
+            * 60;
+
+        let mut pair_states = self
+            .pair_states
+            .lock()
+            .map_err(|e| anyhow!("[TrackingServiceImpl->apply_pair_states] {:?}", e))?;
+
+        /* Pairs removed from the configuration no longer have an incident. */
+        pair_states.retain(|pair_name, _| {
+            index_pairs
+                .iter()
+                .any(|index_pair| index_pair.pair_name() == *pair_name)
+        });
+
+        for pair_sample in pair_samples {
+            let Some(index_pair) = index_pairs
+                .iter()
+                .find(|index_pair| index_pair.pair_name() == *pair_sample.pair_name())
+            else {
+                continue;
+            };
+
+            let pair_name: &str = pair_sample.pair_name();
+            let cluster_name: String = format!(
+                "{} ↔ {}",
+                pair_sample.primary_cluster(),
+                pair_sample.replica_cluster()
+            );
+
+            /* The counts are consistent again: the divergence ends. */
+            if !pair_sample.diverged() {
+                if let Some(pair_state) = pair_states.remove(pair_name)
+                    && pair_state.last_notified_at().is_some()
+                {
+                    let mut recovery: IncidentRecovery = IncidentRecovery::new(
+                        pair_name.to_string(),
+                        convert_date_to_str(*pair_state.incident_started_at(), Utc),
+                        convert_date_to_str(cur_timestamp_utc, Utc),
+                        (cur_timestamp_utc - *pair_state.incident_started_at()).num_seconds(),
+                        *pair_state.peak_fluctuation(),
+                        *pair_state.start_cnt(),
+                        *pair_sample.diff_cnt(),
+                    );
+                    recovery.set_cluster_name(cluster_name);
+                    recovery.set_severity(*pair_state.severity());
+
+                    recoveries.push(recovery);
+                }
+                continue;
+            }
+
+            let pair_state: &mut AlertState =
+                pair_states.entry(pair_name.to_string()).or_insert_with(|| {
+                    let mut pair_state: AlertState =
+                        AlertState::new(cur_timestamp_utc, cur_timestamp_utc, None, None, 0);
+                    pair_state.set_start_cnt(*pair_sample.diff_cnt());
+                    pair_state
+                });
+
+            pair_state.set_last_fired_at(cur_timestamp_utc);
+            pair_state.set_last_cnt(*pair_sample.diff_cnt());
+            pair_state.set_peak_fluctuation(
+                pair_state
+                    .peak_fluctuation()
+                    .max(*pair_sample.diff_percent()),
+            );
+
+            /* A divergence shorter than the grace period is regarded as replication lag. */
+            if (cur_timestamp_utc - *pair_state.incident_started_at()).num_seconds()
+                < index_pair.resolve_grace_period_sec()
+            {
+                continue;
+            }
+
+            let silenced: bool = silence_list.is_silenced(
+                index_pair.index_name(),
+                convert_local_from_utc(cur_timestamp_utc),
+            );
+
+            let mut pair_alert: LogIndexResult = LogIndexResult::new(
+                pair_name.to_string(),
+                true,
+                Some(Vec::new()),
+                *pair_sample.diff_percent(),
+                *pair_sample.primary_cnt(),
+                if pair_sample.replica_cnt() < pair_sample.primary_cnt() {
+                    FluctuationDirection::Drop
+                } else {
+                    FluctuationDirection::Growth
+                },
+                vec![AlertRule::PairDivergence],
+                None,
+                None,
+                None,
+            );
+            pair_alert.set_cluster_name(cluster_name);
+            pair_alert.set_pair_sample(Some(pair_sample.clone()));
+            pair_alert.set_severity(AlertSeverity::Critical);
+            pair_alert.set_suppressed(silenced);
+            pair_alert.set_incident_started_at(Some(convert_date_to_str(
+                *pair_state.incident_started_at(),
+                Utc,
+            )));
+            pair_alert.set_suppressed_cnt(*pair_state.suppressed_cnt());
+
+            let notify_yn: bool = match pair_state.last_notified_at() {
+                None => {
+                    pair_alert.set_alert_status(AlertStatus::Firing);
+                    !silenced
+                }
+                Some(last_notified_at) => {
+                    pair_alert.set_alert_status(AlertStatus::StillFiring);
+                    !silenced
+                        && (cur_timestamp_utc - *last_notified_at).num_seconds() >= renotify_sec
+                }
+            };
+
+            if notify_yn {
+                pair_alert.set_notify_yn(true);
+                pair_state.set_last_notified_at(Some(cur_timestamp_utc));
+                pair_state.set_severity(*pair_alert.severity());
+                pair_state.set_suppressed_cnt(0);
+            } else {
+                pair_state.set_suppressed_cnt(pair_state.suppressed_cnt() + 1);
+            }
+
+            pair_alerts.push(pair_alert);
+        }
+
+        Ok((pair_alerts, recoveries))
+    }
+
+    #[doc = "Function that checks the document count consistency of every primary/replica pair, records the pair samples and sends divergence/recovery notifications."]
+    async fn check_index_pairs(
+        &self,
+        mon_index_name: &str,
+        index_pairs: &[IndexPairConfig],
+        silence_list: &SilenceListConfig,
+    ) -> anyhow::Result<()> {
+        let cur_timestamp_utc: DateTime<Utc> = Utc::now();
+        let save_mon_index: &str = &format!(
+            "{}{}",
+            mon_index_name,
+            convert_data_to_str_index(cur_timestamp_utc, Utc)
+        );
+
+        let count_results: Vec<anyhow::Result<IndexPairSample>> = join_all(
+            index_pairs
+                .iter()
+                .map(|index_pair| self.count_index_pair(index_pair, cur_timestamp_utc)),
+        )
+        .await;
+
+        let mut pair_samples: Vec<IndexPairSample> = Vec::new();
+
+        /* A pair that could not be counted keeps its current state. */
+        for count_result in count_results {
+            match count_result {
+                Ok(pair_sample) => pair_samples.push(pair_sample),
+                Err(e) => error!("[TrackingServiceImpl->check_index_pairs] {:?}", e),
+            }
+        }
+
+        if let Err(e) = self
+            .mon_query_service
+            .post_pair_samples(save_mon_index, &pair_samples)
+            .await
+        {
+            error!("[TrackingServiceImpl->check_index_pairs] {:?}", e);
+        }
+
+        let (pair_alerts, recoveries): (Vec<LogIndexResult>, Vec<IncidentRecovery>) =
+            self.apply_pair_states(index_pairs, &pair_samples, silence_list, cur_timestamp_utc)?;
+
+        if !recoveries.is_empty() {
+            self.handle_incident_recoveries(&recoveries, cur_timestamp_utc)
                 .await?;
+        }
+
+        if pair_alerts.is_empty() {
+            return Ok(());
+        }
 
-            if log_index_res.alert_yn {
-                log_index_results.push(log_index_res);
+        self.logging_alarm_history_infos(&pair_alerts, cur_timestamp_utc)
+            .await?;
+
+        let notify_targets: Vec<LogIndexResult> = pair_alerts
+            .into_iter()
+            .filter(|pair_alert| pair_alert.notify_yn)
+            .collect();
+
+        if !notify_targets.is_empty() {
+            self.alert_index_status(&notify_targets).await?;
+        }
+
+        Ok(())
+    }
+
+    #[doc = "Function that samples, verifies and notifies the indices that are due in this cycle."]
+    async fn run_tracking_cycle(
+        &self,
+        mon_index_name: &str,
+        resolved_index_list: &IndexListConfig,
+        due_index_list: &IndexListConfig,
+        silence_list: &SilenceListConfig,
+    ) {
+        /* 1. Store index document count information. */
+        let index_cnt_map: HashMap<String, usize> = match self
+            .save_index_cnt_infos(due_index_list, mon_index_name, silence_list)
+            .await
+        {
+            Ok(index_cnt_map) => index_cnt_map,
+            Err(e) => {
+                error!(
+                    "[TrackingServiceImpl->run_tracking_cycle] Failed to save index count infos: {:?}",
+                    e
+                );
+                return;
             }
+        };
+
+        let cur_timestamp_utc: DateTime<Utc> = Utc::now();
+
+        /*
+            2. Verify the number if index documents
+            - To send a notification when there is an abnormality in the rate of change of the number of indexes.
+        */
+        let (mut index_doc_verification, evaluation_failures): (
+            Vec<LogIndexResult>,
+            Vec<EvaluationFailure>,
+        ) = self
+            .detect_abnormal_index_changes(mon_index_name, due_index_list, cur_timestamp_utc)
+            .await;
+
+        /* Evaluation failures are reported on their own instead of skipping the whole cycle. */
+        if let Err(e) = self
+            .handle_evaluation_failures(
+                resolved_index_list,
+                due_index_list,
+                &evaluation_failures,
+                cur_timestamp_utc,
+            )
+            .await
+        {
+            error!(
+                "[TrackingServiceImpl->run_tracking_cycle] Failed to send monitoring degraded notification: {:?}",
+                e
+            );
         }
 
-        Ok(log_index_results)
+        /* Indices that could not be evaluated keep their alert state (no false recovery). */
+        let evaluated_index_list: IndexListConfig = IndexListConfig::new(
+            due_index_list
+                .index()
+                .iter()
+                .filter(|index_config| {
+                    !evaluation_failures
+                        .iter()
+                        .any(|failure| failure.index_name() == index_config.index_name())
+                })
+                .cloned()
+                .collect(),
+        );
+
+        /* 3. Suppress duplicate alerts for incidents that are already notified (or silenced). */
+        let recoveries: Vec<IncidentRecovery> = match self.apply_alert_states(
+            &evaluated_index_list,
+            &mut index_doc_verification,
+            &index_cnt_map,
+            silence_list,
+            cur_timestamp_utc,
+        ) {
+            Ok(recoveries) => recoveries,
+            Err(e) => {
+                error!("[TrackingServiceImpl->run_tracking_cycle] {:?}", e);
+                Vec::new()
+            }
+        };
+
+        /* Recovery notifications for indices that returned to normal. */
+        if !recoveries.is_empty()
+            && let Err(e) = self
+                .handle_incident_recoveries(&recoveries, cur_timestamp_utc)
+                .await
+        {
+            error!(
+                "[TrackingServiceImpl->run_tracking_cycle] Failed to send recovery notification: {:?}",
+                e
+            );
+        }
+
+        if !index_doc_verification.is_empty() {
+            /* 4. Save alarm information to keep alarm history */
+            if let Err(e) = self
+                .logging_alarm_history_infos(&index_doc_verification, cur_timestamp_utc)
+                .await
+            {
+                error!("[TrackingServiceImpl->run_tracking_cycle] {:?}", e);
+            }
+
+            /* 5. It sends an alert only for new incidents or when re-notification is due. */
+            let notify_targets: Vec<LogIndexResult> = index_doc_verification
+                .into_iter()
+                .filter(|log_index_res| log_index_res.notify_yn)
+                .collect();
+
+            if notify_targets.is_empty() {
+                return;
+            }
+
+            if let Err(e) = self.alert_index_status(&notify_targets).await {
+                error!(
+                    "[TrackingServiceImpl->run_tracking_cycle] Failed to send alert: {:?}",
+                    e
+                );
+            }
+        }
+    }
+
+    #[doc = "Function that updates the failing indices with the result of this cycle and sends a 'monitoring degraded' notification when the set of failing indices changes or the re-notification interval has elapsed."]
+    async fn handle_evaluation_failures(
+        &self,
+        resolved_index_list: &IndexListConfig,
+        due_index_list: &IndexListConfig,
+        evaluation_failures: &[EvaluationFailure],
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        let cur_failures: Vec<EvaluationFailure> = {
+            let mut failure_map = self.evaluation_failures.lock().map_err(|e| {
+                anyhow!("[TrackingServiceImpl->handle_evaluation_failures] {:?}", e)
+            })?;
+
+            /* Indices evaluated in this cycle are replaced by their latest result; removed indices are dropped. */
+            failure_map.retain(|index_name, _| {
+                resolved_index_list
+                    .index()
+                    .iter()
+                    .any(|index_config| index_config.index_name() == index_name)
+                    && !due_index_list
+                        .index()
+                        .iter()
+                        .any(|index_config| index_config.index_name() == index_name)
+            });
+
+            for failure in evaluation_failures {
+                failure_map.insert(failure.index_name().to_string(), failure.clone());
+            }
+
+            let mut cur_failures: Vec<EvaluationFailure> = failure_map.values().cloned().collect();
+            cur_failures.sort_by(|a, b| a.index_name().cmp(b.index_name()));
+            cur_failures
+        };
+
+        let failed_indices: Vec<String> = cur_failures
+            .iter()
+            .map(|failure| failure.index_name().to_string())
+            .collect();
+
+        let notify_yn: bool = {
+            let mut degraded_state = self.degraded_state.lock().map_err(|e| {
+                anyhow!("[TrackingServiceImpl->handle_evaluation_failures] {:?}", e)
+            })?;
+
+            if failed_indices.is_empty() {
+                *degraded_state = None;
+                return Ok(());
+            }
+
+            let renotify_sec: i64 = get_system_config_info()
+                .alert_renotify_min()
+                .unwrap_or(DEFAULT_ALERT_RENOTIFY_MIN)
+                * 60;
+
+            let notify_yn: bool = match degraded_state.as_ref() {
+                Some((prev_failed_indices, last_notified_at)) => {
+                    *prev_failed_indices != failed_indices
+                        || (cur_timestamp_utc - *last_notified_at).num_seconds() >= renotify_sec
+                }
+                None => true,
+            };
+
+            if notify_yn {
+                *degraded_state = Some((failed_indices, cur_timestamp_utc));
+            }
+
+            notify_yn
+        };
+
+        if notify_yn {
+            self.notification_service
+                .send_monitoring_degraded_message(&cur_failures, resolved_index_list.index().len())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[doc = "Function that records the resolution of each incident in the alarm history and sends recovery notifications."]
+    async fn handle_incident_recoveries(
+        &self,
+        recoveries: &[IncidentRecovery],
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        let loggin_index_name: &str = get_alarm_log_index_info().index_name();
+
+        for recovery in recoveries {
+            let mut alarm_history_form: AlarmLogHistoryIndex = AlarmLogHistoryIndex::new(
+                recovery.index_name().to_string(),
+                *recovery.end_cnt(),
+                *recovery.peak_fluctuation(),
+                convert_date_to_str(cur_timestamp_utc, Utc),
+                Vec::new(),
+                AlertStatus::Resolved,
+                Some(recovery.incident_started_at().to_string()),
+                Some(convert_date_to_str(cur_timestamp_utc, Utc)),
+                true,
+            );
+            alarm_history_form.start_cnt = Some(*recovery.start_cnt());
+            alarm_history_form.peak_fluctuation_val = Some(*recovery.peak_fluctuation());
+            alarm_history_form.resolved_at = Some(recovery.resolved_at().to_string());
+            alarm_history_form.incident_duration_sec = Some(*recovery.duration_sec());
+            alarm_history_form.cluster_name = Some(recovery.cluster_name().to_string());
+            alarm_history_form.severity = *recovery.severity();
+            alarm_history_form.suppressed = *recovery.suppressed();
+            alarm_history_form.notified = !recovery.suppressed();
+            if *recovery.suppressed() {
+                alarm_history_form.last_notified_at = None;
+            }
+
+            self.mon_query_service
+                .post_alarm_history_index(loggin_index_name, alarm_history_form)
+                .await?;
+        }
+
+        /* Silenced (or never announced) incidents are only recorded. */
+        let notify_targets: Vec<IncidentRecovery> = recoveries
+            .iter()
+            .filter(|recovery| !recovery.suppressed())
+            .cloned()
+            .collect();
+
+        if notify_targets.is_empty() {
+            return Ok(());
+        }
+
+        info!(
+            "Sending recovery notification for {} indices",
+            notify_targets.len()
+        );
+
+        self.notification_service
+            .send_recovery_message(&notify_targets)
+            .await
     }
 
     #[doc = "Function that sends current index information via alerts."]
@@ -127,13 +1449,35 @@ where
         let loggin_index_name: &str = get_alarm_log_index_info().index_name();
 
         for index_result in index_doc_verification {
-            let alarm_history_form: AlarmLogHistoryIndex = AlarmLogHistoryIndex::new(
+            let mut alarm_history_form: AlarmLogHistoryIndex = AlarmLogHistoryIndex::new(
                 index_result.index_name().to_string(),
                 index_result.cur_cnt,
                 index_result.fluctuation_val,
                 convert_date_to_str(cur_timestamp_utc, Utc),
+                index_result.alert_rules().clone(),
+                *index_result.alert_status(),
+                index_result.incident_started_at().clone(),
+                None,
+                index_result.notify_yn,
             );
 
+            alarm_history_form.cluster_name = Some(index_result.cluster_name().to_string());
+            alarm_history_form.severity = *index_result.severity();
+            alarm_history_form.suppressed = *index_result.suppressed();
+
+            /* Incident summary used to restore the alert state after a restart - taken from the in-memory state, not this tick. */
+            if let Ok(alert_states) = self.alert_states.lock()
+                && let Some(alert_state) = alert_states.get(index_result.index_name())
+            {
+                alarm_history_form.start_cnt = Some(*alert_state.start_cnt());
+                alarm_history_form.peak_fluctuation_val = Some(*alert_state.peak_fluctuation());
+                alarm_history_form.last_notified_at = alert_state
+                    .last_notified_at()
+                    .map(|notified_at| convert_date_to_str(notified_at, Utc));
+                alarm_history_form.suppressed_cnt = *alert_state.suppressed_cnt();
+                alarm_history_form.notified_rules = alert_state.notified_rules().clone();
+            }
+
             self.mon_query_service
                 .post_alarm_history_index(loggin_index_name, alarm_history_form)
                 .await?;
@@ -156,65 +1500,133 @@ where
         target_index_info_list: &IndexListConfig,
         save_tick: u64,
     ) -> anyhow::Result<()> {
-        /* Schedule ticker */
-        let mut ticker: Interval = interval(Duration::from_secs(save_tick));
+        /* Restore alert states so that a restart does not re-send ongoing incidents. */
+        let mut resolved_index_list: IndexListConfig =
+            self.resolve_target_index_list(target_index_info_list).await;
 
-        loop {
-            ticker.tick().await;
+        if let Err(e) = self.restore_alert_states(&resolved_index_list).await {
+            error!(
+                "[TrackingServiceImpl->tracking_monitor_loop] Failed to restore alert states: {:?}",
+                e
+            );
+        }
 
-            /* 1. Store index document count information. */
-            if let Err(e) = self
-                .save_index_cnt_infos(target_index_info_list, mon_index_name)
-                .await
-            {
-                error!(
-                    "[MainController->monitoring_loop] Failed to save index count infos: {:?}",
-                    e
-                );
-                continue;
+        /* Each index runs on its own cadence (`sample_interval_sec`); the scheduler wakes up at the earliest due time. */
+        let mut index_schedule: IndexSchedule = IndexSchedule::default();
+
+        /* Primary/replica pairs are compared on the default cadence (`save_tick`). */
+        let mut next_pair_check_at: DateTime<Utc> = Utc::now();
+
+        /* Concrete indices behind patterns/aliases change over time (rollover), so they are re-resolved on a fixed interval. */
+        let mut next_resolve_at: DateTime<Utc> =
+            Utc::now() + chrono::Duration::seconds(TARGET_RESOLVE_TICK_SEC);
+
+        loop {
+            if next_resolve_at <= Utc::now() {
+                resolved_index_list = self.resolve_target_index_list(target_index_info_list).await;
+                next_resolve_at = Utc::now() + chrono::Duration::seconds(TARGET_RESOLVE_TICK_SEC);
             }
 
-            let cur_timestamp_utc: DateTime<Utc> = Utc::now();
+            let due_index_list: IndexListConfig =
+                index_schedule.take_due(&resolved_index_list, Utc::now());
+
+            /* Silences are reloaded every cycle so that maintenance windows can be added without a restart. */
+            let silence_list: SilenceListConfig = self.load_silence_list();
 
-            /*
-                2. Verify the number if index documents
-                - To send a notification when there is an abnormality in the rate of change of the number of indexes.
-            */
-            let index_doc_verification: Vec<LogIndexResult> = match self
-                .detect_abnormal_index_changes(
+            if !due_index_list.index().is_empty() {
+                self.run_tracking_cycle(
                     mon_index_name,
-                    target_index_info_list,
-                    cur_timestamp_utc,
+                    &resolved_index_list,
+                    &due_index_list,
+                    &silence_list,
                 )
-                .await
-            {
-                Ok(results) => results,
-                Err(e) => {
-                    error!(
-                        "[MainController->monitoring_loop] Failed to verify index count: {:?}",
-                        e
-                    );
-                    continue;
-                }
-            };
+                .await;
+            }
+
+            if !target_index_info_list.pair().is_empty() && next_pair_check_at <= Utc::now() {
+                next_pair_check_at = Utc::now() + chrono::Duration::seconds(save_tick as i64);
 
-            if !index_doc_verification.is_empty() {
-                /* 3. Save alarm information to keep alarm history */
                 if let Err(e) = self
-                    .logging_alarm_history_infos(&index_doc_verification, cur_timestamp_utc)
+                    .check_index_pairs(mon_index_name, target_index_info_list.pair(), &silence_list)
                     .await
                 {
-                    error!("[MainController->monitoring_loop] {:?}", e);
-                }
-
-                /* 4. It sends an alert based on the verification results. */
-                if let Err(e) = self.alert_index_status(&index_doc_verification).await {
                     error!(
-                        "[MainController->monitoring_loop] Failed to send alert: {:?}",
+                        "[TrackingServiceImpl->tracking_monitor_loop] Failed to check index pairs: {:?}",
                         e
                     );
                 }
             }
+
+            /* Sleep until the next index, pair check or target re-resolution is due, but at most `save_tick` seconds. */
+            let next_wake_at: DateTime<Utc> = match index_schedule.next_wake_at() {
+                Some(next_wake_at) if target_index_info_list.pair().is_empty() => next_wake_at,
+                Some(next_wake_at) => next_wake_at.min(next_pair_check_at),
+                None => next_pair_check_at,
+            }
+            .min(next_resolve_at);
+
+            let wait_ms: i64 = (next_wake_at - Utc::now())
+                .num_milliseconds()
+                .clamp(0, (save_tick * 1000) as i64);
+
+            sleep(Duration::from_millis(wait_ms as u64)).await;
+        }
+    }
+
+    #[doc = "Function that periodically replays the documents spooled on local disk while the monitoring cluster was unavailable."]
+    async fn spool_replay_loop(&self, replay_tick: u64) -> anyhow::Result<()> {
+        /* The local storage backend writes straight to disk and never spools. */
+        if *get_storage_config_info().backend() == StorageBackend::Local {
+            info!(
+                "[TrackingServiceImpl->spool_replay_loop] Spool replay is not used by the local storage backend"
+            );
+            return Ok(());
+        }
+
+        loop {
+            sleep(Duration::from_secs(replay_tick)).await;
+
+            match self.mon_query_service.replay_spooled_documents().await {
+                Ok(0) => (),
+                Ok(replayed_cnt) => info!(
+                    "[TrackingServiceImpl->spool_replay_loop] Replayed {} spooled documents",
+                    replayed_cnt
+                ),
+                Err(e) => error!("[TrackingServiceImpl->spool_replay_loop] {:?}", e),
+            }
+        }
+    }
+
+    #[doc = "Function that periodically compacts daily monitoring indices older than `compaction.raw_retention_days` into hourly rollups."]
+    async fn compaction_loop(&self, mon_index_name: &str) -> anyhow::Result<()> {
+        if get_compaction_config_info().raw_retention_days().is_none() {
+            info!("[TrackingServiceImpl->compaction_loop] Compaction is disabled");
+            return Ok(());
+        }
+
+        /* Rollups are kept in the monitor cluster only - local sample files are not compacted. */
+        if *get_storage_config_info().backend() == StorageBackend::Local {
+            info!(
+                "[TrackingServiceImpl->compaction_loop] Compaction is not supported by the local storage backend"
+            );
+            return Ok(());
+        }
+
+        loop {
+            match self
+                .mon_query_service
+                .compact_monitor_indices(mon_index_name)
+                .await
+            {
+                Ok(0) => (),
+                Ok(compacted_cnt) => info!(
+                    "[TrackingServiceImpl->compaction_loop] Compacted {} daily monitoring indices",
+                    compacted_cnt
+                ),
+                Err(e) => error!("[TrackingServiceImpl->compaction_loop] {:?}", e),
+            }
+
+            sleep(Duration::from_secs(COMPACTION_TICK_SEC)).await;
         }
     }
 }
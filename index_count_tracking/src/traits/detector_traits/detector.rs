@@ -0,0 +1,35 @@
+use crate::common::*;
+
+use crate::model::index::{alert_index::*, index_config::*};
+
+use crate::dto::{comparison_window::*, log_index_result::*};
+
+pub trait Detector: Send + Sync {
+    #[doc = "판단에 필요한 과거 샘플의 조회 구간(초) - 기준 시각으로부터 이 시간만큼의 샘플이 전달된다."]
+    fn lookback_sec(&self, index_config: &IndexConfig) -> i64;
+
+    #[doc = "추가로 비교할 과거 구간의 offset(초) 목록 - 각 offset 마다 lookback_sec 길이의 구간이 `comparisons`로 전달된다."]
+    fn comparison_offsets_sec(&self, _index_config: &IndexConfig) -> Vec<i64> {
+        Vec::new()
+    }
+
+    #[doc = r#"
+        시간순으로 정렬된 샘플을 바탕으로 인덱스 문서 수 변동의 이상 여부를 판단한다.
+
+        # Arguments
+        * `index_config` - 모니터링 대상 인덱스 설정
+        * `samples` - (기준시각 - lookback_sec) ~ 기준시각 구간의 샘플 (timestamp 오름차순, UTC)
+        * `comparisons` - `comparison_offsets_sec` 에 해당하는 과거 비교 구간의 샘플 묶음
+        * `cur_timestamp_utc` - 기준 시각 (UTC)
+
+        # Returns
+        * `LogIndexResult` - 인덱스명, 정상여부, 위반 규칙, (이상 시) 구간 내 AlertIndex 목록
+    "#]
+    fn detect(
+        &self,
+        index_config: &IndexConfig,
+        samples: &[AlertIndex],
+        comparisons: &[ComparisonWindow],
+        cur_timestamp_utc: DateTime<Utc>,
+    ) -> LogIndexResult;
+}
@@ -1,2 +1,3 @@
+pub mod detector_traits;
 pub mod repository_traits;
 pub mod service_traits;
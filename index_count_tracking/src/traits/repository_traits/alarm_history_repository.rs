@@ -0,0 +1,24 @@
+use crate::common::*;
+
+use crate::dto::alarm::{alarm_log_history_index::*, alarm_report_infos::*};
+
+#[async_trait]
+pub trait AlarmHistoryRepository: Send + Sync + std::fmt::Debug {
+    async fn write_alarm_history(
+        &self,
+        index_name: &str,
+        alarm_history: &AlarmLogHistoryIndex,
+    ) -> Result<(), anyhow::Error>;
+    async fn read_latest_alarm_histories(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<AlarmLogHistoryIndex>, anyhow::Error>;
+    async fn read_alarm_index_counts(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<AlarmReportInfos, anyhow::Error>;
+}
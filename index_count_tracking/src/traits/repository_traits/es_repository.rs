@@ -7,12 +7,40 @@ pub trait EsRepository: Send + Sync {
         es_query: &Value,
         index_name: &str,
     ) -> Result<Value, anyhow::Error>;
+    async fn get_msearch_query(
+        &self,
+        es_queries: &[(String, Value)],
+    ) -> Result<Value, anyhow::Error>;
     async fn post_query(&self, document: &Value, index_name: &str) -> Result<(), anyhow::Error>;
+    async fn post_bulk_query(
+        &self,
+        documents: &[Value],
+        index_name: &str,
+    ) -> Result<(), anyhow::Error>;
+    async fn post_bulk_query_with_ids(
+        &self,
+        documents: &[(String, Value)],
+        index_name: &str,
+    ) -> Result<(), anyhow::Error>;
     async fn post_query_struct<T: Serialize + Sync>(
         &self,
         param_struct: &T,
         index_name: &str,
     ) -> Result<(), anyhow::Error>;
+    async fn get_resolve_index(&self, index_name: &str) -> Result<Value, anyhow::Error>;
+    async fn get_index_stats(&self, index_name: &str) -> Result<Value, anyhow::Error>;
+    async fn get_indices_stats(&self, index_names: &[&str]) -> Result<Value, anyhow::Error>;
+    async fn put_index_template(
+        &self,
+        template_name: &str,
+        template_body: &Value,
+    ) -> Result<(), anyhow::Error>;
+    async fn put_ilm_policy(
+        &self,
+        policy_name: &str,
+        policy_body: &Value,
+    ) -> Result<(), anyhow::Error>;
+    async fn delete_index(&self, index_name: &str) -> Result<(), anyhow::Error>;
     #[allow(dead_code)]
     async fn delete_query(&self, doc_id: &str, index_name: &str) -> Result<(), anyhow::Error>;
 }
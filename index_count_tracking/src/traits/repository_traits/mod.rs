@@ -1,3 +1,6 @@
+pub mod alarm_history_repository;
 pub mod es_repository;
+pub mod spool_repository;
 pub mod sqlserver_repository;
 pub mod telegram_repository;
+pub mod time_series_repository;
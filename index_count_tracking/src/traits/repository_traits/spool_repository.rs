@@ -0,0 +1,7 @@
+use crate::dto::spool_entry::*;
+
+pub trait SpoolRepository {
+    fn append_entries(&self, entries: &[SpoolEntry]) -> Result<(), anyhow::Error>;
+    fn take_replay_entries(&self) -> Result<Vec<SpoolEntry>, anyhow::Error>;
+    fn complete_replay(&self, remaining_entries: &[SpoolEntry]) -> Result<(), anyhow::Error>;
+}
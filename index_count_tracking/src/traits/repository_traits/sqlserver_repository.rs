@@ -9,4 +9,7 @@ pub trait SqlServerRepository {
         email_subject: &str,
         email_content: &str,
     ) -> Result<(), anyhow::Error>;
+
+    #[doc = "원본 테이블 행 수 조회 - 첫 번째 행의 첫 번째 컬럼 값을 행 수로 반환"]
+    async fn get_row_count(&self, count_query: &str) -> Result<usize, anyhow::Error>;
 }
@@ -0,0 +1,72 @@
+use crate::common::*;
+
+use crate::model::index::alert_index::*;
+
+use crate::dto::{index_count_agg_result::*, index_pair_sample::*, pair_consistency_info::*};
+
+use crate::enums::sort_order::*;
+
+#[async_trait]
+pub trait TimeSeriesRepository: Send + Sync + std::fmt::Debug {
+    async fn write_samples(
+        &self,
+        index_name: &str,
+        samples: &[AlertIndex],
+    ) -> Result<(), anyhow::Error>;
+    async fn read_samples(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        order: SortOrder,
+        size: usize,
+    ) -> Result<Vec<AlertIndex>, anyhow::Error>;
+    async fn read_last_changed_sample(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        cnt: usize,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<AlertIndex>, anyhow::Error>;
+    async fn read_min_max_cnt(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<(f64, f64)>, anyhow::Error>;
+    async fn read_first_last_samples(
+        &self,
+        mon_index_name: &str,
+        series_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<(AlertIndex, AlertIndex)>, anyhow::Error>;
+    async fn read_boundary_cnts(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        order: SortOrder,
+    ) -> Result<Vec<IndexCountAggResult>, anyhow::Error>;
+    async fn read_series_names(
+        &self,
+        mon_index_name: &str,
+        target_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<String>, anyhow::Error>;
+    async fn write_pair_samples(
+        &self,
+        index_name: &str,
+        pair_samples: &[IndexPairSample],
+    ) -> Result<(), anyhow::Error>;
+    async fn read_pair_consistency_infos(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<PairConsistencyInfo>, anyhow::Error>;
+}
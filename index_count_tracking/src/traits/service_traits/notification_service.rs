@@ -1,6 +1,8 @@
 use crate::common::*;
 
-use crate::dto::{alarm::alarm_image_info::*, log_index_result::*};
+use crate::dto::{
+    alarm::alarm_image_info::*, evaluation_failure::*, incident_recovery::*, log_index_result::*,
+};
 
 #[async_trait]
 pub trait NotificationService: Send + Sync {
@@ -8,6 +10,15 @@ pub trait NotificationService: Send + Sync {
         &self,
         log_index_results: &[LogIndexResult],
     ) -> Result<(), anyhow::Error>;
+    async fn send_recovery_message(
+        &self,
+        recoveries: &[IncidentRecovery],
+    ) -> Result<(), anyhow::Error>;
+    async fn send_monitoring_degraded_message(
+        &self,
+        failures: &[EvaluationFailure],
+        total_cnt: usize,
+    ) -> Result<(), anyhow::Error>;
     async fn send_report_information_by_email(
         &self,
         email_subject: &str,
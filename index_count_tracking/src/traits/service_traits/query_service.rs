@@ -1,22 +1,63 @@
 use crate::common::*;
 
+use crate::model::configs::index_bootstrap_config::*;
 use crate::model::index::{alert_index::*, index_config::*};
 
 use crate::dto::{
     alarm::{alarm_log_history_index::*, alarm_report_infos::*},
     index_count_agg_result::*,
+    index_pair_sample::*,
     log_index_result::*,
+    pair_consistency_info::*,
 };
 
 #[async_trait]
 pub trait QueryService {
+    async fn collect_index_samples(
+        &self,
+        index_configs: &[IndexConfig],
+        cur_utc_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<AlertIndex>>;
     async fn get_index_doc_count(&self, index_name: &str) -> anyhow::Result<usize>;
-    async fn post_log_index(
+    async fn resolve_concrete_indices(&self, index_pattern: &str) -> anyhow::Result<Vec<String>>;
+    async fn get_expanded_series_names(
+        &self,
+        mon_index_name: &str,
+        target_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<String>>;
+    async fn post_log_indices(
+        &self,
+        index_name: &str,
+        alert_indexes: &[AlertIndex],
+    ) -> anyhow::Result<()>;
+    async fn post_pair_samples(
         &self,
         index_name: &str,
-        alert_index: &AlertIndex,
+        pair_samples: &[IndexPairSample],
     ) -> anyhow::Result<()>;
-    async fn get_alert_infos_from_log_index(
+    async fn get_pair_consistency_infos(
+        &self,
+        mon_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<PairConsistencyInfo>>;
+    async fn get_index_samples(
+        &self,
+        mon_index_name: &str,
+        index_name: &str,
+        start_timestamp: DateTime<Utc>,
+        end_timestamp: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<AlertIndex>>;
+    async fn get_boundary_samples(
+        &self,
+        mon_index_name: &str,
+        index_name: &str,
+        start_timestamp: DateTime<Utc>,
+        end_timestamp: DateTime<Utc>,
+    ) -> anyhow::Result<Option<(AlertIndex, AlertIndex)>>;
+    async fn get_stall_infos_from_log_index(
         &self,
         mon_index_name: &str,
         index_config: &IndexConfig,
@@ -34,6 +75,20 @@ pub trait QueryService {
         index_name: &str,
         alarm_history_index: AlarmLogHistoryIndex,
     ) -> anyhow::Result<()>;
+    async fn replay_spooled_documents(&self) -> anyhow::Result<usize>;
+    async fn compact_monitor_indices(&self, mon_index_name: &str) -> anyhow::Result<usize>;
+    async fn bootstrap_index_templates(
+        &self,
+        mon_index_name: &str,
+        alarm_index_name: &str,
+        bootstrap_config: &IndexBootstrapConfig,
+    ) -> anyhow::Result<()>;
+    async fn get_latest_alarm_histories(
+        &self,
+        alarm_index_name: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<AlarmLogHistoryIndex>>;
     async fn get_start_time_all_indicies_count(
         &self,
         mon_index_name: &str,
@@ -10,4 +10,6 @@ pub trait TrackingMonitorService {
         target_index_info_list: &IndexListConfig,
         save_tick: u64,
     ) -> anyhow::Result<()>;
+    async fn spool_replay_loop(&self, replay_tick: u64) -> anyhow::Result<()>;
+    async fn compaction_loop(&self, mon_index_name: &str) -> anyhow::Result<()>;
 }
@@ -90,3 +90,109 @@ pub fn convert_json_from_struct<T: Serialize>(input_struct: &T) -> Result<Value,
         )
     })
 }
+
+#[doc = r#"
+    레코드 목록을 JSON Lines 파일 끝에 한 줄씩 추가 기록하고 디스크에 동기화(fsync)하는 함수.
+
+    로컬 저장소(`storage.backend = "local"`)의 샘플/페어 샘플/알람 이력 파일과 로컬 스풀 파일 기록에 사용한다.
+    동시 기록 직렬화는 호출자가 담당한다.
+
+    # Arguments
+    * `file_path` - 기록할 JSONL 파일 경로 (상위 디렉토리가 없으면 생성)
+    * `records` - 기록할 레코드 목록
+
+    # Returns
+    * `Result<(), anyhow::Error>` - 직렬화 또는 파일 기록 실패 시 오류
+"#]
+pub fn append_jsonl_file<T: Serialize>(
+    file_path: &Path,
+    records: &[T],
+) -> Result<(), anyhow::Error> {
+    let lines: String = to_jsonl_string(records)?;
+
+    if let Some(parent_dir) = file_path.parent() {
+        fs::create_dir_all(parent_dir)?;
+    }
+
+    let mut jsonl_file: fs::File = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_path)?;
+
+    jsonl_file.write_all(lines.as_bytes())?;
+    jsonl_file.sync_all()?;
+
+    Ok(())
+}
+
+#[doc = r#"
+    레코드 목록으로 JSON Lines 파일 내용을 통째로 교체하고 디스크에 동기화(fsync)하는 함수.
+
+    임시 파일(`*.tmp`)에 쓴 뒤 이름을 바꿔(rename) 교체하므로, 기록 도중 종료되어도 기존 파일이 깨지지 않는다.
+    동시 기록 직렬화는 호출자가 담당한다.
+
+    # Arguments
+    * `file_path` - 교체할 JSONL 파일 경로
+    * `records` - 기록할 레코드 목록
+
+    # Returns
+    * `Result<(), anyhow::Error>` - 직렬화 또는 파일 기록 실패 시 오류
+"#]
+pub fn replace_jsonl_file<T: Serialize>(
+    file_path: &Path,
+    records: &[T],
+) -> Result<(), anyhow::Error> {
+    let tmp_path: PathBuf = file_path.with_extension("tmp");
+
+    let mut tmp_file: fs::File = fs::File::create(&tmp_path)?;
+    tmp_file.write_all(to_jsonl_string(records)?.as_bytes())?;
+    tmp_file.sync_all()?;
+
+    fs::rename(&tmp_path, file_path)?;
+
+    Ok(())
+}
+
+#[doc = "레코드 목록을 JSON Lines 문자열(레코드당 한 줄)로 변환"]
+fn to_jsonl_string<T: Serialize>(records: &[T]) -> Result<String, anyhow::Error> {
+    let mut lines: String = String::new();
+
+    for record in records {
+        lines.push_str(&serde_json::to_string(record)?);
+        lines.push('\n');
+    }
+
+    Ok(lines)
+}
+
+#[doc = r#"
+    JSON Lines 파일의 각 줄을 지정된 구조체 타입으로 역직렬화해 반환하는 함수.
+
+    깨진 줄(기록 도중 종료 등)은 에러 로그를 남기고 건너뛴다.
+
+    # Arguments
+    * `file_path` - 읽을 JSONL 파일 경로
+
+    # Returns
+    * `Result<Vec<T>, anyhow::Error>` - 파일 읽기 실패 시 오류
+"#]
+pub fn read_jsonl_file<T: DeserializeOwned>(file_path: &Path) -> Result<Vec<T>, anyhow::Error> {
+    let file_content: String = fs::read_to_string(file_path)?;
+
+    let records: Vec<T> = file_content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str::<T>(line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                error!(
+                    "[read_jsonl_file] Skipping broken line in {:?}: {:?}",
+                    file_path, e
+                );
+                None
+            }
+        })
+        .collect();
+
+    Ok(records)
+}
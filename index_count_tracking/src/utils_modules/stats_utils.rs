@@ -0,0 +1,127 @@
+#[doc = "산술 평균 - 빈 배열이면 None"]
+pub fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+#[doc = "중앙값 - 빈 배열이면 None"]
+pub fn median(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut sorted: Vec<f64> = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let mid: usize = sorted.len() / 2;
+
+    if sorted.len().is_multiple_of(2) {
+        Some((sorted[mid - 1] + sorted[mid]) / 2.0)
+    } else {
+        Some(sorted[mid])
+    }
+}
+
+#[doc = "중앙값 절대편차(MAD) - (중앙값, MAD) 튜플 반환, 빈 배열이면 None"]
+pub fn median_absolute_deviation(values: &[f64]) -> Option<(f64, f64)> {
+    let med: f64 = median(values)?;
+    let deviations: Vec<f64> = values.iter().map(|v| (v - med).abs()).collect();
+    let mad: f64 = median(&deviations)?;
+
+    Some((med, mad))
+}
+
+#[doc = "지수가중이동평균/분산 - (평균, 표준편차) 튜플 반환, 빈 배열이면 None"]
+pub fn ewma_mean_std(values: &[f64], alpha: f64) -> Option<(f64, f64)> {
+    let (first, rest) = values.split_first()?;
+
+    let mut ewma_mean: f64 = *first;
+    let mut ewma_var: f64 = 0.0;
+
+    for value in rest {
+        let diff: f64 = value - ewma_mean;
+        let incr: f64 = alpha * diff;
+        ewma_mean += incr;
+        ewma_var = (1.0 - alpha) * (ewma_var + diff * incr);
+    }
+
+    Some((ewma_mean, ewma_var.sqrt()))
+}
+
+#[doc = "byte 수를 사람이 읽기 쉬운 단위(B/KB/MB/GB/TB)로 변환"]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+    let mut size: f64 = bytes as f64;
+    let mut unit_idx: usize = 0;
+
+    while size >= 1024.0 && unit_idx < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_idx += 1;
+    }
+
+    if unit_idx == 0 {
+        format!("{} {}", bytes, UNITS[unit_idx])
+    } else {
+        format!("{:.2} {}", size, UNITS[unit_idx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mean_of_empty_is_none() {
+        assert_eq!(mean(&[]), None);
+        assert_eq!(mean(&[1.0, 2.0, 6.0]), Some(3.0));
+    }
+
+    #[test]
+    fn median_handles_odd_even_and_empty() {
+        assert_eq!(median(&[]), None);
+        assert_eq!(median(&[5.0, 1.0, 3.0]), Some(3.0));
+        assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), Some(2.5));
+    }
+
+    #[test]
+    fn median_absolute_deviation_of_spread_values() {
+        /* median 2, deviations [1, 1, 0, 0, 2, 4, 7] -> MAD 1 */
+        assert_eq!(
+            median_absolute_deviation(&[1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]),
+            Some((2.0, 1.0))
+        );
+        assert_eq!(median_absolute_deviation(&[]), None);
+    }
+
+    #[test]
+    fn median_absolute_deviation_of_constant_values_is_zero() {
+        assert_eq!(
+            median_absolute_deviation(&[100.0, 100.0, 100.0, 100.0]),
+            Some((100.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn ewma_mean_std_of_empty_is_none() {
+        assert_eq!(ewma_mean_std(&[], 0.3), None);
+    }
+
+    #[test]
+    fn ewma_mean_std_of_constant_values_has_zero_std() {
+        assert_eq!(ewma_mean_std(&[7.0], 0.3), Some((7.0, 0.0)));
+        assert_eq!(ewma_mean_std(&[7.0, 7.0, 7.0], 0.3), Some((7.0, 0.0)));
+    }
+
+    #[test]
+    fn ewma_mean_std_follows_recent_values() {
+        /* mean: 0 -> 5 -> 7.5, var: 0 -> 25 -> 18.75 */
+        let (ewma_mean, ewma_std) = ewma_mean_std(&[0.0, 10.0, 10.0], 0.5).unwrap();
+
+        assert!((ewma_mean - 7.5).abs() < 1e-9);
+        assert!((ewma_std - 18.75_f64.sqrt()).abs() < 1e-9);
+    }
+}
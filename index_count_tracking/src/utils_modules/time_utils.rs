@@ -63,12 +63,9 @@ where
     TzOut: TimeZone,
     TzOut::Offset: Display,
 {
-    time.with_timezone(&tz)
-        .format("%Y%m%d")
-        .to_string()
+    time.with_timezone(&tz).format("%Y%m%d").to_string()
 }
 
-
 #[doc = "특정 시각에서 특정 시각을 빼준 시각을 반환하는 함수"]
 pub fn minus_h(dt: DateTime<Utc>, hours: i64) -> DateTime<Utc> {
     dt - chrono::Duration::hours(hours)
@@ -78,3 +75,35 @@ pub fn minus_h(dt: DateTime<Utc>, hours: i64) -> DateTime<Utc> {
 pub fn minus_h_local(dt: DateTime<Local>, hours: i64) -> DateTime<Local> {
     dt - chrono::Duration::hours(hours)
 }
+
+#[doc = "초 단위 시간을 `1h 2m 3s` 형태의 문자열로 변환하는 함수"]
+pub fn format_duration_sec(duration_sec: i64) -> String {
+    let hours: i64 = duration_sec / 3600;
+    let minutes: i64 = (duration_sec % 3600) / 60;
+    let seconds: i64 = duration_sec % 60;
+
+    if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[doc = "`%Y-%m-%dT%H:%M:%S` 형식의 로컬 시각 문자열을 `DateTime<Local>`로 변환하는 함수"]
+pub fn convert_local_from_str(time_str: &str) -> anyhow::Result<DateTime<Local>> {
+    let naive_time: chrono::NaiveDateTime =
+        chrono::NaiveDateTime::parse_from_str(time_str, "%Y-%m-%dT%H:%M:%S")
+            .map_err(|e| anyhow!("[convert_local_from_str] {:?}", e))?;
+
+    Local
+        .from_local_datetime(&naive_time)
+        .single()
+        .ok_or_else(|| {
+            anyhow!(
+                "[convert_local_from_str] Ambiguous local time: {}",
+                time_str
+            )
+        })
+}